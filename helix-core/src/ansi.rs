@@ -0,0 +1,155 @@
+//! ANSI SGR color interpretation, for viewing files that contain raw
+//! terminal escape codes (e.g. captured build logs) as colored text instead
+//! of `\x1b[` noise, without touching the buffer. Used by
+//! `:ansi-view`/`:ansi-view-stop` in `helix-term`, which render the
+//! overlays produced here via [`crate::text_annotations::Overlay`] the same
+//! way `:redact` does.
+//!
+//! Only the foreground SGR parameters (`30`-`37`, `90`-`97`, and the
+//! `0`/`39` resets) are interpreted -- good enough for the vast majority of
+//! colorized build output without implementing the full SGR grammar
+//! (256-color and true-color palettes, background colors, text attributes).
+
+use crate::graphemes::next_grapheme_boundary;
+use crate::text_annotations::Overlay;
+use crate::RopeSlice;
+
+/// One contiguous run of overlays that should all be styled the same
+/// `scope`'s color.
+pub struct AnsiSpan {
+    pub scope: &'static str,
+    pub overlays: Vec<Overlay>,
+}
+
+fn scope_for_sgr(param: u32) -> Option<&'static str> {
+    Some(match param {
+        30 => "ansi.black",
+        31 => "ansi.red",
+        32 => "ansi.green",
+        33 => "ansi.yellow",
+        34 => "ansi.blue",
+        35 => "ansi.magenta",
+        36 => "ansi.cyan",
+        37 => "ansi.white",
+        90 => "ansi.bright-black",
+        91 => "ansi.bright-red",
+        92 => "ansi.bright-green",
+        93 => "ansi.bright-yellow",
+        94 => "ansi.bright-blue",
+        95 => "ansi.bright-magenta",
+        96 => "ansi.bright-cyan",
+        97 => "ansi.bright-white",
+        _ => return None,
+    })
+}
+
+/// Parses `\x1b[<params>m` SGR escapes out of `text`, returning the
+/// overlays that hide the raw escape bytes and the [`AnsiSpan`]s that
+/// recolor the text they select.
+pub fn ansi_overlays(text: RopeSlice) -> (Vec<Overlay>, Vec<AnsiSpan>) {
+    let text_str = text.to_string();
+    let mut escape_overlays = Vec::new();
+    let mut spans: Vec<AnsiSpan> = Vec::new();
+    let mut current_scope: Option<&'static str> = None;
+    let mut byte_idx = 0;
+
+    while let Some(rel) = text_str[byte_idx..].find("\x1b[") {
+        let start = byte_idx + rel;
+        push_colored_run(text, byte_idx, start, current_scope, &mut spans);
+
+        let Some(m_rel) = text_str[start..].find('m') else {
+            break;
+        };
+        let params_end = start + m_rel;
+        let end = params_end + 1;
+
+        let params = &text_str[start + 2..params_end];
+        if params.is_empty() {
+            current_scope = None;
+        } else {
+            for param in params.split(';') {
+                match param.parse::<u32>() {
+                    Ok(0) | Ok(39) => current_scope = None,
+                    Ok(code) => {
+                        if let Some(scope) = scope_for_sgr(code) {
+                            current_scope = Some(scope);
+                        }
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+
+        let mut idx = text.byte_to_char(start);
+        let escape_end = text.byte_to_char(end);
+        while idx < escape_end {
+            escape_overlays.push(Overlay::new(idx, ""));
+            idx = next_grapheme_boundary(text, idx);
+        }
+
+        byte_idx = end;
+    }
+    push_colored_run(text, byte_idx, text_str.len(), current_scope, &mut spans);
+
+    (escape_overlays, spans)
+}
+
+fn push_colored_run(
+    text: RopeSlice,
+    start_byte: usize,
+    end_byte: usize,
+    scope: Option<&'static str>,
+    spans: &mut Vec<AnsiSpan>,
+) {
+    let Some(scope) = scope else { return };
+    if start_byte >= end_byte {
+        return;
+    }
+
+    let mut idx = text.byte_to_char(start_byte);
+    let end = text.byte_to_char(end_byte);
+    let mut overlays = Vec::new();
+    while idx < end {
+        let next = next_grapheme_boundary(text, idx);
+        let grapheme: String = text.slice(idx..next).chars().collect();
+        overlays.push(Overlay::new(idx, grapheme));
+        idx = next;
+    }
+
+    match spans.last_mut() {
+        Some(last) if last.scope == scope => last.overlays.extend(overlays),
+        _ => spans.push(AnsiSpan { scope, overlays }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ropey::Rope;
+
+    #[test]
+    fn colors_single_span() {
+        let rope = Rope::from_str("\x1b[31merror\x1b[0m: oops\n");
+        let (escapes, spans) = ansi_overlays(rope.slice(..));
+        assert_eq!(escapes.len(), "\x1b[31m".len() + "\x1b[0m".len());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].scope, "ansi.red");
+        assert_eq!(spans[0].overlays.len(), "error".len());
+    }
+
+    #[test]
+    fn unstyled_text_produces_no_spans() {
+        let rope = Rope::from_str("nothing to see here");
+        let (escapes, spans) = ansi_overlays(rope.slice(..));
+        assert!(escapes.is_empty());
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn unknown_sgr_param_is_ignored() {
+        let rope = Rope::from_str("\x1b[1mbold\x1b[0m");
+        let (escapes, spans) = ansi_overlays(rope.slice(..));
+        assert_eq!(escapes.len(), "\x1b[1m".len() + "\x1b[0m".len());
+        assert!(spans.is_empty());
+    }
+}
@@ -120,11 +120,23 @@ fn default() -> Self {
 //   middle of triple quotes, and more exotic pairs like Jinja's {% %}
 
 #[must_use]
-pub fn hook(doc: &Rope, selection: &Selection, ch: char, pairs: &AutoPairs) -> Option<Transaction> {
+pub fn hook(
+    doc: &Rope,
+    selection: &Selection,
+    ch: char,
+    pairs: &AutoPairs,
+    surround_selections: bool,
+) -> Option<Transaction> {
     log::trace!("autopairs hook selection: {:#?}", selection);
 
     if let Some(pair) = pairs.get(ch) {
-        if pair.same() {
+        let should_surround = surround_selections
+            && pair.open == ch
+            && selection.iter().any(|r| !is_cursor_like(doc, r));
+
+        if should_surround {
+            return Some(handle_surround(doc, selection, pair));
+        } else if pair.same() {
             return Some(handle_same(doc, selection, pair));
         } else if pair.open == ch {
             return Some(handle_open(doc, selection, pair));
@@ -371,3 +383,50 @@ fn handle_same(doc: &Rope, selection: &Selection, pair: &Pair) -> Transaction {
     log::debug!("auto pair transaction: {:#?}", t);
     t
 }
+
+/// a range that is empty or covers a single grapheme is a plain cursor, not
+/// a selection to wrap
+fn is_cursor_like(doc: &Rope, range: &Range) -> bool {
+    range.is_empty() || range.is_single_grapheme(doc.slice(..))
+}
+
+/// wrap every range wider than a cursor in `pair`'s open/close characters
+/// instead of replacing it, e.g. typing `(` on `[wor]d` produces `[(wor)]d`,
+/// with the selection grown to cover the inserted pair. Cursor-like ranges
+/// in a mixed selection only get the open character inserted; no close
+/// character is added for them.
+fn handle_surround(doc: &Rope, selection: &Selection, pair: &Pair) -> Transaction {
+    let mut changes = Vec::with_capacity(selection.len() * 2);
+    let mut end_ranges = SmallVec::with_capacity(selection.len());
+    let mut offs = 0;
+
+    for range in selection.iter() {
+        if is_cursor_like(doc, range) {
+            let cursor = range.cursor(doc.slice(..));
+            let mut tendril = Tendril::new();
+            tendril.push(pair.open);
+            changes.push((cursor, cursor, Some(tendril)));
+
+            let next_range = get_next_range(doc, range, offs, 1);
+            end_ranges.push(next_range);
+            offs += 1;
+        } else {
+            let mut open = Tendril::new();
+            open.push(pair.open);
+            let mut close = Tendril::new();
+            close.push(pair.close);
+            changes.push((range.from(), range.from(), Some(open)));
+            changes.push((range.to(), range.to(), Some(close)));
+
+            let next_range = Range::new(range.from() + offs, range.to() + offs + 2)
+                .with_direction(range.direction());
+            end_ranges.push(next_range);
+            offs += 2;
+        }
+    }
+
+    let transaction = Transaction::change(doc, changes.into_iter())
+        .with_selection(Selection::new(end_ranges, selection.primary_index()));
+    log::debug!("auto pair surround transaction: {:#?}", transaction);
+    transaction
+}
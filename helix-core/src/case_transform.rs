@@ -0,0 +1,167 @@
+//! Case-transform escapes for regex replacement templates (`\U`, `\L`, `\u`,
+//! `\l`), similar to those supported by Vim's `:substitute` and Perl's `s///`.
+//!
+//! Given a template string (the replacement side, after capture groups have
+//! already been expanded, e.g. by [`regex::Regex::replace`]'s `$1` syntax)
+//! it applies the case-transform escapes and returns the final string.
+//! Structural search (`crate::structural_search`) runs its replacement
+//! templates through [`expand_case_transforms`].
+
+/// Applies `\U`, `\L`, `\u`, `\l` case-transform escapes to `template`.
+///
+/// - `\U` / `\L` upper/lower-case everything up to the next `\E`, `\U`, or
+///   `\L`, or the end of the string if none follows.
+/// - `\u` / `\l` upper/lower-case only the next character.
+/// - `\E` ends a `\U`/`\L` span early.
+/// - `\\` inserts a literal backslash; any other `\x` is passed through
+///   as-is (`\` followed by `x`), so unrelated backslash sequences (e.g. a
+///   literal `\n` the caller wanted to keep) aren't silently eaten.
+pub fn expand_case_transforms(template: &str) -> String {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Span {
+        None,
+        Upper,
+        Lower,
+    }
+
+    let mut output = String::with_capacity(template.len());
+    let mut span = Span::None;
+    let mut one_shot: Option<Span> = None;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(apply(c, one_shot.take().or(Some(span)).unwrap()));
+            continue;
+        }
+
+        match chars.next() {
+            Some('U') => span = Span::Upper,
+            Some('L') => span = Span::Lower,
+            Some('E') => span = Span::None,
+            Some('u') => one_shot = Some(Span::Upper),
+            Some('l') => one_shot = Some(Span::Lower),
+            Some('\\') => output.push('\\'),
+            Some(other) => {
+                output.push('\\');
+                output.push(other);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    fn apply(c: char, span: Span) -> char {
+        match span {
+            Span::None => c,
+            // `to_uppercase`/`to_lowercase` can yield more than one `char`
+            // for a handful of code points; falling back to the original
+            // character keeps this a simple char-for-char mapping rather
+            // than growing the template unexpectedly.
+            Span::Upper => c.to_uppercase().next().unwrap_or(c),
+            Span::Lower => c.to_lowercase().next().unwrap_or(c),
+        }
+    }
+
+    output
+}
+
+/// The casing convention detected in a matched word, used by
+/// [`preserve_case`] to carry that convention over to its replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordCase {
+    /// `foobar` or `foo_bar`: no letter is uppercase.
+    Lower,
+    /// `FOOBAR` or `FOO_BAR`: every letter is uppercase.
+    Upper,
+    /// `Foobar` or `Foo_bar`: only the first letter is uppercase.
+    Title,
+    /// `FooBar` or `fooBar`: any other mix of upper- and lowercase letters.
+    Camel,
+}
+
+fn detect_word_case(word: &str) -> Option<WordCase> {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic()).peekable();
+    let first_upper = letters.peek()?.is_uppercase();
+    let rest_upper_count = letters.clone().skip(1).filter(|c| c.is_uppercase()).count();
+    let rest_len = letters.count().saturating_sub(1);
+
+    Some(match (first_upper, rest_upper_count) {
+        (false, 0) => WordCase::Lower,
+        (true, n) if n == rest_len && rest_len > 0 => WordCase::Upper,
+        (true, 0) => WordCase::Title,
+        (false, n) if n == rest_len && rest_len > 0 => WordCase::Upper,
+        _ => WordCase::Camel,
+    })
+}
+
+/// Rewrites `replacement` to follow the same casing convention as `matched`,
+/// so replacing `FooBar`/`foo_bar`/`FOO_BAR` occurrences of a word all
+/// produce correctly-cased results from a single lowercase `replacement`,
+/// rather than requiring a separate template per case style. Word boundaries
+/// (`_`, `-`, whitespace) in `replacement` are left untouched — only the
+/// letters are re-cased.
+///
+/// Falls back to `replacement` unchanged if `matched` contains no letters to
+/// infer a convention from.
+pub fn preserve_case(matched: &str, replacement: &str) -> String {
+    match detect_word_case(matched) {
+        Some(WordCase::Upper) => replacement.to_uppercase(),
+        Some(WordCase::Lower) | None => replacement.to_string(),
+        Some(WordCase::Title) => {
+            let mut chars = replacement.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        // Camel-case (`FooBar`) has no single-word analogue to project onto
+        // a plain `replacement`, so title-case it as the closest reasonable
+        // approximation rather than leaving it unchanged.
+        Some(WordCase::Camel) => {
+            let mut chars = replacement.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upper_lower_spans() {
+        assert_eq!(expand_case_transforms(r"\Uhello"), "HELLO");
+        assert_eq!(expand_case_transforms(r"\Uhello\E world"), "HELLO world");
+        assert_eq!(expand_case_transforms(r"\LHELLO\E WORLD"), "hello WORLD");
+    }
+
+    #[test]
+    fn test_one_shot_transforms() {
+        assert_eq!(expand_case_transforms(r"\uhello"), "Hello");
+        assert_eq!(expand_case_transforms(r"\lHELLO"), "hELLO");
+        assert_eq!(expand_case_transforms(r"\u\Lhello WORLD"), "Hello world");
+    }
+
+    #[test]
+    fn test_literal_backslash_and_passthrough() {
+        assert_eq!(expand_case_transforms(r"a\\b"), r"a\b");
+        assert_eq!(expand_case_transforms(r"a\nb"), r"a\nb");
+    }
+
+    #[test]
+    fn test_no_escapes() {
+        assert_eq!(expand_case_transforms("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_preserve_case() {
+        assert_eq!(preserve_case("foo", "bar"), "bar");
+        assert_eq!(preserve_case("FOO", "bar"), "BAR");
+        assert_eq!(preserve_case("Foo", "bar"), "Bar");
+        assert_eq!(preserve_case("FooBar", "quux"), "Quux");
+        assert_eq!(preserve_case("123", "bar"), "bar");
+    }
+}
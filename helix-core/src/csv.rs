@@ -0,0 +1,64 @@
+//! Virtual-text column alignment for delimiter-separated text (CSV/TSV and
+//! similar), used by `:csv-align`. Splitting is a plain search for the
+//! delimiter byte, not a full RFC 4180 parser, so a delimiter inside a
+//! quoted field will be (mis)treated as a column boundary; that's judged an
+//! acceptable trade-off for a lightweight, dependency-free viewer.
+
+use crate::line_ending::line_without_line_ending;
+use crate::text_annotations::InlineAnnotation;
+use crate::RopeSlice;
+
+/// Returns the padding needed after each field of every line in `text` so
+/// that columns line up visually when rendered, without changing the
+/// underlying buffer. Recomputed over the whole document, so this is
+/// intended for reasonably small delimited files, not multi-gigabyte ones.
+pub fn column_annotations(text: RopeSlice, delimiter: char) -> Vec<InlineAnnotation> {
+    let mut column_widths: Vec<usize> = Vec::new();
+    let rows: Vec<Vec<(usize, usize)>> = (0..text.len_lines())
+        .map(|line_idx| {
+            let line = line_without_line_ending(&text, line_idx);
+            let mut fields = Vec::new();
+            let mut start = 0;
+            for (col, ch) in line.chars().enumerate() {
+                if ch == delimiter {
+                    fields.push((start, col));
+                    start = col + 1;
+                }
+            }
+            fields.push((start, line.len_chars()));
+
+            for (i, &(field_start, field_end)) in fields.iter().enumerate() {
+                let width = field_end - field_start;
+                match column_widths.get_mut(i) {
+                    Some(existing) => *existing = (*existing).max(width),
+                    None => column_widths.push(width),
+                }
+            }
+
+            fields
+        })
+        .collect();
+
+    let mut annotations = Vec::new();
+    for (row, fields) in rows.iter().enumerate() {
+        let line_start = text.line_to_char(row);
+        let last_field = fields.len().saturating_sub(1);
+        for (i, &(field_start, field_end)) in fields.iter().enumerate() {
+            if i == last_field {
+                // No trailing delimiter after the last field, so there's
+                // nothing to align it against; leave it as-is.
+                break;
+            }
+            let width = field_end - field_start;
+            let padding = column_widths[i].saturating_sub(width);
+            if padding > 0 {
+                annotations.push(InlineAnnotation::new(
+                    line_start + field_end,
+                    " ".repeat(padding),
+                ));
+            }
+        }
+    }
+
+    annotations
+}
@@ -218,4 +218,24 @@ fn new_file() {
     fn deleted_file() {
         test_identity("foo", "");
     }
+
+    #[test]
+    fn selection_survives_unrelated_edit() {
+        use crate::Selection;
+
+        let old = Rope::from("line one\nline two\nline three\n");
+        let new = Rope::from("line one\nline TWENTY-TWO\nline three\n");
+
+        // cursor sits inside "line three", after the edited "line two" -> "line
+        // TWENTY-TWO" hunk, and should be shifted along with the growth of that
+        // hunk rather than reset, mirroring what `Document::apply_impl` does with
+        // the diff-based transactions produced by formatting and `:reload`.
+        let cursor = old.line_to_char(2) + 5;
+        let selection = Selection::single(cursor, cursor);
+        let transaction = compare_ropes(&old, &new);
+        let mapped = selection.map(transaction.changes());
+
+        let expected = new.line_to_char(2) + 5;
+        assert_eq!(mapped.primary().head, expected);
+    }
 }
@@ -1,8 +1,11 @@
 use std::ops::DerefMut;
 
+use arc_swap::{ArcSwap, Guard};
 use nucleo::pattern::{Atom, AtomKind, CaseMatching};
 use nucleo::Config;
+use once_cell::sync::Lazy;
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 
 pub struct LazyMutex<T> {
     inner: Mutex<Option<T>>,
@@ -24,6 +27,80 @@ pub fn lock(&self) -> impl DerefMut<Target = T> + '_ {
 
 pub static MATCHER: LazyMutex<nucleo::Matcher> = LazyMutex::new(nucleo::Matcher::default);
 
+/// Default case-matching behavior for pickers, completion filtering and
+/// prompt completers. Individual pickers can still cycle through the other
+/// modes at runtime (`Alt-c`), this only picks what they start on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FuzzyCaseMatching {
+    Smart,
+    Ignore,
+    Respect,
+}
+
+impl From<FuzzyCaseMatching> for CaseMatching {
+    fn from(case_matching: FuzzyCaseMatching) -> Self {
+        match case_matching {
+            FuzzyCaseMatching::Smart => CaseMatching::Smart,
+            FuzzyCaseMatching::Ignore => CaseMatching::Ignore,
+            FuzzyCaseMatching::Respect => CaseMatching::Respect,
+        }
+    }
+}
+
+/// Tunables for the nucleo matcher, applied consistently across pickers,
+/// completion filtering and prompt completers. Lives here rather than
+/// `helix-view::editor::Config` so this module's matcher helpers can read
+/// it without a dependency on `helix-view`; `helix-view` publishes into
+/// [`FUZZY_MATCHING_CONFIG`] whenever its own config is loaded or reloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct FuzzyMatchingConfig {
+    pub case_matching: FuzzyCaseMatching,
+    /// Favors matches at the start of the candidate string. Defaults to
+    /// `true`, matching `nucleo::Config::DEFAULT`.
+    pub prefer_prefix: bool,
+    /// Normalizes Unicode (e.g. matching `é` against `e`) in match
+    /// candidates. Defaults to `true`.
+    pub normalize_unicode: bool,
+    /// Uses path-aware scoring (treats `/` as a stronger word boundary) for
+    /// pickers that match file paths. Defaults to `true`.
+    pub normalize_paths: bool,
+}
+
+impl Default for FuzzyMatchingConfig {
+    fn default() -> Self {
+        Self {
+            case_matching: FuzzyCaseMatching::Smart,
+            prefer_prefix: true,
+            normalize_unicode: true,
+            normalize_paths: true,
+        }
+    }
+}
+
+impl FuzzyMatchingConfig {
+    /// Builds a `nucleo` matcher config from these settings. Set
+    /// `match_paths` for pickers that match file paths (file picker, global
+    /// search, path completion, ...) so `normalize_paths` takes effect.
+    pub fn nucleo_config(&self, match_paths: bool) -> Config {
+        let mut config = Config::DEFAULT;
+        config.prefer_prefix = self.prefer_prefix;
+        config.normalize = self.normalize_unicode;
+        if match_paths && self.normalize_paths {
+            config.set_match_paths();
+        }
+        config
+    }
+}
+
+pub static FUZZY_MATCHING_CONFIG: Lazy<ArcSwap<FuzzyMatchingConfig>> =
+    Lazy::new(|| ArcSwap::from_pointee(FuzzyMatchingConfig::default()));
+
+pub fn fuzzy_matching_config() -> Guard<std::sync::Arc<FuzzyMatchingConfig>> {
+    FUZZY_MATCHING_CONFIG.load()
+}
+
 /// convenience function to easily fuzzy match
 /// on a (relatively small list of inputs). This is not recommended for building a full tui
 /// application that can match large numbers of matches as all matching is done on the current
@@ -34,10 +111,8 @@ pub fn fuzzy_match<T: AsRef<str>>(
     path: bool,
 ) -> Vec<(T, u16)> {
     let mut matcher = MATCHER.lock();
-    matcher.config = Config::DEFAULT;
-    if path {
-        matcher.config.set_match_paths();
-    }
-    let pattern = Atom::new(pattern, CaseMatching::Smart, AtomKind::Fuzzy, false);
+    let config = fuzzy_matching_config();
+    matcher.config = config.nucleo_config(path);
+    let pattern = Atom::new(pattern, config.case_matching.into(), AtomKind::Fuzzy, false);
     pattern.match_list(items, &mut matcher)
 }
@@ -209,8 +209,15 @@ fn path_up(&self, mut n: usize, a: usize) -> Vec<usize> {
         path
     }
 
+    /// Number of revisions in the history, including the root. Every
+    /// revision number below this is valid to pass to [`Self::jump_to`].
+    #[inline]
+    pub fn num_revisions(&self) -> usize {
+        self.revisions.len()
+    }
+
     /// Create a [`Transaction`] that will jump to a specific revision in the history.
-    fn jump_to(&mut self, to: usize) -> Vec<Transaction> {
+    pub fn jump_to(&mut self, to: usize) -> Vec<Transaction> {
         let lca = self.lowest_common_ancestor(self.current, to);
         let up = self.path_up(self.current, lca);
         let down = self.path_up(to, lca);
@@ -1,9 +1,12 @@
 pub use encoding_rs as encoding;
 
+pub mod ansi;
 pub mod auto_pairs;
+pub mod case_transform;
 pub mod chars;
 pub mod comment;
 pub mod config;
+pub mod csv;
 pub mod diagnostic;
 pub mod diff;
 pub mod doc_formatter;
@@ -13,16 +16,22 @@
 pub mod increment;
 pub mod indent;
 pub mod line_ending;
+pub mod line_length;
 pub mod macros;
 pub mod match_brackets;
+pub mod modeline;
 pub mod movement;
 pub mod object;
 pub mod path;
 mod position;
+pub mod redact;
+pub mod schema;
 pub mod search;
 pub mod selection;
 pub mod shellwords;
+pub mod structural_search;
 pub mod surround;
+pub mod sync_log;
 pub mod syntax;
 pub mod test;
 pub mod text_annotations;
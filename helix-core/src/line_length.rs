@@ -0,0 +1,54 @@
+//! Computes "line exceeds budget" diagnostics for lines longer than a given
+//! width. Used by the `line-length-diagnostic` editor/language config to
+//! surface long lines through the normal diagnostics pipeline, as an
+//! alternative (or complement) to just drawing a `rulers` column.
+
+use crate::{
+    diagnostic::{Diagnostic, Range, Severity},
+    line_ending::line_without_line_ending,
+    RopeSlice,
+};
+
+pub const SOURCE: &str = "line-length";
+
+/// Reserved `language_server_id` for diagnostics produced by this and other
+/// built-in (non-LSP) diagnostic sources, distinguishing them from real
+/// `helix_lsp::Client` ids.
+pub const BUILTIN_LANGUAGE_SERVER_ID: usize = usize::MAX;
+
+/// One diagnostic per line whose character count (excluding the line
+/// ending) exceeds `max_width`, covering the overflowing characters.
+pub fn line_length_diagnostics(
+    text: RopeSlice,
+    max_width: usize,
+    severity: Severity,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for line_idx in 0..text.len_lines() {
+        let line = line_without_line_ending(&text, line_idx);
+        let len = line.len_chars();
+        if len <= max_width {
+            continue;
+        }
+
+        let line_start = text.line_to_char(line_idx);
+        diagnostics.push(Diagnostic {
+            range: Range {
+                start: line_start + max_width,
+                end: line_start + len,
+            },
+            ends_at_word: false,
+            starts_at_word: false,
+            zero_width: false,
+            line: line_idx,
+            message: format!("line exceeds {max_width} characters ({len})"),
+            severity: Some(severity),
+            code: None,
+            language_server_id: BUILTIN_LANGUAGE_SERVER_ID,
+            tags: Vec::new(),
+            source: Some(SOURCE.to_owned()),
+            data: None,
+        });
+    }
+    diagnostics
+}
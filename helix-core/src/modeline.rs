@@ -0,0 +1,61 @@
+//! Vim-style modeline comments that pin a document to a specific language,
+//! e.g. `# helix: language=jinja`. Checked for every file on open, which
+//! helps where the extension alone is ambiguous (a templated `.html` file
+//! that's really Jinja, a `.txt` log that should highlight as one), and
+//! takes priority over the normal extension/shebang based detection.
+//!
+//! Only `language=<id>` is recognized for now; Vim's modelines support many
+//! more options (tab width, fold method, ...), but nothing in this codebase
+//! reads those besides the language, so parsing them would be dead code.
+
+use crate::Rope;
+
+/// How many lines at the start and end of a file are checked for a
+/// modeline, mirroring Vim's default `modelines` setting.
+const SCAN_LINES: usize = 5;
+
+/// Returns the language id set by a `helix: language=<id>` modeline in the
+/// first or last [`SCAN_LINES`] lines of `text`, if any. `<id>` may contain
+/// letters, digits, `_`, `-` and `+` (covers ids like `c-sharp`, `c++`).
+pub fn detect_language(text: &Rope) -> Option<String> {
+    let total_lines = text.len_lines();
+    let head = 0..total_lines.min(SCAN_LINES);
+    let tail = total_lines.saturating_sub(SCAN_LINES)..total_lines;
+
+    head.chain(tail)
+        .find_map(|line_idx| language_from_line(&text.line(line_idx).to_string()))
+}
+
+fn language_from_line(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once("helix:")?;
+    let (_, rest) = rest.split_once("language=")?;
+    let id: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+        .collect();
+    (!id.is_empty()).then_some(id)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detects_modeline_in_first_lines() {
+        let text = Rope::from("# helix: language=jinja\n<html>{{ foo }}</html>\n");
+        assert_eq!(detect_language(&text), Some("jinja".to_string()));
+    }
+
+    #[test]
+    fn detects_modeline_in_last_lines() {
+        let text = Rope::from("body\n".repeat(20) + "// helix: language=c-sharp\n");
+        assert_eq!(detect_language(&text), Some("c-sharp".to_string()));
+    }
+
+    #[test]
+    fn ignores_files_without_a_modeline() {
+        let text = Rope::from("just a normal file\n");
+        assert_eq!(detect_language(&text), None);
+    }
+}
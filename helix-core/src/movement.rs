@@ -5,7 +5,7 @@
 
 use crate::{
     char_idx_at_visual_offset,
-    chars::{categorize_char, char_is_line_ending, CharCategory},
+    chars::{categorize_char, char_is_line_ending, char_is_whitespace, CharCategory},
     doc_formatter::TextFormat,
     graphemes::{
         next_grapheme_boundary, nth_next_grapheme_boundary, nth_prev_grapheme_boundary,
@@ -243,6 +243,98 @@ fn word_move(slice: RopeSlice, range: Range, count: usize, target: WordMotionTar
     range
 }
 
+/// Returns true if there is a sub-word boundary between `a` and `b`, i.e. a
+/// transition that `camelCase`/`snake_case`-aware motions should stop at:
+/// underscores, and lower-to-upper case transitions (`fooBar` -> `foo|Bar`).
+fn is_sub_word_boundary(a: char, b: char) -> bool {
+    if a == '_' || b == '_' {
+        return a != b;
+    }
+    if categorize_char(a) != categorize_char(b) {
+        return true;
+    }
+    a.is_lowercase() && b.is_uppercase()
+}
+
+fn sub_word_move(slice: RopeSlice, range: Range, count: usize, target: WordMotionTarget) -> Range {
+    let is_prev = matches!(
+        target,
+        WordMotionTarget::PrevWordStart | WordMotionTarget::PrevWordEnd
+    );
+
+    if (is_prev && range.head == 0) || (!is_prev && range.head == slice.len_chars()) {
+        return range;
+    }
+
+    let is_skip_char = |c: char| char_is_whitespace(c) || char_is_line_ending(c);
+    let len_chars = slice.len_chars();
+
+    let mut pos = range.head;
+    // Start of the last sub-word stepped over, used to select that sub-word
+    // for `NextWordEnd`.
+    let mut sub_word_start = pos;
+    for _ in 0..count {
+        let new_pos = if is_prev {
+            // Step onto the character to the left of `pos` and skip any
+            // whitespace, mirroring the block-cursor handling in `word_move`.
+            let mut i = pos.saturating_sub(1);
+            while i > 0 && is_skip_char(slice.char(i)) {
+                i -= 1;
+            }
+            // Walk left through the sub-word to its start.
+            while i > 0 && !is_sub_word_boundary(slice.char(i - 1), slice.char(i)) {
+                i -= 1;
+            }
+            i
+        } else {
+            // Skip any whitespace at or after `pos`.
+            let mut i = pos;
+            while i < len_chars && is_skip_char(slice.char(i)) {
+                i += 1;
+            }
+            sub_word_start = i;
+            // Walk right through the sub-word to just past its end.
+            while i + 1 < len_chars && !is_sub_word_boundary(slice.char(i), slice.char(i + 1)) {
+                i += 1;
+            }
+            if i < len_chars {
+                i + 1
+            } else {
+                len_chars
+            }
+        };
+
+        if new_pos == pos {
+            break;
+        }
+        pos = new_pos;
+    }
+
+    if matches!(target, WordMotionTarget::NextWordEnd) && sub_word_start < pos {
+        // Select the sub-word so the block cursor sits on its last character.
+        return Range::new(sub_word_start, pos);
+    }
+    range.put_cursor(slice, pos, false)
+}
+
+/// Move to the start of the next sub-word, stopping at `camelCase` and
+/// `snake_case` boundaries in addition to normal word boundaries.
+pub fn move_next_sub_word_start(slice: RopeSlice, range: Range, count: usize) -> Range {
+    sub_word_move(slice, range, count, WordMotionTarget::NextWordStart)
+}
+
+/// Move to the end of the next sub-word, stopping at `camelCase` and
+/// `snake_case` boundaries in addition to normal word boundaries.
+pub fn move_next_sub_word_end(slice: RopeSlice, range: Range, count: usize) -> Range {
+    sub_word_move(slice, range, count, WordMotionTarget::NextWordEnd)
+}
+
+/// Move to the start of the previous sub-word, stopping at `camelCase` and
+/// `snake_case` boundaries in addition to normal word boundaries.
+pub fn move_prev_sub_word_start(slice: RopeSlice, range: Range, count: usize) -> Range {
+    sub_word_move(slice, range, count, WordMotionTarget::PrevWordStart)
+}
+
 pub fn move_prev_paragraph(
     slice: RopeSlice,
     range: Range,
@@ -1792,4 +1884,24 @@ fn test_behaviour_when_moving_to_next_paragraph_extend() {
             assert_eq!(actual, expected, "\nbefore: `{:?}`", before);
         }
     }
+
+    #[test]
+    fn test_sub_word_motions() {
+        let text = Rope::from("fooBarBaz qux_quux__corge");
+        let slice = text.slice(..);
+
+        let range = Range::point(0);
+        let range = move_next_sub_word_start(slice, range, 1);
+        assert_eq!((range.anchor, range.head), (3, 3));
+        let range = move_next_sub_word_start(slice, range, 1);
+        assert_eq!((range.anchor, range.head), (6, 6));
+        let range = move_next_sub_word_end(slice, range, 1);
+        assert_eq!((range.anchor, range.head), (6, 9));
+
+        let range = Range::point(text.len_chars());
+        let range = move_prev_sub_word_start(slice, range, 1);
+        assert_eq!((range.anchor, range.head), (20, 20));
+        let range = move_prev_sub_word_start(slice, range, 1);
+        assert_eq!((range.anchor, range.head), (18, 18));
+    }
 }
@@ -0,0 +1,176 @@
+//! A minimal, dependency-free validator for a small set of bundled JSON
+//! config file schemas, used by `:schema-check` in `helix-term`.
+//!
+//! This is deliberately narrow: it only understands a handful of JSON
+//! Schema-like constraints (`required`, field type) for a couple of very
+//! common files, not a general JSON Schema implementation, and it doesn't
+//! touch YAML at all (`serde_json` can't parse it, and pulling in a YAML
+//! parser plus a real schema store just for this is a much bigger change).
+//! A full schema store with network-fetched schemas and LSP-integrated
+//! diagnostics/completion, as covered by json-language-server or
+//! yaml-language-server, is out of scope here; this only aims to catch the
+//! most common mistakes in a couple of ubiquitous files without any
+//! external server.
+
+use std::ops::Range;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    String,
+    Bool,
+    Object,
+    Array,
+}
+
+impl FieldKind {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            FieldKind::String => value.is_string(),
+            FieldKind::Bool => value.is_boolean(),
+            FieldKind::Object => value.is_object(),
+            FieldKind::Array => value.is_array(),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            FieldKind::String => "a string",
+            FieldKind::Bool => "a boolean",
+            FieldKind::Object => "an object",
+            FieldKind::Array => "an array",
+        }
+    }
+}
+
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub required: bool,
+    pub kind: FieldKind,
+}
+
+pub struct Schema {
+    /// Exact file name this schema applies to, e.g. `"package.json"`.
+    pub file_name: &'static str,
+    pub fields: &'static [FieldSchema],
+}
+
+pub const SCHEMAS: &[Schema] = &[
+    Schema {
+        file_name: "package.json",
+        fields: &[
+            FieldSchema { name: "name", required: true, kind: FieldKind::String },
+            FieldSchema { name: "version", required: true, kind: FieldKind::String },
+            FieldSchema { name: "private", required: false, kind: FieldKind::Bool },
+            FieldSchema { name: "dependencies", required: false, kind: FieldKind::Object },
+            FieldSchema { name: "devDependencies", required: false, kind: FieldKind::Object },
+            FieldSchema { name: "scripts", required: false, kind: FieldKind::Object },
+        ],
+    },
+    Schema {
+        file_name: "tsconfig.json",
+        fields: &[
+            FieldSchema { name: "compilerOptions", required: false, kind: FieldKind::Object },
+            FieldSchema { name: "include", required: false, kind: FieldKind::Array },
+            FieldSchema { name: "exclude", required: false, kind: FieldKind::Array },
+        ],
+    },
+];
+
+/// Looks up the bundled schema for an exact file name (e.g. `package.json`),
+/// if one exists.
+pub fn schema_for_file_name(file_name: &str) -> Option<&'static Schema> {
+    SCHEMAS.iter().find(|schema| schema.file_name == file_name)
+}
+
+pub struct SchemaViolation {
+    pub message: String,
+    /// Best-effort byte range to anchor a diagnostic to. `serde_json::Value`
+    /// doesn't retain source spans, so this is found by searching the raw
+    /// text for the field's key rather than computed from the parse tree;
+    /// it is the empty range at the start of the document when that search
+    /// fails or the field is missing, and the whole document when the text
+    /// isn't a JSON object.
+    pub byte_range: Range<usize>,
+}
+
+/// Validates `text` (raw file contents) against `schema`.
+pub fn validate(schema: &Schema, text: &str) -> Vec<SchemaViolation> {
+    let value: Value = match serde_json::from_str(text) {
+        Ok(value) => value,
+        Err(err) => {
+            return vec![SchemaViolation {
+                message: format!("invalid JSON: {err}"),
+                byte_range: 0..text.len(),
+            }]
+        }
+    };
+    let Value::Object(map) = value else {
+        return vec![SchemaViolation {
+            message: "expected a JSON object at the top level".to_string(),
+            byte_range: 0..text.len(),
+        }];
+    };
+
+    let mut violations = Vec::new();
+    for field in schema.fields {
+        match map.get(field.name) {
+            Some(value) if !field.kind.matches(value) => violations.push(SchemaViolation {
+                message: format!("`{}` should be {}", field.name, field.kind.describe()),
+                byte_range: field_key_range(text, field.name),
+            }),
+            Some(_) => {}
+            None if field.required => violations.push(SchemaViolation {
+                message: format!("missing required field `{}`", field.name),
+                byte_range: 0..0,
+            }),
+            None => {}
+        }
+    }
+    violations
+}
+
+/// Finds the byte range of `"name"` (the quoted key) in `text`, falling back
+/// to the start of the document if it can't be found.
+fn field_key_range(text: &str, name: &str) -> Range<usize> {
+    let needle = format!("\"{name}\"");
+    match text.find(&needle) {
+        Some(start) => start..start + needle.len(),
+        None => 0..0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_missing_required_fields() {
+        let schema = schema_for_file_name("package.json").unwrap();
+        let violations = validate(schema, "{}");
+        let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert!(messages.contains(&"missing required field `name`"));
+        assert!(messages.contains(&"missing required field `version`"));
+    }
+
+    #[test]
+    fn reports_wrong_field_type() {
+        let schema = schema_for_file_name("package.json").unwrap();
+        let violations = validate(schema, r#"{"name": "x", "version": "1.0.0", "private": "yes"}"#);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "`private` should be a boolean");
+    }
+
+    #[test]
+    fn valid_document_has_no_violations() {
+        let schema = schema_for_file_name("package.json").unwrap();
+        let violations = validate(schema, r#"{"name": "x", "version": "1.0.0"}"#);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn unknown_file_name_has_no_schema() {
+        assert!(schema_for_file_name("random.json").is_none());
+    }
+}
@@ -0,0 +1,91 @@
+//! Structural search and replace: finds every match of a tree-sitter query
+//! in a syntax tree and expands a capture-based replacement template for
+//! each one, e.g. to swap the argument order of every call to a function:
+//!
+//! ```query
+//! (call_expression
+//!   function: (identifier) @fn
+//!   arguments: (arguments (_) @a (_) @b)) @call
+//! ```
+//! ```text
+//! ${fn}(${b}, ${a})
+//! ```
+//!
+//! The first capture that appears in the query (`@call` above) is treated as
+//! the span that gets replaced; the others are only available for
+//! substitution into the template via `${name}`.
+
+use ropey::RopeSlice;
+use tree_sitter::{Query, QueryCursor, Tree};
+
+use crate::case_transform::expand_case_transforms;
+use crate::syntax::RopeProvider;
+
+/// One match of a structural search, ready to be turned into an edit.
+pub struct StructuralMatch {
+    /// The byte range of the query's first capture, i.e. the text this
+    /// match's `replacement` should replace.
+    pub byte_range: std::ops::Range<usize>,
+    /// `template` with `${name}` placeholders expanded and case-transform
+    /// escapes applied.
+    pub replacement: String,
+}
+
+/// Runs `query` over `tree` and returns one [`StructuralMatch`] per match,
+/// in document order. `template`'s `${name}` placeholders are replaced with
+/// the text captured under `name`; unknown placeholders are an error, since
+/// silently leaving them as literal text would be easy to miss in a preview.
+pub fn find_matches(
+    text: RopeSlice,
+    tree: &Tree,
+    query: &Query,
+    template: &str,
+) -> Result<Vec<StructuralMatch>, anyhow::Error> {
+    let mut cursor = QueryCursor::new();
+    let capture_names = query.capture_names();
+
+    let mut matches = Vec::new();
+    for query_match in cursor.matches(query, tree.root_node(), RopeProvider(text)) {
+        // By convention the first capture in the pattern is the span that
+        // gets replaced; tree-sitter returns captures in the order their
+        // node finished matching, not pattern order, so pick the one with
+        // the lowest capture index rather than `captures[0]`.
+        let Some(root_capture) = query_match.captures.iter().min_by_key(|c| c.index) else {
+            continue;
+        };
+
+        let mut replacement = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("${") {
+            replacement.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}') else {
+                anyhow::bail!("unterminated placeholder in replacement template");
+            };
+            let name = &rest[start + 2..start + end];
+            let capture = query_match
+                .captures
+                .iter()
+                .find(|c| capture_names[c.index as usize] == name)
+                .ok_or_else(|| anyhow::anyhow!("no capture named `{name}` in this match"))?;
+            let start_char = text.byte_to_char(capture.node.start_byte());
+            let end_char = text.byte_to_char(capture.node.end_byte());
+            replacement.push_str(&text.slice(start_char..end_char).to_string());
+            rest = &rest[start + end + 1..];
+        }
+        replacement.push_str(rest);
+
+        matches.push(StructuralMatch {
+            byte_range: root_capture.node.start_byte()..root_capture.node.end_byte(),
+            replacement: expand_case_transforms(&replacement),
+        });
+    }
+
+    Ok(matches)
+}
+
+// No unit tests here: exercising `find_matches` needs a real compiled
+// grammar (e.g. tree-sitter-rust), and helix-core has no such dependency --
+// grammars are only available at runtime via `helix-loader`. The other
+// tree-sitter-driven functions in this crate (`textobject_treesitter`,
+// `find_matching_bracket`, ...) are untested for the same reason; the
+// `helix-term` command that drives this module is exercised manually.
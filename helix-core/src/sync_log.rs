@@ -0,0 +1,91 @@
+//! An append-only, serializable log of every transaction committed to a
+//! document, kept independently of [`crate::history::History`] (which is
+//! mutated by undo/redo and can move `current` backwards).
+//!
+//! This is groundwork for collaborative editing and for external tooling
+//! that wants to observe edits in real time: a consumer only needs to
+//! remember the [`OperationId`] of the last entry it has seen and can
+//! resume from exactly that point with [`TransactionLog::since`], whether
+//! that consumer is a future pairing session or a script tailing changes
+//! over IPC.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Transaction;
+
+/// A stable, monotonically increasing identifier for a [`LogEntry`]. IDs are
+/// never reused or reassigned, so they double as sync cursors: a consumer
+/// that last observed ID `n` can resume with `since(Some(n))`.
+pub type OperationId = u64;
+
+/// A single entry in a [`TransactionLog`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub id: OperationId,
+    /// The entry this transaction was composed on top of, or `None` for the
+    /// first entry in the log.
+    pub parent: Option<OperationId>,
+    pub transaction: Transaction,
+}
+
+/// Per-document append-only transaction log. See the module documentation
+/// for the intended use.
+#[derive(Debug, Default)]
+pub struct TransactionLog {
+    entries: Vec<LogEntry>,
+}
+
+impl TransactionLog {
+    /// Appends `transaction` to the log and returns the ID of its new
+    /// entry.
+    pub fn record(&mut self, transaction: &Transaction) -> OperationId {
+        let id = self.entries.len() as OperationId;
+        let entry = LogEntry {
+            id,
+            parent: id.checked_sub(1),
+            transaction: transaction.clone(),
+        };
+        self.entries.push(entry);
+        id
+    }
+
+    /// The ID of the most recently recorded entry, or `None` if the log is
+    /// empty.
+    pub fn head(&self) -> Option<OperationId> {
+        self.entries.last().map(|entry| entry.id)
+    }
+
+    /// Entries recorded strictly after `since`, in commit order. Pass
+    /// `None` to fetch the entire log (e.g. for a consumer that's never
+    /// synced before).
+    pub fn since(&self, since: Option<OperationId>) -> &[LogEntry] {
+        let start = since.map_or(0, |id| id as usize + 1);
+        self.entries.get(start..).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rope;
+
+    #[test]
+    fn record_and_since() {
+        let doc = Rope::from("hello world");
+        let mut log = TransactionLog::default();
+        assert_eq!(log.head(), None);
+        assert!(log.since(None).is_empty());
+
+        let txn = Transaction::new(&doc);
+        let first = log.record(&txn);
+        let second = log.record(&txn);
+
+        assert_eq!(first, 0);
+        assert_eq!(second, 1);
+        assert_eq!(log.head(), Some(second));
+        assert_eq!(log.since(None).len(), 2);
+        assert_eq!(log.since(Some(first)).len(), 1);
+        assert_eq!(log.since(Some(second)).len(), 0);
+        assert_eq!(log.since(Some(first))[0].parent, Some(first));
+    }
+}
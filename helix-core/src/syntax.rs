@@ -30,6 +30,9 @@
 
 use helix_loader::grammar::{get_language, load_runtime_file};
 
+pub mod heuristic;
+pub mod structure_path;
+
 fn deserialize_regex<'de, D>(deserializer: D) -> Result<Option<Regex>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -164,6 +167,9 @@ pub struct LanguageConfiguration {
 
     pub rulers: Option<Vec<u16>>, // if set, override editor's rulers
 
+    /// If set, overrides `editor.line-length-diagnostic.enable` for this language.
+    pub line_length_diagnostic: Option<bool>,
+
     /// Hardcoded LSP root directories relative to the workspace root, like `examples` or `tools/fuzz`.
     /// Falling back to the current working directory if none are configured.
     pub workspace_lsp_roots: Option<Vec<PathBuf>>,
@@ -173,6 +179,15 @@ pub struct LanguageConfiguration {
 
     #[serde(default)]
     pub persistent_diagnostic_sources: Vec<String>,
+
+    /// Additional characters that trigger autocompletion for this language,
+    /// on top of whatever the language server advertises via
+    /// `completionProvider.triggerCharacters`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completion_trigger_characters: Vec<String>,
+
+    /// Overrides `editor.completion-trigger-len` for this language.
+    pub completion_trigger_len: Option<u8>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -325,6 +340,13 @@ pub enum LanguageServerFeature {
     Diagnostics,
     RenameSymbol,
     InlayHints,
+    InlineCompletion,
+    InlineValue,
+    CodeLens,
+    DocumentColor,
+    CallHierarchy,
+    DocumentLink,
+    RenameFiles,
 }
 
 impl Display for LanguageServerFeature {
@@ -348,6 +370,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Diagnostics => "diagnostics",
             RenameSymbol => "rename-symbol",
             InlayHints => "inlay-hints",
+            InlineCompletion => "inline-completion",
+            InlineValue => "inline-value",
+            CodeLens => "code-lens",
+            DocumentColor => "document-color",
+            CallHierarchy => "call-hierarchy",
+            DocumentLink => "document-link",
+            RenameFiles => "rename-files",
         };
         write!(f, "{feature}",)
     }
@@ -809,6 +838,12 @@ pub struct SoftWrap {
     pub wrap_indicator: Option<String>,
     /// Softwrap at `text_width` instead of viewport width if it is shorter
     pub wrap_at_text_width: Option<bool>,
+    /// Softwrap at this percentage of the viewport width instead of a fixed
+    /// `text_width`, recomputed every time the view is resized. Useful for
+    /// prose editing, where a fixed column reads well in one terminal size
+    /// but not another. Clamped to `1..=100`; setting this implies
+    /// `wrap_at_text_width`. Takes precedence over `text_width` when set.
+    pub text_width_percentage: Option<u8>,
 }
 
 // Expose loader as Lazy<> global since it's always static?
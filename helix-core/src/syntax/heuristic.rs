@@ -0,0 +1,216 @@
+//! A lightweight fallback highlighter for files that have no tree-sitter
+//! grammar (either because none is configured for the language, or because
+//! the grammar isn't built). It recognizes a handful of common token shapes
+//! - line comments, quoted strings and numbers - well enough to keep logs
+//! and niche file formats from rendering as completely flat text. It has no
+//! notion of language grammar or nesting, so anything more structured than
+//! that is out of scope; that's what a real tree-sitter grammar is for.
+//!
+//! The output is the same [`HighlightEvent`](super::HighlightEvent) stream
+//! tree-sitter highlighting produces, so callers can use either behind one
+//! iterator interface.
+
+use super::{Highlight, HighlightEvent};
+
+/// Default comment-start tokens tried when the document has no language
+/// configuration to read them from. Covers the common cases well enough to
+/// be useful without guessing at a specific language's syntax.
+const DEFAULT_COMMENT_TOKENS: &[&str] = &["//", "#", ";", "--", "%"];
+
+/// The highlight to use for each recognized token kind, resolved once by
+/// the caller (normally by looking up theme scopes) and reused for an
+/// entire highlight run.
+#[derive(Default, Clone, Copy)]
+pub struct HeuristicHighlights {
+    pub comment: Option<Highlight>,
+    pub string: Option<Highlight>,
+    pub number: Option<Highlight>,
+    /// Highlights for log-severity words. Left at its default (all `None`)
+    /// outside of log-viewing contexts, in which case matching words are
+    /// left unstyled just like any other plain text.
+    pub log_levels: LogLevelHighlights,
+}
+
+/// Highlights for common log-severity words, resolved from the same
+/// `diagnostic.*` theme scopes used for diagnostics so a log's severity
+/// words match the colors already used for errors and warnings elsewhere.
+#[derive(Default, Clone, Copy)]
+pub struct LogLevelHighlights {
+    pub error: Option<Highlight>,
+    pub warning: Option<Highlight>,
+    pub info: Option<Highlight>,
+    pub debug: Option<Highlight>,
+}
+
+#[derive(Clone, Copy)]
+enum LogLevel {
+    Error,
+    Warning,
+    Info,
+    Debug,
+}
+
+/// Recognized log-severity words, longest-alias-first so e.g. `WARNING`
+/// isn't shadowed by a hypothetical shorter prefix match.
+const LOG_LEVEL_WORDS: &[(&str, LogLevel)] = &[
+    ("ERROR", LogLevel::Error),
+    ("WARNING", LogLevel::Warning),
+    ("WARN", LogLevel::Warning),
+    ("INFO", LogLevel::Info),
+    ("DEBUG", LogLevel::Debug),
+    ("TRACE", LogLevel::Debug),
+];
+
+/// Scans `source[range]` for comments, strings and numbers, and returns the
+/// resulting `HighlightEvent` stream with byte offsets relative to the
+/// start of `source` (not `range.start`). `comment_tokens` overrides
+/// [`DEFAULT_COMMENT_TOKENS`] when non-empty, so a language configuration's
+/// own comment tokens are honored if one happens to be present.
+///
+/// `range` must start and end on UTF-8 character boundaries within
+/// `source` (passing `0..source.len()` always satisfies this).
+pub fn highlight_events(
+    source: &str,
+    range: std::ops::Range<usize>,
+    comment_tokens: &[String],
+    highlights: &HeuristicHighlights,
+) -> Vec<HighlightEvent> {
+    let bytes = source.as_bytes();
+    let end = range.end.min(bytes.len());
+    let mut events = Vec::new();
+    let mut pos = range.start.min(end);
+    let mut plain_start = pos;
+
+    let comment_tokens: Vec<&str> = if comment_tokens.is_empty() {
+        DEFAULT_COMMENT_TOKENS.to_vec()
+    } else {
+        comment_tokens.iter().map(String::as_str).collect()
+    };
+
+    while pos < end {
+        let byte = bytes[pos];
+
+        if comment_tokens
+            .iter()
+            .any(|token| source[pos..end].starts_with(*token))
+        {
+            flush_plain(&mut events, plain_start, pos);
+            let comment_end = source[pos..end]
+                .find('\n')
+                .map_or(end, |offset| pos + offset);
+            push_highlighted(&mut events, highlights.comment, pos, comment_end);
+            pos = comment_end;
+            plain_start = pos;
+            continue;
+        }
+
+        if byte.is_ascii_uppercase()
+            && pos
+                .checked_sub(1)
+                .and_then(|prev| bytes.get(prev))
+                .map_or(true, |prev| !prev.is_ascii_alphanumeric() && *prev != b'_')
+        {
+            let word_match = LOG_LEVEL_WORDS.iter().find_map(|(word, level)| {
+                let word_end = pos + word.len();
+                let followed_by_word_char = bytes
+                    .get(word_end)
+                    .map_or(false, |b| b.is_ascii_alphanumeric() || *b == b'_');
+                if !followed_by_word_char && source[pos..end].starts_with(word) {
+                    Some((word.len(), *level))
+                } else {
+                    None
+                }
+            });
+            if let Some((len, level)) = word_match {
+                flush_plain(&mut events, plain_start, pos);
+                let highlight = match level {
+                    LogLevel::Error => highlights.log_levels.error,
+                    LogLevel::Warning => highlights.log_levels.warning,
+                    LogLevel::Info => highlights.log_levels.info,
+                    LogLevel::Debug => highlights.log_levels.debug,
+                };
+                push_highlighted(&mut events, highlight, pos, pos + len);
+                pos += len;
+                plain_start = pos;
+                continue;
+            }
+        }
+
+        if byte == b'"' || byte == b'\'' || byte == b'`' {
+            flush_plain(&mut events, plain_start, pos);
+            let start = pos;
+            let quote = byte;
+            pos += 1;
+            while pos < end {
+                if bytes[pos] == b'\\' {
+                    pos += 1;
+                    if pos < end {
+                        pos += char_len_at(source, pos);
+                    }
+                    continue;
+                }
+                if bytes[pos] == quote {
+                    pos += 1;
+                    break;
+                }
+                pos += char_len_at(source, pos);
+            }
+            push_highlighted(&mut events, highlights.string, start, pos.min(end));
+            plain_start = pos.min(end);
+            continue;
+        }
+
+        if byte.is_ascii_digit()
+            && pos
+                .checked_sub(1)
+                .and_then(|prev| bytes.get(prev))
+                .map_or(true, |prev| !prev.is_ascii_alphanumeric() && *prev != b'_')
+        {
+            let start = pos;
+            while pos < end && (bytes[pos].is_ascii_alphanumeric() || matches!(bytes[pos], b'.' | b'_')) {
+                pos += 1;
+            }
+            flush_plain(&mut events, plain_start, start);
+            push_highlighted(&mut events, highlights.number, start, pos);
+            plain_start = pos;
+            continue;
+        }
+
+        pos += char_len_at(source, pos);
+    }
+
+    flush_plain(&mut events, plain_start, end);
+    events
+}
+
+/// Length in bytes of the UTF-8 character starting at `pos`, so the scanner
+/// never lands `pos` in the middle of a multi-byte character (which would
+/// panic on the next `&str` byte-range index).
+fn char_len_at(source: &str, pos: usize) -> usize {
+    source[pos..].chars().next().map_or(1, char::len_utf8)
+}
+
+fn flush_plain(events: &mut Vec<HighlightEvent>, start: usize, end: usize) {
+    if start < end {
+        events.push(HighlightEvent::Source { start, end });
+    }
+}
+
+fn push_highlighted(
+    events: &mut Vec<HighlightEvent>,
+    highlight: Option<Highlight>,
+    start: usize,
+    end: usize,
+) {
+    if start >= end {
+        return;
+    }
+    match highlight {
+        Some(highlight) => {
+            events.push(HighlightEvent::HighlightStart(highlight));
+            events.push(HighlightEvent::Source { start, end });
+            events.push(HighlightEvent::HighlightEnd);
+        }
+        None => events.push(HighlightEvent::Source { start, end }),
+    }
+}
@@ -0,0 +1,64 @@
+//! Computes a breadcrumb path from the document root down to the node under
+//! the cursor, for structured data formats. Used by the `structure-path`
+//! statusline element and the `:structure-path-copy` command.
+//!
+//! Only JSON is mapped so far. YAML and TOML have their own tree-sitter
+//! grammars with different node kinds and field names, and guessing at
+//! their exact shape isn't worth the risk of silently producing wrong
+//! paths; add a case to [`structure_path`] once those grammars have been
+//! checked against a real parse.
+
+use tree_sitter::Node;
+
+use crate::RopeSlice;
+
+/// Returns the breadcrumb path to `pos` (e.g.
+/// `spec.template.containers[0].image`), or `None` if `language_id` isn't
+/// supported, or `pos` isn't inside any keyed or indexed node.
+pub fn structure_path(root: Node, text: RopeSlice, pos: usize, language_id: &str) -> Option<String> {
+    match language_id {
+        "json" => json_path(root, text, pos),
+        _ => None,
+    }
+}
+
+fn json_path(root: Node, text: RopeSlice, pos: usize) -> Option<String> {
+    let mut node = root.named_descendant_for_byte_range(pos, pos)?;
+    let mut segments: Vec<String> = Vec::new();
+
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            "pair" => {
+                if let Some(key) = parent.child_by_field_name("key") {
+                    let key_text = text.byte_slice(key.byte_range()).to_string();
+                    segments.push(key_text.trim_matches('"').to_string());
+                }
+            }
+            "array" => {
+                let mut cursor = parent.walk();
+                let index = parent
+                    .named_children(&mut cursor)
+                    .position(|sibling| sibling.id() == node.id())?;
+                segments.push(format!("[{index}]"));
+            }
+            _ => {}
+        }
+        node = parent;
+    }
+
+    if segments.is_empty() {
+        return None;
+    }
+    segments.reverse();
+
+    let mut path = String::new();
+    for segment in segments {
+        if segment.starts_with('[') || path.is_empty() {
+            path.push_str(&segment);
+        } else {
+            path.push('.');
+            path.push_str(&segment);
+        }
+    }
+    Some(path)
+}
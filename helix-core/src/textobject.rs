@@ -50,6 +50,100 @@ fn find_word_boundary(slice: RopeSlice, mut pos: usize, direction: Direction, lo
     pos
 }
 
+/// Like [`find_word_boundary`], but additionally stops at `camelCase` and
+/// `snake_case` sub-word boundaries (an underscore, or a lower-to-upper case
+/// transition).
+fn find_sub_word_boundary(slice: RopeSlice, mut pos: usize, direction: Direction) -> usize {
+    use CharCategory::{Eol, Whitespace};
+
+    let is_boundary = |prev: char, next: char| {
+        if prev == '_' || next == '_' {
+            return prev != next;
+        }
+        categorize_char(prev) != categorize_char(next) || (prev.is_lowercase() && next.is_uppercase())
+    };
+
+    let iter = match direction {
+        Direction::Forward => slice.chars_at(pos),
+        Direction::Backward => {
+            let mut iter = slice.chars_at(pos);
+            iter.reverse();
+            iter
+        }
+    };
+
+    let mut prev_char = match direction {
+        Direction::Forward if pos == 0 => None,
+        Direction::Forward => Some(slice.char(pos - 1)),
+        Direction::Backward if pos == slice.len_chars() => None,
+        Direction::Backward => Some(slice.char(pos)),
+    };
+
+    for ch in iter {
+        match categorize_char(ch) {
+            Eol | Whitespace => return pos,
+            _ => {
+                if let Some(prev) = prev_char {
+                    // `is_boundary` takes its characters in text order.
+                    let boundary = match direction {
+                        Direction::Forward => is_boundary(prev, ch),
+                        Direction::Backward => is_boundary(ch, prev),
+                    };
+                    if boundary && pos != 0 && pos != slice.len_chars() {
+                        return pos;
+                    }
+                }
+                match direction {
+                    Direction::Forward => pos += 1,
+                    Direction::Backward => pos = pos.saturating_sub(1),
+                }
+                prev_char = Some(ch);
+            }
+        }
+    }
+
+    pos
+}
+
+/// A sub-word aware variant of [`textobject_word`]: stops at `camelCase` and
+/// `snake_case` boundaries so that e.g. `mis` on `foo_barBaz` with the cursor
+/// in `bar` only selects `bar`.
+pub fn textobject_sub_word(slice: RopeSlice, range: Range, textobject: TextObject) -> Range {
+    let pos = range.cursor(slice);
+
+    let word_start = find_sub_word_boundary(slice, pos, Direction::Backward);
+    let word_end = match slice.get_char(pos).map(categorize_char) {
+        None | Some(CharCategory::Whitespace | CharCategory::Eol) => pos,
+        _ => find_sub_word_boundary(slice, pos + 1, Direction::Forward),
+    };
+
+    if word_start == word_end {
+        return Range::new(word_start, word_end);
+    }
+
+    match textobject {
+        TextObject::Inside => Range::new(word_start, word_end),
+        TextObject::Around => {
+            let whitespace_count_right = slice
+                .chars_at(word_end)
+                .take_while(|c| char_is_whitespace(*c))
+                .count();
+
+            if whitespace_count_right > 0 {
+                Range::new(word_start, word_end + whitespace_count_right)
+            } else {
+                let whitespace_count_left = {
+                    let mut iter = slice.chars_at(word_start);
+                    iter.reverse();
+                    iter.take_while(|c| char_is_whitespace(*c)).count()
+                };
+                Range::new(word_start - whitespace_count_left, word_end)
+            }
+        }
+        TextObject::Movement => unreachable!(),
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum TextObject {
     Around,
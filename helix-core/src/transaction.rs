@@ -9,7 +9,7 @@
 pub type Deletion = (usize, usize);
 
 // TODO: pub(crate)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Operation {
     /// Move cursor by n characters.
     Retain(usize),
@@ -49,7 +49,7 @@ fn insert_offset(self, s: &str) -> usize {
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ChangeSet {
     pub(crate) changes: Vec<Operation>,
     /// The required document length. Will refuse to apply changes unless it matches.
@@ -496,7 +496,7 @@ pub fn changes_iter(&self) -> ChangeIterator {
 
 /// Transaction represents a single undoable unit of changes. Several changes can be grouped into
 /// a single transaction.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Transaction {
     changes: ChangeSet,
     selection: Option<Selection>,
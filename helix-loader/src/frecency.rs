@@ -0,0 +1,121 @@
+//! Frecency tracking for recently/frequently accepted picker items.
+//!
+//! Every time an item is accepted from a picker (a file opened, a command
+//! run, ...) it's recorded (count and last-access timestamp) to a small
+//! on-disk store in the cache directory, keyed by the picker's id and the
+//! item's own key. Pickers use the resulting scores to boost frequently/
+//! recently accepted items, speeding up day-to-day selection.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache_dir;
+
+fn frecency_file() -> PathBuf {
+    cache_dir().join("frecency.toml")
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct FrecencyEntry {
+    count: u32,
+    last_accessed: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FrecencyStore {
+    #[serde(flatten)]
+    entries: HashMap<String, FrecencyEntry>,
+}
+
+fn load_store() -> FrecencyStore {
+    std::fs::read_to_string(frecency_file())
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(store: &FrecencyStore) -> std::io::Result<()> {
+    let path = frecency_file();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let contents = toml::to_string(store).unwrap_or_default();
+    std::fs::write(path, contents)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Entries are stored under a single flat map keyed by `picker_id` and
+/// `item_key` so that every picker's accepts live in the same small TOML
+/// file without colliding with one another.
+fn store_key(picker_id: &str, item_key: &str) -> String {
+    format!("{picker_id}\u{1}{item_key}")
+}
+
+/// Record that `item_key` was just accepted from the picker identified by
+/// `picker_id` (see `Picker::with_frecency_id`).
+pub fn record_accept(picker_id: &str, item_key: &str) -> std::io::Result<()> {
+    let mut store = load_store();
+    let entry = store
+        .entries
+        .entry(store_key(picker_id, item_key))
+        .or_insert(FrecencyEntry {
+            count: 0,
+            last_accessed: 0,
+        });
+    entry.count += 1;
+    entry.last_accessed = now_secs();
+    save_store(&store)
+}
+
+/// Record that `path` was just opened. A thin wrapper over [`record_accept`]
+/// under the file picker's `"file"` id; called directly from `Editor::open`
+/// so every opened file is tracked, not only ones opened through the picker.
+pub fn record_access(path: &Path) -> std::io::Result<()> {
+    record_accept("file", &path.to_string_lossy())
+}
+
+/// Half-life for the recency decay applied in [`scores_for`]: an item's
+/// score halves for every week since it was last accepted, so an item
+/// accepted many times a long while ago doesn't permanently outrank one
+/// accepted yesterday.
+const HALF_LIFE_SECS: f64 = 7.0 * 24.0 * 3600.0;
+
+/// Loads the persisted store and returns a frecency score for every item
+/// previously accepted from the picker identified by `picker_id`, higher
+/// meaning more frequently/recently accepted. Reads the whole store once so
+/// callers should call this a single time per picker invocation rather than
+/// per item.
+pub fn scores_for(picker_id: &str) -> HashMap<String, f64> {
+    let now = now_secs();
+    let prefix = store_key(picker_id, "");
+    load_store()
+        .entries
+        .into_iter()
+        .filter_map(|(key, entry)| {
+            let item_key = key.strip_prefix(&prefix)?.to_owned();
+            let age_secs = now.saturating_sub(entry.last_accessed) as f64;
+            let decay = 0.5f64.powf(age_secs / HALF_LIFE_SECS);
+            Some((item_key, entry.count as f64 * decay))
+        })
+        .collect()
+}
+
+/// Convenience wrapper over [`scores_for`] for the file picker's `"file"`
+/// id, used to rank the initial (query-less) file crawl.
+pub fn scores() -> HashMap<PathBuf, f64> {
+    scores_for("file")
+        .into_iter()
+        .map(|(path, score)| (PathBuf::from(path), score))
+        .collect()
+}
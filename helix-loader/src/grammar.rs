@@ -196,6 +196,70 @@ pub fn build_grammars(target: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// The outcome of building a single grammar, reported to a
+/// [`build_grammars_with_progress`] caller as soon as that grammar finishes.
+/// Grammars build in parallel, so events may arrive in any order.
+pub enum GrammarBuildEvent {
+    Built(String),
+    AlreadyBuilt(String),
+    Failed(String, String),
+}
+
+/// The overall result of a [`build_grammars_with_progress`] run.
+#[derive(Default)]
+pub struct GrammarBuildSummary {
+    pub built: Vec<String>,
+    pub already_built: usize,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Like [`build_grammars`], but built for driving an in-editor command
+/// instead of the CLI: `on_event` is invoked from a worker thread as soon as
+/// each grammar finishes, so callers can surface incremental progress, and
+/// `only` optionally restricts the build to a subset of grammar ids (e.g.
+/// the grammars actually used by the current workspace).
+pub fn build_grammars_with_progress(
+    target: Option<String>,
+    only: Option<&HashSet<String>>,
+    on_event: impl Fn(GrammarBuildEvent) + Send + Sync + 'static,
+) -> Result<GrammarBuildSummary> {
+    ensure_git_is_available()?;
+
+    let mut grammars = get_grammar_configs()?;
+    if let Some(only) = only {
+        grammars.retain(|grammar| only.contains(&grammar.grammar_id));
+    }
+
+    let on_event = std::sync::Arc::new(on_event);
+    let results = run_parallel(grammars, move |grammar| {
+        let grammar_id = grammar.grammar_id.clone();
+        let result = build_grammar(grammar, target.as_deref());
+        match &result {
+            Ok(BuildStatus::AlreadyBuilt) => {
+                on_event(GrammarBuildEvent::AlreadyBuilt(grammar_id.clone()))
+            }
+            Ok(BuildStatus::Built) => on_event(GrammarBuildEvent::Built(grammar_id.clone())),
+            Err(error) => on_event(GrammarBuildEvent::Failed(
+                grammar_id.clone(),
+                format!("{error:#}"),
+            )),
+        }
+        result
+    });
+
+    let mut summary = GrammarBuildSummary::default();
+    for (grammar_id, res) in results {
+        match res {
+            Ok(BuildStatus::AlreadyBuilt) => summary.already_built += 1,
+            Ok(BuildStatus::Built) => summary.built.push(grammar_id),
+            Err(error) => summary.failed.push((grammar_id, format!("{error:#}"))),
+        }
+    }
+    summary.built.sort_unstable();
+
+    Ok(summary)
+}
+
 // Returns the set of grammar configurations the user requests.
 // Grammars are configured in the default and user `languages.toml` and are
 // merged. The `grammar_selection` key of the config is then used to filter
@@ -1,5 +1,8 @@
 pub mod config;
+pub mod frecency;
 pub mod grammar;
+pub mod trash;
+pub mod trust;
 
 use etcetera::base_strategy::{choose_base_strategy, BaseStrategy};
 use std::path::{Path, PathBuf};
@@ -0,0 +1,68 @@
+//! Soft-delete support: instead of permanently removing files, move them
+//! into a per-install trash directory so accidental deletions (from an LSP
+//! workspace edit, the file-tree explorer, or `:remove`) can be recovered
+//! from the filesystem. Callers gate this behind the `editor.trash-delete`
+//! config flag, falling back to a plain removal when it's disabled.
+
+use std::path::{Path, PathBuf};
+
+use crate::cache_dir;
+
+fn trash_dir() -> PathBuf {
+    cache_dir().join("trash")
+}
+
+/// Move `path` (file or directory) into the trash directory, returning the
+/// path it was moved to. Falls back to leaving the original error untouched
+/// if the trash directory can't be created.
+pub fn move_to_trash(path: &Path) -> std::io::Result<PathBuf> {
+    let dir = trash_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_owned())
+        .unwrap_or_else(|| std::ffi::OsString::from("unnamed"));
+
+    let mut dest = dir.join(&name);
+    let mut suffix = 0;
+    while dest.exists() {
+        suffix += 1;
+        dest = dir.join(format!("{}.{}", name.to_string_lossy(), suffix));
+    }
+
+    match std::fs::rename(path, &dest) {
+        Ok(()) => Ok(dest),
+        // `rename` can't move across filesystems/mounts, which is common
+        // here since the cache dir the trash lives under is frequently on a
+        // different one than the file being deleted. `path` is untouched by
+        // a failed rename, so fall back to a recursive copy followed by
+        // removing the original; only then is the rename error worth
+        // surfacing.
+        Err(rename_err) => match copy_recursive(path, &dest).and_then(|()| remove(path)) {
+            Ok(()) => Ok(dest),
+            Err(_) => Err(rename_err),
+        },
+    }
+}
+
+fn remove(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
+    if from.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        std::fs::copy(from, to).map(|_| ())
+    }
+}
@@ -0,0 +1,74 @@
+//! A minimal workspace trust model.
+//!
+//! Workspace-local configuration (`.helix/config.toml`, and anything else
+//! that lets a project direct the editor to run commands or load arbitrary
+//! config) is only honored for workspaces the user has explicitly marked as
+//! trusted. Decisions are keyed by a hash of the canonicalized workspace
+//! path and persisted to disk so the user isn't asked again for the same
+//! workspace.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache_dir;
+
+fn trust_file() -> std::path::PathBuf {
+    cache_dir().join("trust.toml")
+}
+
+/// A stable identifier for a workspace path, used as the key in the
+/// persisted allow/deny list so we don't store raw filesystem paths.
+fn workspace_hash(path: &Path) -> String {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut hasher = DefaultHasher::new();
+    canonical.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TrustStore {
+    #[serde(flatten)]
+    decisions: HashMap<String, bool>,
+}
+
+fn load_store() -> TrustStore {
+    std::fs::read_to_string(trust_file())
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(store: &TrustStore) -> std::io::Result<()> {
+    let path = trust_file();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let contents = toml::to_string(store).unwrap_or_default();
+    std::fs::write(path, contents)
+}
+
+/// Returns the persisted trust decision for `workspace`, or `None` if the
+/// user has not yet made a decision (workspace-local config should be
+/// treated as untrusted until a decision is recorded).
+pub fn trust_decision(workspace: &Path) -> Option<bool> {
+    load_store().decisions.get(&workspace_hash(workspace)).copied()
+}
+
+/// Persist an allow/deny decision for `workspace`.
+pub fn set_trust_decision(workspace: &Path, trusted: bool) -> std::io::Result<()> {
+    let mut store = load_store();
+    store.decisions.insert(workspace_hash(workspace), trusted);
+    save_store(&store)
+}
+
+/// Whether workspace-local configuration should currently be loaded for
+/// `workspace`. Unknown workspaces are untrusted by default.
+pub fn is_trusted(workspace: &Path) -> bool {
+    trust_decision(workspace).unwrap_or(false)
+}
@@ -357,6 +357,22 @@ pub fn supports_feature(&self, feature: LanguageServerFeature) -> bool {
                 capabilities.inlay_hint_provider,
                 Some(OneOf::Left(true) | OneOf::Right(InlayHintServerCapabilities::Options(_)))
             ),
+            LanguageServerFeature::InlineCompletion => {
+                matches!(capabilities.inline_completion_provider, Some(true))
+            }
+            LanguageServerFeature::InlineValue => matches!(
+                capabilities.inline_value_provider,
+                Some(OneOf::Left(true) | OneOf::Right(_))
+            ),
+            LanguageServerFeature::CodeLens => capabilities.code_lens_provider.is_some(),
+            LanguageServerFeature::DocumentColor => capabilities.color_provider.is_some(),
+            LanguageServerFeature::CallHierarchy => capabilities.call_hierarchy_provider.is_some(),
+            LanguageServerFeature::DocumentLink => capabilities.document_link_provider.is_some(),
+            LanguageServerFeature::RenameFiles => capabilities
+                .workspace
+                .as_ref()
+                .and_then(|workspace| workspace.file_operations.as_ref())
+                .is_some_and(|file_operations| file_operations.will_rename.is_some()),
         }
     }
 
@@ -1035,6 +1051,252 @@ pub fn text_document_range_inlay_hints(
         Some(self.call::<lsp::request::InlayHintRequest>(params))
     }
 
+    /// `textDocument/inlineCompletion`, gated on `inlineCompletionProvider`,
+    /// a proposed LSP capability at the time this client was pinned to a
+    /// pre-3.18 `lsp-types` version (see the `proposed` feature this crate
+    /// enables on it). Rendering is handled by the caller: like
+    /// `send_copilot_completion`, the result is adapted into the
+    /// `copilot_types` ghost-text pipeline rather than a separate one.
+    pub fn text_document_inline_completion(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        position: lsp::Position,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.inline_completion_provider {
+            Some(true) => (),
+            _ => return None,
+        }
+
+        let params = lsp::InlineCompletionParams {
+            text_document_position: lsp::TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+            context: lsp::InlineCompletionContext {
+                trigger_kind: lsp::InlineCompletionTriggerKind::INVOKED,
+                selected_completion_info: None,
+            },
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::InlineCompletionRequest>(params))
+    }
+
+    /// `textDocument/inlineValue`, used by debuggers to show variable values
+    /// next to the line they're stopped at. Only the protocol request is
+    /// implemented here; wiring it up to fire automatically when a debug
+    /// session stops is left for a follow-up, since that needs a hook into
+    /// `helix-dap`'s stopped-event handling rather than anything in this
+    /// client.
+    pub fn text_document_inline_value(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        range: lsp::Range,
+        stopped_location: lsp::Range,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.inline_value_provider {
+            Some(OneOf::Left(true) | OneOf::Right(_)) => (),
+            _ => return None,
+        }
+
+        let params = lsp::InlineValueParams {
+            text_document,
+            range,
+            context: lsp::InlineValueContext { stopped_location },
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+        };
+
+        Some(self.call::<lsp::request::InlineValueRequest>(params))
+    }
+
+    /// `textDocument/codeLens`, gated on `codeLensProvider`. Lenses whose
+    /// `command` is `None` need a follow-up `codeLens/resolve` call (see
+    /// [`Self::resolve_code_lens`]) before they can be rendered or executed.
+    pub fn text_document_code_lens(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.code_lens_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::CodeLensParams {
+            text_document,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::CodeLensRequest>(params))
+    }
+
+    /// `codeLens/resolve`, only needed for lenses the server sent without a
+    /// `command` (i.e. `resolveProvider: true` in `codeLensProvider`).
+    pub fn resolve_code_lens(
+        &self,
+        code_lens: lsp::CodeLens,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.code_lens_provider {
+            Some(lsp::CodeLensOptions {
+                resolve_provider: Some(true),
+            }) => (),
+            _ => return None,
+        }
+
+        Some(self.call::<lsp::request::CodeLensResolve>(code_lens))
+    }
+
+    /// `textDocument/documentColor`, gated on `colorProvider`. Finds every
+    /// color literal (CSS `#rrggbb`, JS color objects, ...) in the document
+    /// so they can be decorated with a swatch.
+    pub fn text_document_document_color(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.color_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::DocumentColorParams {
+            text_document,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::DocumentColor>(params))
+    }
+
+    /// `textDocument/colorPresentation`, used to turn a resolved color back
+    /// into source text (e.g. offering `#ff0000`, `rgb(255, 0, 0)` and
+    /// `hsl(0, 100%, 50%)` as edits for a literal under the cursor).
+    pub fn text_document_color_presentation(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        color: lsp::Color,
+        range: lsp::Range,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.color_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::ColorPresentationParams {
+            text_document,
+            color,
+            range,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::ColorPresentationRequest>(params))
+    }
+
+    /// `textDocument/prepareCallHierarchy`, gated on `callHierarchyProvider`.
+    /// Resolves the symbol under the cursor to one or more call hierarchy
+    /// items, each of which can then be fed to [`Self::incoming_calls`] or
+    /// [`Self::outgoing_calls`].
+    pub fn prepare_call_hierarchy(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        position: lsp::Position,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.call_hierarchy_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::CallHierarchyPrepareParams {
+            text_document_position_params: lsp::TextDocumentPositionParams::new(
+                text_document,
+                position,
+            ),
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+        };
+
+        Some(self.call::<lsp::request::CallHierarchyPrepare>(params))
+    }
+
+    /// `callHierarchy/incomingCalls`: who calls `item`.
+    pub fn incoming_calls(
+        &self,
+        item: lsp::CallHierarchyItem,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.call_hierarchy_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::CallHierarchyIncomingCallsParams {
+            item,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::CallHierarchyIncomingCalls>(params))
+    }
+
+    /// `callHierarchy/outgoingCalls`: what `item` calls.
+    pub fn outgoing_calls(
+        &self,
+        item: lsp::CallHierarchyItem,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.call_hierarchy_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::CallHierarchyOutgoingCallsParams {
+            item,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::CallHierarchyOutgoingCalls>(params))
+    }
+
+    /// `textDocument/documentLink`, gated on `documentLinkProvider`. Finds
+    /// every link (URL, or a server-resolved in-workspace reference) in the
+    /// document so that the one under the cursor can be opened directly.
+    pub fn text_document_document_link(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.document_link_provider {
+            Some(_) => (),
+            None => return None,
+        }
+
+        let params = lsp::DocumentLinkParams {
+            text_document,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::DocumentLinkRequest>(params))
+    }
+
     pub fn text_document_hover(
         &self,
         text_document: lsp::TextDocumentIdentifier,
@@ -1483,4 +1745,50 @@ pub fn did_change_watched_files(
             changes,
         })
     }
+
+    /// Asks the server to compute the edits (e.g. import rewrites) needed
+    /// before `old_uri` is renamed to `new_uri`, which the caller is
+    /// expected to apply before performing the rename on disk.
+    pub fn will_rename_files(
+        &self,
+        old_uri: lsp::Url,
+        new_uri: lsp::Url,
+    ) -> Option<impl Future<Output = Result<Value>>> {
+        if !self.supports_feature(LanguageServerFeature::RenameFiles) {
+            return None;
+        }
+
+        let params = lsp::RenameFilesParams {
+            files: vec![lsp::FileRename {
+                old_uri: old_uri.to_string(),
+                new_uri: new_uri.to_string(),
+            }],
+        };
+
+        Some(self.call::<lsp::request::WillRenameFiles>(params))
+    }
+
+    /// Notifies the server that `old_uri` has been renamed to `new_uri` on
+    /// disk, after the rename has actually happened.
+    pub fn did_rename_files(
+        &self,
+        old_uri: lsp::Url,
+        new_uri: lsp::Url,
+    ) -> Option<impl Future<Output = std::result::Result<(), Error>>> {
+        let capabilities = self.capabilities.get().unwrap();
+        capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.file_operations.as_ref())
+            .and_then(|file_operations| file_operations.did_rename.as_ref())?;
+
+        let params = lsp::RenameFilesParams {
+            files: vec![lsp::FileRename {
+                old_uri: old_uri.to_string(),
+                new_uri: new_uri.to_string(),
+            }],
+        };
+
+        Some(self.notify::<lsp::notification::DidRenameFiles>(params))
+    }
 }
@@ -1,4 +1,4 @@
-use lsp_types::{request::Request, Position, Range};
+use lsp_types::{request::Request, InlineCompletionItem, Position, Range};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
@@ -47,3 +47,31 @@ pub struct Completion {
     region: Option<(usize, usize)>,
     pub text: String,
 }
+
+impl Completion {
+    /// Adapts a standard LSP `textDocument/inlineCompletion` item into the
+    /// shape `CopilotState::populate` expects, so inline completions from
+    /// any language server render through the same ghost-text pipeline as
+    /// Copilot's own completions. `fallback_position` is used when the item
+    /// has no `range` of its own; the spec allows this, anchoring the
+    /// completion at the requested cursor position instead.
+    pub fn from_inline_completion_item(
+        item: InlineCompletionItem,
+        fallback_position: Position,
+    ) -> Self {
+        let range = item.range.unwrap_or(Range {
+            start: fallback_position,
+            end: fallback_position,
+        });
+        Completion {
+            uuid: String::new(),
+            range,
+            display_text: item.insert_text.clone(),
+            position: range.start,
+            doc_version: None,
+            point: None,
+            region: None,
+            text: item.insert_text,
+        }
+    }
+}
@@ -4,7 +4,10 @@
     chars::char_is_word,
     diagnostic::{DiagnosticTag, NumberOrString, Severity},
     path::get_relative_path,
-    pos_at_coords, syntax, Selection,
+    pos_at_coords,
+    regex::RegexBuilder,
+    shellwords::Shellwords,
+    syntax, Selection,
 };
 use helix_lsp::{
     lsp::{self, notification::Notification},
@@ -24,14 +27,14 @@
 use tui::backend::Backend;
 
 use crate::{
-    args::Args,
-    commands::apply_workspace_edit,
+    args::{Args, PostLoadAction},
+    commands::{apply_workspace_edit, lsp::refresh_workspace_diagnostics_picker},
     compositor::{Compositor, Event},
     config::Config,
     handlers,
     job::Jobs,
     keymap::Keymaps,
-    ui::{self, overlay::overlaid},
+    ui::{self, overlay::overlaid, PromptEvent},
 };
 
 use log::{debug, error, warn};
@@ -54,7 +57,7 @@
 use tui::backend::TestBackend;
 
 #[cfg(not(feature = "integration"))]
-type TerminalBackend = CrosstermBackend<std::io::Stdout>;
+type TerminalBackend = CrosstermBackend<Box<dyn std::io::Write + Send>>;
 
 #[cfg(feature = "integration")]
 type TerminalBackend = TestBackend;
@@ -100,11 +103,65 @@ fn setup_integration_logging() {
         .apply();
 }
 
+/// Applies a `+/pattern` startup argument by moving the cursor of the
+/// current view to the first match of `pattern` in the current document,
+/// mirroring the analogous vim startup option. Does nothing (besides
+/// reporting an error) if the pattern doesn't compile or has no match.
+fn apply_goto_match(editor: &mut Editor, pattern: &str) {
+    let regex = match RegexBuilder::new(pattern).multi_line(true).build() {
+        Ok(regex) => regex,
+        Err(err) => {
+            editor.set_error(format!("invalid regex in `+/{}`: {}", pattern, err));
+            return;
+        }
+    };
+
+    let (view, doc) = current!(editor);
+    let contents = doc.text().slice(..).to_string();
+    let Some(mat) = regex.find(&contents) else {
+        editor.set_error(format!("pattern not found: {}", pattern));
+        return;
+    };
+
+    let start = doc.text().byte_to_char(mat.start());
+    doc.set_selection(view.id, Selection::point(start));
+    align_view(doc, view, Align::Center);
+}
+
 impl Application {
     pub fn new(
         args: Args,
         config: Config,
         syn_loader_conf: syntax::Configuration,
+    ) -> Result<Self, Error> {
+        #[cfg(not(feature = "integration"))]
+        let writer: Box<dyn std::io::Write + Send> = Box::new(stdout());
+        #[cfg(feature = "integration")]
+        let writer = ();
+
+        Self::new_with_writer(args, config, syn_loader_conf, writer)
+    }
+
+    /// Headless variant of [`Self::new`] used by [`crate::daemon`]: renders
+    /// to `writer` (a socket-backed sink for an attached client, typically)
+    /// instead of the real terminal, so the daemon process itself never
+    /// touches OS terminal state like raw mode or the alternate screen.
+    #[cfg(all(not(feature = "integration"), unix))]
+    pub fn new_headless(
+        args: Args,
+        config: Config,
+        syn_loader_conf: syntax::Configuration,
+        writer: Box<dyn std::io::Write + Send>,
+    ) -> Result<Self, Error> {
+        Self::new_with_writer(args, config, syn_loader_conf, writer)
+    }
+
+    fn new_with_writer(
+        args: Args,
+        config: Config,
+        syn_loader_conf: syntax::Configuration,
+        #[cfg(not(feature = "integration"))] writer: Box<dyn std::io::Write + Send>,
+        #[cfg(feature = "integration")] _writer: (),
     ) -> Result<Self, Error> {
         #[cfg(feature = "integration")]
         setup_integration_logging();
@@ -134,7 +191,7 @@ pub fn new(
         let syn_loader = std::sync::Arc::new(syntax::Loader::new(syn_loader_conf));
 
         #[cfg(not(feature = "integration"))]
-        let backend = CrosstermBackend::new(stdout(), &config.editor);
+        let backend = CrosstermBackend::new(writer, &config.editor);
 
         #[cfg(feature = "integration")]
         let backend = TestBackend::new(120, 150);
@@ -160,6 +217,8 @@ pub fn new(
         let editor_view = Box::new(ui::EditorView::new(Keymaps::new(keys)));
         compositor.push(editor_view);
 
+        let mut jobs = Jobs::new();
+
         if args.load_tutor {
             let path = helix_loader::runtime_file(Path::new("tutor"));
             editor.open(&path, Action::VerticalSplit)?;
@@ -214,14 +273,43 @@ pub fn new(
                 let (view, doc) = current!(editor);
                 align_view(doc, view, Align::Center);
             }
-        } else if stdin().is_tty() || cfg!(feature = "integration") {
+        } else if cfg!(feature = "integration") {
+            editor.new_file(Action::VerticalSplit);
+        } else if stdin().is_tty() {
             editor.new_file(Action::VerticalSplit);
+            compositor.push(Box::new(ui::Dashboard::new()));
         } else {
             editor
                 .new_file_from_stdin(Action::VerticalSplit)
                 .unwrap_or_else(|_| editor.new_file(Action::VerticalSplit));
         }
 
+        for action in args.post_load_actions {
+            match action {
+                PostLoadAction::GotoMatch(pattern) => {
+                    apply_goto_match(&mut editor, &pattern);
+                }
+                PostLoadAction::RunCommand(command) => {
+                    let mut cx = crate::compositor::Context {
+                        editor: &mut editor,
+                        jobs: &mut jobs,
+                        scroll: None,
+                    };
+                    let shellwords = Shellwords::from(&command);
+                    let args = shellwords.words();
+                    if let Some(cmd) = args.first().and_then(|name| {
+                        crate::commands::typed::TYPABLE_COMMAND_MAP.get(name as &str)
+                    }) {
+                        if let Err(e) = (cmd.fun)(&mut cx, &args[1..], PromptEvent::Validate) {
+                            cx.editor.set_error(format!("{}", e));
+                        }
+                    } else if let Some(name) = args.first() {
+                        cx.editor.set_error(format!("no such command: '{}'", name));
+                    }
+                }
+            }
+        }
+
         editor.set_theme(theme);
 
         #[cfg(windows)]
@@ -247,7 +335,7 @@ pub fn new(
             syn_loader,
 
             signals,
-            jobs: Jobs::new(),
+            jobs,
             lsp_progress: LspProgressMap::new(),
         };
 
@@ -287,6 +375,32 @@ async fn render(&mut self) {
         self.terminal.draw(pos, kind).unwrap();
     }
 
+    /// Renders the current compositor state and returns it as plain text,
+    /// one line per row with trailing whitespace trimmed. Lets integration
+    /// tests assert on pickers, prompts and popups the same way they assert
+    /// on document contents, instead of reaching for `sleep`s to wait for a
+    /// component to draw.
+    #[cfg(feature = "integration")]
+    pub async fn render_to_string(&mut self) -> String {
+        self.render().await;
+        let area = *self.terminal.backend().buffer().area();
+        let buffer = self.terminal.backend().buffer();
+
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                if let Some(cell) = buffer.get(x, y) {
+                    text.push_str(&cell.symbol);
+                }
+            }
+            while text.ends_with(' ') {
+                text.pop();
+            }
+            text.push('\n');
+        }
+        text
+    }
+
     pub async fn event_loop<S>(&mut self, input_stream: &mut S)
     where
         S: Stream<Item = std::io::Result<crossterm::event::Event>> + Unpin,
@@ -319,7 +433,15 @@ pub async fn event_loop_until_idle<S>(&mut self, input_stream: &mut S) -> bool
                         return false;
                     };
                 }
-                Some(event) = input_stream.next() => {
+                event = input_stream.next() => {
+                    // `None` means the input stream has closed for good (e.g. a
+                    // daemon's attached client disconnected) rather than just
+                    // having nothing ready yet, so there's nothing left for this
+                    // loop to wait on; let the caller decide what to do next
+                    // (the daemon's accept loop moves on to the next client).
+                    let Some(event) = event else {
+                        return false;
+                    };
                     self.handle_terminal_events(event).await;
                 }
                 Some(callback) = self.jobs.callbacks.recv() => {
@@ -333,9 +455,18 @@ pub async fn event_loop_until_idle<S>(&mut self, input_stream: &mut S) -> bool
                         helix_event::status::Severity::Warning => Severity::Warning,
                         helix_event::status::Severity::Error => Severity::Error,
                     };
-                    // TODO: show multiple status messages at once to avoid clobbering
-                    self.editor.status_msg = Some((msg.message, severity));
+                    self.editor.notifications.push(severity, msg.message);
                     helix_event::request_redraw();
+                    // Dropping the toast after its display duration doesn't need a
+                    // job callback since `NotificationStore::visible` already filters
+                    // by elapsed time; just make sure a redraw happens once it expires.
+                    tokio::spawn(async {
+                        tokio::time::sleep(std::time::Duration::from_secs(
+                            helix_view::notification::TOAST_DURATION_SECS,
+                        ))
+                        .await;
+                        helix_event::request_redraw();
+                    });
                 }
                 Some(callback) = self.jobs.wait_futures.next() => {
                     self.jobs.handle_callback(&mut self.editor, &mut self.compositor, callback);
@@ -377,6 +508,11 @@ pub fn handle_config_events(&mut self, config_event: ConfigEvent) {
                 };
                 self.config.store(Arc::new(app_config));
             }
+
+            ConfigEvent::ReloadGrammars => match self.refresh_language_config() {
+                Ok(()) => self.editor.set_status("Grammars and queries reloaded"),
+                Err(err) => self.editor.set_error(err.to_string()),
+            },
         }
 
         // Update all the relevant members in the editor after updating
@@ -531,6 +667,69 @@ pub async fn handle_idle_timeout(&mut self) {
         if should_render || self.editor.needs_redraw {
             self.render().await;
         }
+
+        if self.editor.log_follow.is_some() {
+            // Shared with LSP `didChangeWatchedFiles`: anything the poll
+            // finds changed is forwarded there too, see
+            // `helix_view::fs_watcher`.
+            for path in self.editor.fs_watcher.poll_changed() {
+                self.editor
+                    .language_servers
+                    .file_event_handler
+                    .file_changed(path.clone());
+                self.follow_log(&path);
+            }
+            // The watcher only polls and never wakes the event loop itself,
+            // so keep polling by re-arming the idle timer for as long as
+            // `:log-follow` is active.
+            self.editor.reset_idle_timer();
+        }
+    }
+
+    /// Reloads the document being followed via `:log-follow` from disk and
+    /// jumps its view to the new last line, so newly appended lines show up.
+    /// No-op unless `changed_path` is the path of the followed document.
+    fn follow_log(&mut self, changed_path: &std::path::Path) {
+        let Some(doc_id) = self.editor.log_follow else {
+            return;
+        };
+
+        let following_changed_path = self
+            .editor
+            .document(doc_id)
+            .and_then(|doc| doc.path())
+            .map_or(false, |path| path == changed_path);
+        if !following_changed_path {
+            return;
+        }
+
+        let view_id = self
+            .editor
+            .tree
+            .views()
+            .find(|(view, _focus)| view.doc == doc_id)
+            .map(|(view, _focus)| view.id);
+
+        let Some(view_id) = view_id else {
+            // The view showing this document was closed; stop following it.
+            self.editor.log_follow = None;
+            return;
+        };
+
+        let scrolloff = self.editor.config().scrolloff;
+        let view = view_mut!(self.editor, view_id);
+        let doc = doc_mut!(self.editor, &doc_id);
+        if doc.reload(view, &self.editor.diff_providers).is_err() {
+            // The file may be transiently unreadable (e.g. rotated); leave
+            // the buffer as-is and try again on the next idle timeout.
+            return;
+        }
+
+        let view = view_mut!(self.editor, view_id);
+        let doc = doc_mut!(self.editor, &doc_id);
+        let pos = doc.text().len_chars();
+        doc.set_selection(view.id, Selection::point(pos));
+        view.ensure_cursor_in_view(doc, scrolloff);
     }
 
     pub fn handle_document_write(&mut self, doc_save_event: DocumentSavedEventResult) {
@@ -896,6 +1095,7 @@ macro_rules! language_server {
                             doc.replace_diagnostics(diagnostics, unchaged_diag_sources, server_id);
                         }
 
+                        let url = params.uri.clone();
                         let diagnostics = params.diagnostics.into_iter().map(|d| (d, server_id));
 
                         // Insert the original lsp::Diagnostics here because we may have no open document
@@ -918,6 +1118,10 @@ macro_rules! language_server {
                         diagnostics.sort_unstable_by_key(|(d, server_id)| {
                             (d.severity, d.range.start, *server_id)
                         });
+
+                        // Keep an already-open workspace diagnostics picker live instead of
+                        // leaving it showing a stale snapshot from when it was opened.
+                        refresh_workspace_diagnostics_picker(&self.editor, &url, diagnostics);
                     }
                     Notification::ShowMessage(params) => {
                         log::warn!("unhandled window/showMessage: {:?}", params);
@@ -1079,10 +1283,12 @@ macro_rules! language_server {
                         let language_server = language_server!();
                         if language_server.is_initialized() {
                             let offset_encoding = language_server.offset_encoding();
+                            let label = params.label.as_deref().unwrap_or("apply workspace edit");
                             let res = apply_workspace_edit(
                                 &mut self.editor,
                                 offset_encoding,
                                 &params.edit,
+                                label,
                             );
 
                             Ok(json!(lsp::ApplyWorkspaceEditResponse {
@@ -1237,6 +1443,14 @@ pub async fn run<S>(&mut self, input_stream: &mut S) -> Result<i32, Error>
         Ok(self.editor.exit_code)
     }
 
+    /// Forces the next render to redraw the whole screen rather than only
+    /// the cells that changed. Used by [`crate::daemon`] when a new client
+    /// attaches, since it starts out with a blank terminal.
+    #[cfg(unix)]
+    pub fn request_full_redraw(&mut self) {
+        self.compositor.need_full_redraw();
+    }
+
     pub async fn close(&mut self) -> Vec<anyhow::Error> {
         // [NOTE] we intentionally do not return early for errors because we
         //        want to try to run as much cleanup as we can, regardless of
@@ -3,6 +3,17 @@
 use helix_view::tree::Layout;
 use std::path::{Path, PathBuf};
 
+/// A `+/pattern` or `+:command` argument, applied once all files given at
+/// startup are loaded, to the document that has focus then (the last file
+/// given). Named after the analogous vim `+cmd` startup option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostLoadAction {
+    /// `+/pattern` — move the cursor to the first match of a regex.
+    GotoMatch(String),
+    /// `+:command` — run a typable command, e.g. `+:set-language rust`.
+    RunCommand(String),
+}
+
 #[derive(Default)]
 pub struct Args {
     pub display_help: bool,
@@ -18,6 +29,17 @@ pub struct Args {
     pub config_file: Option<PathBuf>,
     pub files: Vec<(PathBuf, Position)>,
     pub working_directory: Option<PathBuf>,
+    pub post_load_actions: Vec<PostLoadAction>,
+    /// Run as a headless daemon: the editor core runs with no attached
+    /// terminal, and clients connect over a Unix domain socket (see
+    /// [`crate::daemon`]).
+    pub headless: bool,
+    /// Attach to a daemon started with `--headless` instead of starting a
+    /// new instance.
+    pub attach: bool,
+    /// Socket path used by `--headless`/`--attach`. Defaults to
+    /// [`helix_loader::cache_dir`]`().join("daemon.sock")`.
+    pub socket_path: Option<PathBuf>,
 }
 
 impl Args {
@@ -45,6 +67,11 @@ pub fn parse_args() -> Result<Args> {
                     args.health = true;
                     args.health_arg = argv.next_if(|opt| !opt.starts_with('-'));
                 }
+                "--headless" => args.headless = true,
+                "--attach" => {
+                    args.attach = true;
+                    args.socket_path = argv.next_if(|opt| !opt.starts_with('-')).map(PathBuf::from);
+                }
                 "-g" | "--grammar" => match argv.next().as_deref() {
                     Some("fetch") => args.fetch_grammars = true,
                     Some("build") => args.build_grammars = true,
@@ -88,6 +115,12 @@ pub fn parse_args() -> Result<Args> {
                         }
                     }
                 }
+                arg if arg.starts_with("+/") => args
+                    .post_load_actions
+                    .push(PostLoadAction::GotoMatch(arg[2..].to_string())),
+                arg if arg.starts_with("+:") => args
+                    .post_load_actions
+                    .push(PostLoadAction::RunCommand(arg[2..].to_string())),
                 arg => args.files.push(parse_file(arg)),
             }
         }
@@ -1,4 +1,5 @@
 pub(crate) mod dap;
+pub(crate) mod export;
 pub(crate) mod lsp;
 pub(crate) mod typed;
 
@@ -32,10 +33,11 @@
 };
 use helix_view::{
     document::{FormatterError, Mode, SCRATCH_BUFFER_NAME},
-    editor::Action,
+    editor::{Action, PasteSelectionMismatch},
     info::Info,
     input::KeyEvent,
     keyboard::KeyCode,
+    quickfix::QuickfixEntry,
     tree,
     view::View,
     Document, DocumentId, Editor, ViewId,
@@ -245,6 +247,9 @@ pub fn doc(&self) -> &str {
         move_prev_long_word_start, "Move to start of previous long word",
         move_next_long_word_end, "Move to end of next long word",
         move_prev_long_word_end, "Move to end of previous long word",
+        move_next_sub_word_start, "Move to start of next sub word",
+        move_prev_sub_word_start, "Move to start of previous sub word",
+        move_next_sub_word_end, "Move to end of next sub word",
         move_parent_node_end, "Move to end of the parent node",
         move_parent_node_start, "Move to beginning of the parent node",
         extend_next_word_start, "Extend to start of next word",
@@ -255,6 +260,9 @@ pub fn doc(&self) -> &str {
         extend_prev_long_word_start, "Extend to start of previous long word",
         extend_next_long_word_end, "Extend to end of next long word",
         extend_prev_long_word_end, "Extend to end of prev long word",
+        extend_next_sub_word_start, "Extend to start of next sub word",
+        extend_prev_sub_word_start, "Extend to start of previous sub word",
+        extend_next_sub_word_end, "Extend to end of next sub word",
         extend_parent_node_end, "Extend to end of the parent node",
         extend_parent_node_start, "Extend to beginning of the parent node",
         find_till_char, "Move till next occurrence of char",
@@ -282,6 +290,8 @@ pub fn doc(&self) -> &str {
         select_regex, "Select all regex matches inside selections",
         split_selection, "Split selections on regex matches",
         split_selection_on_newline, "Split selection on newlines",
+        split_selection_on_treesitter_object, "Split selection on tree-sitter object",
+        toggle_node_expansion, "Toggle single-line/multi-line for enclosing bracketed node",
         merge_selections, "Merge selections",
         merge_consecutive_selections, "Merge consecutive selections",
         search, "Search for regex pattern",
@@ -311,9 +321,14 @@ pub fn doc(&self) -> &str {
         file_picker, "Open file picker",
         file_picker_in_current_buffer_directory, "Open file picker at current buffers's directory",
         file_picker_in_current_directory, "Open file picker at current working directory",
+        file_explorer, "Open directory browser at current buffer's directory",
         code_action, "Perform code action",
         buffer_picker, "Open buffer picker",
         jumplist_picker, "Open jumplist picker",
+        buffer_line_picker, "Fuzzy-match lines in the current buffer",
+        registers_picker, "Open register picker",
+        csv_next_column, "Move to the next delimiter-separated field on the current line",
+        csv_previous_column, "Move to the previous delimiter-separated field on the current line",
         symbol_picker, "Open symbol picker",
         select_references_to_symbol_under_cursor, "Select symbol references",
         workspace_symbol_picker, "Open workspace symbol picker",
@@ -328,6 +343,7 @@ pub fn doc(&self) -> &str {
         select_mode, "Enter selection extend mode",
         exit_select_mode, "Exit selection mode",
         goto_definition, "Goto definition",
+        goto_definition_peek, "Peek definition in a picker without jumping",
         goto_declaration, "Goto declaration",
         add_newline_above, "Add newline above",
         add_newline_below, "Add newline below",
@@ -339,6 +355,12 @@ pub fn doc(&self) -> &str {
         goto_file_hsplit, "Goto files in selection (hsplit)",
         goto_file_vsplit, "Goto files in selection (vsplit)",
         goto_reference, "Goto references",
+        call_hierarchy_incoming, "Show incoming calls in a picker",
+        call_hierarchy_outgoing, "Show outgoing calls in a picker",
+        goto_link, "Open the link under the cursor",
+        goto_parent_symbol, "Goto the symbol enclosing the cursor",
+        goto_next_symbol, "Goto the next sibling symbol",
+        goto_previous_symbol, "Goto the previous sibling symbol",
         goto_window_top, "Goto window top",
         goto_window_center, "Goto window center",
         goto_window_bottom, "Goto window bottom",
@@ -351,6 +373,9 @@ pub fn doc(&self) -> &str {
         goto_last_diag, "Goto last diagnostic",
         goto_next_diag, "Goto next diagnostic",
         goto_prev_diag, "Goto previous diagnostic",
+        goto_next_quickfix, "Goto next quickfix list entry",
+        goto_prev_quickfix, "Goto previous quickfix list entry",
+        cmdline_window_accept, "Run the content of an open command-line window",
         goto_next_change, "Goto next change",
         goto_prev_change, "Goto previous change",
         goto_first_change, "Goto first change",
@@ -369,6 +394,8 @@ pub fn doc(&self) -> &str {
         signature_help, "Show signature help",
         smart_tab, "Insert tab if all cursors have all whitespace to their left; otherwise, run a separate command.",
         apply_copilot_completion, "Apply copilot completion",
+        accept_copilot_completion_word, "Accept the first word of the copilot completion",
+        accept_copilot_completion_line, "Accept the first line of the copilot completion",
         show_or_next_copilot_completion, "Show or cycle forward copilot completion",
         hide_or_prev_copilot_completion, "Hide or cycle backwards copilot completion",
         toggle_copilot_auto, "Toggle automatic rendering of copilot completions",
@@ -402,6 +429,8 @@ pub fn doc(&self) -> &str {
         paste_clipboard_before, "Paste clipboard before selections",
         paste_primary_clipboard_after, "Paste primary clipboard after selections",
         paste_primary_clipboard_before, "Paste primary clipboard before selections",
+        paste_clipboard_after_as_selections, "Paste clipboard after selections, one selection per pasted line",
+        paste_clipboard_before_as_selections, "Paste clipboard before selections, one selection per pasted line",
         indent, "Indent selection",
         unindent, "Unindent selection",
         format_selections, "Format selection",
@@ -421,6 +450,8 @@ pub fn doc(&self) -> &str {
         rotate_selections_backward, "Rotate selections backward",
         rotate_selection_contents_forward, "Rotate selection contents forward",
         rotate_selection_contents_backward, "Rotate selections contents backward",
+        swap_selections_content, "Swap the contents of two selections",
+        rename_symbol_in_buffer, "Rename all occurrences of the word under the cursor in this buffer",
         reverse_selection_contents, "Reverse selections contents",
         expand_selection, "Expand selection to parent syntax node",
         shrink_selection, "Shrink selection to previously expanded syntax node",
@@ -446,8 +477,10 @@ pub fn doc(&self) -> &str {
         vsplit_new, "Vertical right split scratch buffer",
         wclose, "Close window",
         wonly, "Close windows except current",
+        toggle_view_pin, "Pin/unpin the current view's buffer",
         select_register, "Select register",
         insert_register, "Insert register",
+        inspect_register, "Inspect register values",
         align_view_middle, "Align view middle",
         align_view_top, "Align view top",
         align_view_center, "Align view center",
@@ -1078,6 +1111,18 @@ fn move_next_word_end(cx: &mut Context) {
     move_word_impl(cx, movement::move_next_word_end)
 }
 
+fn move_next_sub_word_start(cx: &mut Context) {
+    move_word_impl(cx, movement::move_next_sub_word_start)
+}
+
+fn move_prev_sub_word_start(cx: &mut Context) {
+    move_word_impl(cx, movement::move_prev_sub_word_start)
+}
+
+fn move_next_sub_word_end(cx: &mut Context) {
+    move_word_impl(cx, movement::move_next_sub_word_end)
+}
+
 fn move_next_long_word_start(cx: &mut Context) {
     move_word_impl(cx, movement::move_next_long_word_start)
 }
@@ -1238,6 +1283,18 @@ fn extend_prev_word_start(cx: &mut Context) {
     extend_word_impl(cx, movement::move_prev_word_start)
 }
 
+fn extend_next_sub_word_start(cx: &mut Context) {
+    extend_word_impl(cx, movement::move_next_sub_word_start)
+}
+
+fn extend_prev_sub_word_start(cx: &mut Context) {
+    extend_word_impl(cx, movement::move_prev_sub_word_start)
+}
+
+fn extend_next_sub_word_end(cx: &mut Context) {
+    extend_word_impl(cx, movement::move_next_sub_word_end)
+}
+
 fn extend_next_word_end(cx: &mut Context) {
     extend_word_impl(cx, movement::move_next_word_end)
 }
@@ -1878,6 +1935,90 @@ fn split_selection_on_newline(cx: &mut Context) {
     doc.set_selection(view.id, selection);
 }
 
+/// Split each selection into one selection per tree-sitter textobject node of
+/// the requested kind (e.g. `f` for function, `a` for argument/parameter)
+/// that lies within it, using the same object names as `select_textobject`.
+fn split_selection_on_treesitter_object(cx: &mut Context) {
+    cx.on_next_key(move |cx, event| {
+        cx.editor.autoinfo = None;
+        let ch = match event.char() {
+            Some(ch) => ch,
+            None => return,
+        };
+        let object_name = match ch {
+            't' => "class",
+            'f' => "function",
+            'a' => "parameter",
+            'c' => "comment",
+            'T' => "test",
+            _ => return,
+        };
+
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let (lang_config, syntax) = match doc.language_config().zip(doc.syntax()) {
+            Some(t) => t,
+            None => {
+                cx.editor
+                    .set_status("Tree-sitter is not available in the current buffer");
+                return;
+            }
+        };
+        let query = match lang_config.textobject_query() {
+            Some(query) => query,
+            None => {
+                cx.editor
+                    .set_status("No textobject query available for this language");
+                return;
+            }
+        };
+
+        let capture_name = format!("{}.inside", object_name);
+        let root = syntax.tree().root_node();
+
+        let mut ranges = Vec::new();
+        for selection_range in doc.selection(view.id).ranges() {
+            let byte_range = text.char_to_byte(selection_range.from())
+                ..text.char_to_byte(selection_range.to());
+            let mut cursor = helix_core::tree_sitter::QueryCursor::new();
+            let mut found = false;
+            if let Some(nodes) = query.capture_nodes(&capture_name, root, text, &mut cursor) {
+                for node in nodes {
+                    let node_range = node.byte_range();
+                    if node_range.start >= byte_range.start && node_range.end <= byte_range.end {
+                        found = true;
+                        ranges.push(Range::new(
+                            text.byte_to_char(node_range.start),
+                            text.byte_to_char(node_range.end),
+                        ));
+                    }
+                }
+            }
+            if !found {
+                ranges.push(selection_range);
+            }
+        }
+
+        if ranges.is_empty() {
+            return;
+        }
+        ranges.sort_unstable_by_key(|range| range.from());
+        let selection = Selection::new(ranges.into(), 0);
+        doc.set_selection(view.id, selection);
+    });
+
+    cx.editor.autoinfo = Some(Info::new(
+        "Split selection on tree-sitter object",
+        &[
+            ("t", "Class"),
+            ("f", "Function"),
+            ("a", "Argument/parameter"),
+            ("c", "Comment"),
+            ("T", "Test"),
+        ],
+    ));
+}
+
 fn merge_selections(cx: &mut Context) {
     let (view, doc) = current!(cx.editor);
     let selection = doc.selection(view.id).clone().merge_ranges();
@@ -2120,6 +2261,87 @@ fn search_selection(cx: &mut Context) {
     }
 }
 
+/// Rename every occurrence of the word under the primary cursor within the
+/// current buffer. This is a lightweight, LSP-free stand-in for languages
+/// without rename support: it matches by word-bounded text, not tree-sitter
+/// scope or a project-wide grep, so it's only appropriate for symbols that
+/// are effectively file-scoped (locals, file-private items) -- there's no
+/// cross-file occurrence gathering or picker-based per-occurrence review
+/// here, just a single buffer-wide confirm before the replace is applied.
+fn rename_symbol_in_buffer(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let range = doc.selection(view.id).primary();
+
+    let word_range = textobject::textobject_word(text, range, textobject::TextObject::Inside, 1, false);
+    let word = word_range.fragment(text).into_owned();
+    if word.is_empty() || !word.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        cx.editor
+            .set_status("No identifier under the cursor to rename");
+        return;
+    }
+
+    let regex = match RegexBuilder::new(&format!(r"\b{}\b", regex::escape(&word))).build() {
+        Ok(regex) => regex,
+        Err(err) => {
+            cx.editor.set_error(err.to_string());
+            return;
+        }
+    };
+
+    ui::prompt(
+        cx,
+        format!("rename '{}' to:", word).into(),
+        None,
+        ui::completers::none,
+        move |cx, input, event| {
+            if event != PromptEvent::Validate || input.is_empty() {
+                return;
+            }
+            let doc = doc!(cx.editor);
+            let text = doc.text().slice(..);
+            let contents = text.to_string();
+            let new_name: Tendril = input.into();
+            let count = regex.find_iter(&contents).count();
+            if count == 0 {
+                cx.editor.set_status("No occurrences found");
+                return;
+            }
+
+            cx.editor.set_status(format!(
+                "rename {} occurrence(s) of '{}' to '{}'? (y/n)",
+                count, word, new_name
+            ));
+            cx.on_next_key(move |cx, event| {
+                if event.char() != Some('y') {
+                    cx.editor.set_status("Rename cancelled");
+                    return;
+                }
+
+                let (view, doc) = current!(cx.editor);
+                let text = doc.text().slice(..);
+                let contents = text.to_string();
+                let changes = regex
+                    .find_iter(&contents)
+                    .map(|m| {
+                        (
+                            text.byte_to_char(m.start()),
+                            text.byte_to_char(m.end()),
+                            Some(new_name.clone()),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let count = changes.len();
+                let transaction = Transaction::change(doc.text(), changes.into_iter());
+                doc.apply(&transaction, view.id);
+                cx.editor
+                    .set_status(format!("renamed {} occurrence(s)", count));
+            });
+        },
+    );
+}
+
 fn make_search_word_bounded(cx: &mut Context) {
     // Defaults to the active search register instead `/` to be more ergonomic assuming most people
     // would use this command following `search_selection`. This avoids selecting the register
@@ -2167,14 +2389,25 @@ struct FileResult {
         /// 0 indexed lines
         line_num: usize,
         line_content: String,
+        /// The document the match was found in, if it was already open at
+        /// search time. Used to resolve the preview/jump target directly by
+        /// id instead of by path, so that an open, unsaved buffer is always
+        /// preferred over its (possibly stale) on-disk contents.
+        doc_id: Option<DocumentId>,
     }
 
     impl FileResult {
-        fn new(path: &Path, line_num: usize, line_content: String) -> Self {
+        fn new(
+            path: &Path,
+            line_num: usize,
+            line_content: String,
+            doc_id: Option<DocumentId>,
+        ) -> Self {
             Self {
                 path: path.to_path_buf(),
                 line_num,
                 line_content,
+                doc_id,
             }
         }
     }
@@ -2215,7 +2448,7 @@ fn new(path: &Path, line_num: usize, line_content: String) -> Self {
 
         let documents: Vec<_> = editor
             .documents()
-            .map(|doc| (doc.path().cloned(), doc.text().to_owned()))
+            .map(|doc| (doc.id(), doc.path().cloned(), doc.text().to_owned()))
             .collect();
 
         let matcher = match RegexMatcherBuilder::new()
@@ -2273,6 +2506,13 @@ fn new(path: &Path, line_num: usize, line_content: String) -> Self {
                             _ => return WalkState::Continue,
                         };
 
+                        let doc = documents.iter().find(|&(_, doc_path, _)| {
+                            doc_path
+                                .as_ref()
+                                .map_or(false, |doc_path| doc_path == entry.path())
+                        });
+                        let doc_id = doc.map(|&(id, ..)| id);
+
                         let mut stop = false;
                         let sink = sinks::UTF8(|line_num, line_content| {
                             stop = injector
@@ -2280,18 +2520,14 @@ fn new(path: &Path, line_num: usize, line_content: String) -> Self {
                                     entry.path(),
                                     line_num as usize - 1,
                                     line_content.to_string(),
+                                    doc_id,
                                 ))
                                 .is_err();
 
                             Ok(!stop)
                         });
-                        let doc = documents.iter().find(|&(doc_path, _)| {
-                            doc_path
-                                .as_ref()
-                                .map_or(false, |doc_path| doc_path == entry.path())
-                        });
 
-                        let result = if let Some((_, doc)) = doc {
+                        let result = if let Some((_, _, doc)) = doc {
                             // there is already a buffer for this file
                             // search the buffer instead of the file because it's faster
                             // and captures new edits without requiring a save
@@ -2332,18 +2568,35 @@ fn new(path: &Path, line_num: usize, line_content: String) -> Self {
                 picker,
                 1, // contents
                 injector,
-                move |cx, FileResult { path, line_num, .. }, action| {
-                    let doc = match cx.editor.open(path, action) {
-                        Ok(id) => doc_mut!(cx.editor, &id),
-                        Err(e) => {
-                            cx.editor.set_error(format!(
-                                "Failed to open file '{}': {}",
-                                path.display(),
-                                e
-                            ));
-                            return;
+                move |cx,
+                      FileResult {
+                          path,
+                          line_num,
+                          doc_id,
+                          ..
+                      },
+                      action| {
+                    // Prefer the buffer the match was actually found in so that an
+                    // open, unsaved file is jumped to directly instead of being
+                    // re-resolved (and potentially re-read from disk) by path.
+                    let id = match (*doc_id).filter(|id| cx.editor.documents.contains_key(id)) {
+                        Some(id) => {
+                            cx.editor.switch(id, action);
+                            id
                         }
+                        None => match cx.editor.open(path, action) {
+                            Ok(id) => id,
+                            Err(e) => {
+                                cx.editor.set_error(format!(
+                                    "Failed to open file '{}': {}",
+                                    path.display(),
+                                    e
+                                ));
+                                return;
+                            }
+                        },
                     };
+                    let doc = doc_mut!(cx.editor, &id);
 
                     let line_num = *line_num;
                     let view = view_mut!(cx.editor);
@@ -2363,9 +2616,15 @@ fn new(path: &Path, line_num: usize, line_content: String) -> Self {
                     }
                 },
             )
-            .with_preview(|_editor, FileResult { path, line_num, .. }| {
-                Some((path.clone().into(), Some((*line_num, *line_num))))
-            });
+            .with_preview(
+                |editor, FileResult { path, line_num, doc_id, .. }| {
+                    let path_or_id = match *doc_id {
+                        Some(id) if editor.documents.contains_key(&id) => id.into(),
+                        _ => path.clone().into(),
+                    };
+                    Some((path_or_id, Some((*line_num, *line_num))))
+                },
+            );
             let dyn_picker = DynamicPicker::new(picker, Box::new(get_files));
             compositor.push(Box::new(overlaid(dyn_picker)))
         };
@@ -2645,7 +2904,9 @@ fn enter_insert_mode(cx: &mut Context) {
     let mut copilot_state = doc.copilot_state.lock();
     copilot_state.enterered_insert_mode();
     copilot_state.reset_state();
+    drop(copilot_state);
     doc.send_copilot_completion(view.id);
+    doc.send_lsp_inline_completion(view.id, &cx.editor.config());
 
     cx.editor.mode = Mode::Insert;
 }
@@ -2739,9 +3000,25 @@ fn file_picker_in_current_directory(cx: &mut Context) {
     cx.push_layer(Box::new(overlaid(picker)));
 }
 
+fn file_explorer(cx: &mut Context) {
+    let dir = doc!(cx.editor)
+        .path()
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+        .unwrap_or_else(helix_loader::current_working_dir);
+
+    if !dir.exists() {
+        cx.editor.set_error("Directory does not exist");
+        return;
+    }
+
+    let picker = ui::directory_picker(dir);
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
 fn buffer_picker(cx: &mut Context) {
     let current = view!(cx.editor).doc;
 
+    #[derive(Clone)]
     struct BufferMeta {
         id: DocumentId,
         path: Option<PathBuf>,
@@ -2750,6 +3027,10 @@ struct BufferMeta {
         focused_at: std::time::Instant,
     }
 
+    struct BufferPickerData {
+        icons_nerd_font: bool,
+    }
+
     let new_meta = |doc: &Document| BufferMeta {
         id: doc.id(),
         path: doc.path().cloned(),
@@ -2768,31 +3049,46 @@ struct BufferMeta {
     // mru
     items.sort_unstable_by_key(|item| std::cmp::Reverse(item.focused_at));
 
-    let columns = vec![
-        PickerColumn::new("id", |meta: &BufferMeta, _| meta.id.to_string().into()),
-        PickerColumn::new("flags", |meta: &BufferMeta, _| {
-            let mut flags = String::new();
-            if meta.is_modified {
-                flags.push('+');
-            }
-            if meta.is_current {
-                flags.push('*');
-            }
-            flags.into()
-        }),
-        PickerColumn::new("path", |meta: &BufferMeta, _| {
-            let path = meta
-                .path
-                .as_deref()
-                .map(helix_core::path::get_relative_path);
-            path.as_deref()
-                .and_then(Path::to_str)
-                .unwrap_or(SCRATCH_BUFFER_NAME)
-                .to_string()
-                .into()
-        }),
-    ];
-    let picker = Picker::new(columns, 2, items, (), |cx, meta, action| {
+    let icons_enabled = cx.editor.config().picker.icons;
+    let mut columns = Vec::new();
+    if icons_enabled {
+        columns.push(PickerColumn::new(
+            "icon",
+            |meta: &BufferMeta, data: &BufferPickerData| match &meta.path {
+                Some(path) => helix_view::icons::icon_for(path, false, data.icons_nerd_font).into(),
+                None => " ".into(),
+            },
+        ));
+    }
+    columns.push(PickerColumn::new("id", |meta: &BufferMeta, _| {
+        meta.id.to_string().into()
+    }));
+    columns.push(PickerColumn::new("flags", |meta: &BufferMeta, _| {
+        let mut flags = String::new();
+        if meta.is_modified {
+            flags.push('+');
+        }
+        if meta.is_current {
+            flags.push('*');
+        }
+        flags.into()
+    }));
+    columns.push(PickerColumn::new("path", |meta: &BufferMeta, _| {
+        let path = meta
+            .path
+            .as_deref()
+            .map(helix_core::path::get_relative_path);
+        path.as_deref()
+            .and_then(Path::to_str)
+            .unwrap_or(SCRATCH_BUFFER_NAME)
+            .to_string()
+            .into()
+    }));
+    let default_column = 2 + usize::from(icons_enabled);
+    let picker_data = BufferPickerData {
+        icons_nerd_font: cx.editor.config().picker.icons_nerd_font,
+    };
+    let picker = Picker::new(columns, default_column, items, picker_data, |cx, meta, action| {
         cx.editor.switch(meta.id, action);
     })
     .with_preview(|editor, meta| {
@@ -2803,6 +3099,23 @@ struct BufferMeta {
             .primary()
             .cursor_line(doc.text().slice(..));
         Some((meta.id.into(), Some((line, line))))
+    })
+    .with_action(ctrl!('x'), "close buffer", |cx, meta| {
+        let id = meta.id;
+        if let Err(err) = cx.editor.close_document(id, false) {
+            cx.editor.set_error(format!("{err}"));
+            return;
+        }
+        // The buffer picker stays open, so drop the closed entry from the
+        // matcher instead of leaving a stale row until it's reopened.
+        let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+            if let Some(picker) =
+                compositor.find::<ui::overlay::Overlay<Picker<BufferMeta, BufferPickerData>>>()
+            {
+                picker.content.remove_matching_items(|meta| meta.id == id);
+            }
+        }));
+        cx.jobs.callback(async move { Ok(callback) });
     });
     cx.push_layer(Box::new(overlaid(picker)));
 }
@@ -2813,6 +3126,7 @@ struct JumpMeta {
         path: Option<PathBuf>,
         selection: Selection,
         text: String,
+        line: Option<usize>,
         is_current: bool,
     }
 
@@ -2833,11 +3147,14 @@ struct JumpMeta {
                 .join(" ")
         });
 
+        let line = doc.map(|d| selection.primary().cursor_line(d.text().slice(..)));
+
         JumpMeta {
             id: doc_id,
             path: doc.and_then(|d| d.path().cloned()),
             selection,
             text,
+            line,
             is_current: view.doc == doc_id,
         }
     };
@@ -2855,6 +3172,11 @@ struct JumpMeta {
                 .to_string()
                 .into()
         }),
+        ui::PickerColumn::new("line", |item: &JumpMeta, _| {
+            item.line
+                .map_or_else(String::new, |line| (line + 1).to_string())
+                .into()
+        }),
         ui::PickerColumn::new("flags", |item: &JumpMeta, _| {
             let mut flags = Vec::new();
             if item.is_current {
@@ -2901,6 +3223,62 @@ struct JumpMeta {
     cx.push_layer(Box::new(overlaid(picker)));
 }
 
+fn buffer_line_picker(cx: &mut Context) {
+    struct LineMatch {
+        line: usize,
+        content: String,
+    }
+
+    let doc = doc!(cx.editor);
+    let doc_id = doc.id();
+    let text = doc.text().slice(..);
+
+    let items: Vec<LineMatch> = text
+        .lines()
+        .enumerate()
+        .filter_map(|(line, contents)| {
+            let content = contents.to_string();
+            let content = content.trim_end_matches(['\n', '\r']);
+            if content.is_empty() {
+                return None;
+            }
+            Some(LineMatch {
+                line,
+                content: content.to_string(),
+            })
+        })
+        .collect();
+
+    let columns = vec![
+        ui::PickerColumn::new("line", |item: &LineMatch, _| {
+            (item.line + 1).to_string().into()
+        }),
+        ui::PickerColumn::new("contents", |item: &LineMatch, _| {
+            item.content.as_str().into()
+        }),
+    ];
+
+    let picker = Picker::new(columns, 1, items, (), move |cx, item, action| {
+        cx.editor.switch(doc_id, action);
+        let config = cx.editor.config();
+        let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &doc_id));
+        let text = doc.text().slice(..);
+        let pos = text.line_to_char(item.line);
+        push_jump(view, doc);
+        let selection = doc
+            .selection(view.id)
+            .clone()
+            .transform(|range| range.put_cursor(text, pos, false));
+        doc.set_selection(view.id, selection);
+        if action.align_view(view, doc.id()) {
+            align_view(doc, view, Align::Center);
+        }
+    })
+    .with_preview(move |_editor, item| Some((doc_id.into(), Some((item.line, item.line)))));
+
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
 pub fn command_palette(cx: &mut Context) {
     let register = cx.register;
     let count = cx.count;
@@ -2973,7 +3351,8 @@ pub fn command_palette(cx: &mut Context) {
                         doc.append_changes_to_history(view);
                     }
                 }
-            });
+            })
+            .with_frecency_id("command");
             compositor.push(Box::new(overlaid(picker)));
         },
     ));
@@ -2982,7 +3361,8 @@ pub fn command_palette(cx: &mut Context) {
 fn last_picker(cx: &mut Context) {
     // TODO: last picker does not seem to work well with buffer_picker
     cx.callback.push(Box::new(|compositor, cx| {
-        if let Some(picker) = compositor.last_picker.take() {
+        if let Some(mut picker) = compositor.last_picker.take() {
+            picker.on_reopen(cx.editor);
             compositor.push(picker);
         } else {
             cx.editor.set_error("no last picker")
@@ -3098,6 +3478,7 @@ async fn make_format_callback(
 
         if let Ok(format) = format {
             if doc.version() == doc_version {
+                doc.create_checkpoint("before-format".to_string());
                 doc.apply(&format, view.id);
                 doc.append_changes_to_history(view);
                 doc.detect_indent_and_line_ending();
@@ -3285,6 +3666,54 @@ fn goto_last_line(cx: &mut Context) {
     doc.set_selection(view.id, selection);
 }
 
+fn csv_next_column(cx: &mut Context) {
+    csv_move_column(cx, Direction::Forward);
+}
+
+fn csv_previous_column(cx: &mut Context) {
+    csv_move_column(cx, Direction::Backward);
+}
+
+/// Moves the cursor to the start of the next/previous delimiter-separated
+/// field on the current line. Uses the delimiter set by `:csv-align` if
+/// active, comma otherwise. Doesn't wrap to the previous/next line, mirroring
+/// how most other single-line motions (e.g. `dollar`/`goto_line_start`)
+/// behave.
+fn csv_move_column(cx: &mut Context, direction: Direction) {
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let delimiter = doc.csv_delimiter.unwrap_or(',');
+
+    let selection = doc.selection(view.id).clone().transform(|range| {
+        let cursor = range.cursor(text);
+        let line = text.char_to_line(cursor);
+        let line_start = text.line_to_char(line);
+        let line_slice = helix_core::line_ending::line_without_line_ending(&text, line);
+        let col = cursor - line_start;
+
+        let mut field_starts = vec![0usize];
+        field_starts.extend(
+            line_slice
+                .chars()
+                .enumerate()
+                .filter(|&(_, c)| c == delimiter)
+                .map(|(i, _)| i + 1),
+        );
+
+        let target = match direction {
+            Direction::Forward => field_starts.iter().copied().find(|&start| start > col),
+            Direction::Backward => field_starts.iter().copied().rev().find(|&start| start < col),
+        };
+
+        match target {
+            Some(target) => range.put_cursor(text, line_start + target, cx.editor.mode == Mode::Select),
+            None => range,
+        }
+    });
+
+    doc.set_selection(view.id, selection);
+}
+
 fn goto_last_accessed_file(cx: &mut Context) {
     let view = view_mut!(cx.editor);
     if let Some(alt) = view.docs_access_history.pop() {
@@ -3409,6 +3838,77 @@ fn goto_prev_diag(cx: &mut Context) {
     doc.set_selection(view.id, selection);
 }
 
+fn goto_next_quickfix(cx: &mut Context) {
+    goto_quickfix_impl(cx, Direction::Forward);
+}
+
+fn goto_prev_quickfix(cx: &mut Context) {
+    goto_quickfix_impl(cx, Direction::Backward);
+}
+
+fn goto_quickfix_impl(cx: &mut Context, direction: Direction) {
+    let entry = match direction {
+        Direction::Forward => cx.editor.quickfix.next(),
+        Direction::Backward => cx.editor.quickfix.prev(),
+    }
+    .cloned();
+
+    let Some(QuickfixEntry { path, line }) = entry else {
+        cx.editor.set_error("Quickfix list is empty");
+        return;
+    };
+
+    let doc = match cx.editor.open(&path, Action::Replace) {
+        Ok(id) => doc_mut!(cx.editor, &id),
+        Err(err) => {
+            cx.editor
+                .set_error(format!("Failed to open file '{}': {}", path.display(), err));
+            return;
+        }
+    };
+
+    let view = view_mut!(cx.editor);
+    let text = doc.text();
+    if line >= text.len_lines() {
+        cx.editor.set_error(
+            "The line you jumped to does not exist anymore because the file has changed.",
+        );
+        return;
+    }
+    let start = text.line_to_char(line);
+    let end = text.line_to_char((line + 1).min(text.len_lines()));
+    doc.set_selection(view.id, Selection::single(start, end));
+    align_view(doc, view, Align::Center);
+}
+
+/// Finishes an open command-line window (`ui::Prompt::open_command_line_window`)
+/// by feeding its buffer's content back into the prompt it was opened from
+/// and closing the scratch buffer. Bound to `Enter` in normal mode, which is
+/// otherwise unbound, so it's a no-op outside of a command-line window.
+fn cmdline_window_accept(cx: &mut Context) {
+    let (_, doc) = current!(cx.editor);
+    if cx.editor.command_line_window != Some(doc.id) {
+        return;
+    }
+    let doc_id = doc.id;
+    let line = doc.text().to_string();
+    // The prompt this window was opened from is still single-line; join any
+    // extra lines the user typed rather than discarding them silently.
+    let line = line.lines().collect::<Vec<_>>().join(" ");
+
+    cx.editor.command_line_window = None;
+    if let Err(err) = cx.editor.close_document(doc_id, true) {
+        cx.editor.set_error(err.to_string());
+        return;
+    }
+
+    cx.callback.push(Box::new(move |compositor, cx| {
+        if let Some(prompt) = compositor.find::<Prompt>() {
+            prompt.submit(line, cx);
+        }
+    }));
+}
+
 fn goto_first_change(cx: &mut Context) {
     goto_first_change_impl(cx, false);
 }
@@ -3542,10 +4042,11 @@ pub fn insert_char(cx: &mut Context, c: char) {
         let text = doc.text();
         let selection = doc.selection(view.id);
         let auto_pairs = doc.auto_pairs(cx.editor);
+        let surround_selections = cx.editor.config().auto_pairs_surround_selections;
 
         let transaction = auto_pairs
             .as_ref()
-            .and_then(|ap| auto_pairs::hook(text, selection, c, ap))
+            .and_then(|ap| auto_pairs::hook(text, selection, c, ap, surround_selections))
             .or_else(|| insert(text, selection, c));
 
         let (view, doc) = current!(cx.editor);
@@ -3566,6 +4067,26 @@ pub fn apply_copilot_completion(cx: &mut Context) {
         }
     }
 
+    pub fn accept_copilot_completion_word(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+
+        let copilot_state = doc.copilot_state.lock();
+        if let Some(transaction) = copilot_state.get_word_transaction(doc.text()) {
+            drop(copilot_state);
+            doc.apply(&transaction, view.id);
+        }
+    }
+
+    pub fn accept_copilot_completion_line(cx: &mut Context) {
+        let (view, doc) = current!(cx.editor);
+
+        let copilot_state = doc.copilot_state.lock();
+        if let Some(transaction) = copilot_state.get_line_transaction(doc.text()) {
+            drop(copilot_state);
+            doc.apply(&transaction, view.id);
+        }
+    }
+
     pub fn show_or_next_copilot_completion(cx: &mut Context) {
         let (_, doc) = current!(cx.editor);
         doc.copilot_state.lock().show_or_increment_completion();
@@ -4036,14 +4557,6 @@ fn paste_impl(
         doc.append_changes_to_history(view);
     }
 
-    let repeat = std::iter::repeat(
-        // `values` is asserted to have at least one entry above.
-        values
-            .last()
-            .map(|value| Tendril::from(value.repeat(count)))
-            .unwrap(),
-    );
-
     // if any of values ends with a line ending, it's linewise paste
     let linewise = values
         .iter()
@@ -4051,14 +4564,22 @@ fn paste_impl(
 
     // Only compiled once.
     static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\r\n|\r|\n").unwrap());
-    let mut values = values
+    let normalized: Vec<Tendril> = values
         .iter()
         .map(|value| REGEX.replace_all(value, doc.line_ending.as_str()))
         .map(|value| Tendril::from(value.as_ref().repeat(count)))
-        .chain(repeat);
+        .collect();
 
     let text = doc.text();
     let selection = doc.selection(view.id);
+    let mismatch = doc.config.load().paste_selection_mismatch;
+    let mut values = resolve_paste_values(
+        normalized,
+        selection.len(),
+        mismatch,
+        doc.line_ending.as_str(),
+    )
+    .into_iter();
 
     let mut offset = 0;
     let mut ranges = SmallVec::with_capacity(selection.len());
@@ -4103,6 +4624,40 @@ fn paste_impl(
     doc.append_changes_to_history(view);
 }
 
+/// Adapts a register's yanked `values` to the number of selections being
+/// pasted into, according to `behavior`. See [`helix_view::editor::PasteSelectionMismatch`].
+fn resolve_paste_values(
+    values: Vec<Tendril>,
+    selection_len: usize,
+    behavior: PasteSelectionMismatch,
+    line_ending: &str,
+) -> Vec<Tendril> {
+    if values.len() == selection_len || values.is_empty() {
+        return values;
+    }
+
+    match behavior {
+        PasteSelectionMismatch::Repeat => {
+            let last = values.last().unwrap().clone();
+            let mut values = values;
+            values.resize(selection_len, last);
+            values
+        }
+        PasteSelectionMismatch::Cycle => (0..selection_len)
+            .map(|i| values[i % values.len()].clone())
+            .collect(),
+        PasteSelectionMismatch::JoinAll => {
+            let joined: String = values
+                .iter()
+                .map(|value| value.as_ref())
+                .collect::<Vec<&str>>()
+                .join(line_ending);
+            let joined = Tendril::from(joined);
+            std::iter::repeat(joined).take(selection_len).collect()
+        }
+    }
+}
+
 pub(crate) fn paste_bracketed_value(cx: &mut Context, contents: String) {
     let count = cx.count();
     let paste = match cx.editor.mode {
@@ -4203,6 +4758,46 @@ fn paste_before(cx: &mut Context) {
     );
 }
 
+/// Like [`paste`], but afterwards splits each pasted block on line
+/// boundaries so every pasted line becomes its own selection, turning a
+/// copied list into a ready-made multi-cursor session.
+fn paste_as_selections(editor: &mut Editor, register: char, pos: Paste, count: usize) {
+    let Some(values) = editor.registers.read(register, editor) else {
+        return;
+    };
+    let values: Vec<_> = values.map(|value| value.to_string()).collect();
+
+    let (view, doc) = current!(editor);
+    paste_impl(&values, doc, view, pos, count, editor.mode);
+
+    if editor.mode != Mode::Normal {
+        return;
+    }
+    let (view, doc) = current!(editor);
+    static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\r\n|\r|\n").unwrap());
+    let text = doc.text().slice(..);
+    let selection = selection::split_on_matches(text, doc.selection(view.id), &REGEX);
+    doc.set_selection(view.id, selection);
+}
+
+fn paste_clipboard_after_as_selections(cx: &mut Context) {
+    paste_as_selections(
+        cx.editor,
+        cx.register.unwrap_or('*'),
+        Paste::After,
+        cx.count(),
+    );
+}
+
+fn paste_clipboard_before_as_selections(cx: &mut Context) {
+    paste_as_selections(
+        cx.editor,
+        cx.register.unwrap_or('*'),
+        Paste::Before,
+        cx.count(),
+    );
+}
+
 fn get_lines(doc: &Document, view_id: ViewId) -> Vec<usize> {
     let mut lines = Vec::new();
 
@@ -4423,6 +5018,108 @@ fn keep_or_remove_selections_impl(cx: &mut Context, remove: bool) {
     )
 }
 
+/// Toggle the nearest enclosing bracketed node (argument list, array
+/// literal, ...) between a single-line and a multi-line layout,
+/// splitting/joining on its top-level commas and adjusting the trailing
+/// comma and indentation to match. Only nodes whose first and last children
+/// are brackets are handled; a method chain is not split at its `.` calls.
+fn toggle_node_expansion(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let Some(syntax) = doc.syntax() else {
+        cx.editor
+            .set_status("Tree-sitter is not available in the current buffer");
+        return;
+    };
+
+    let indent = doc.indent_style.as_str();
+    let selection = doc.selection(view.id).clone();
+    let mut changes = Vec::new();
+
+    for range in selection.ranges() {
+        let pos = range.cursor(text);
+        let byte_pos = text.char_to_byte(pos);
+        let mut node = syntax
+            .tree()
+            .root_node()
+            .descendant_for_byte_range(byte_pos, byte_pos);
+
+        // Walk up to the nearest node that starts and ends with a bracket.
+        let bracket_node = loop {
+            match node {
+                Some(n) => {
+                    let first = n.child(0);
+                    let last = n.child(n.child_count().saturating_sub(1));
+                    let is_bracketed = matches!(
+                        (first.map(|c| c.kind()), last.map(|c| c.kind())),
+                        (Some("(" | "[" | "{"), Some(")" | "]" | "}"))
+                    );
+                    if is_bracketed && n.named_child_count() > 0 {
+                        break Some(n);
+                    }
+                    node = n.parent();
+                }
+                None => break None,
+            }
+        };
+
+        let Some(bracket_node) = bracket_node else {
+            continue;
+        };
+
+        let open = bracket_node.child(0).unwrap();
+        let close = bracket_node.child(bracket_node.child_count() - 1).unwrap();
+        let open_end = text.byte_to_char(open.end_byte());
+        let close_start = text.byte_to_char(close.start_byte());
+        let inner = text.slice(open_end..close_start);
+        let is_multiline = inner.chars().any(|c| c == '\n');
+
+        // Collect the top level named children (the "elements" of the list).
+        let mut cursor = bracket_node.walk();
+        let elements: Vec<_> = bracket_node
+            .named_children(&mut cursor)
+            .map(|c| (text.byte_to_char(c.start_byte()), text.byte_to_char(c.end_byte())))
+            .collect();
+        if elements.is_empty() {
+            continue;
+        }
+
+        let base_indent = {
+            let line = text.char_to_line(bracket_node.start_byte().min(text.len_chars()));
+            indent::indent_level_for_line(text.line(line), doc.tab_width(), doc.indent_width())
+        };
+
+        let replacement = if is_multiline {
+            let mut s = String::new();
+            for (i, (start, end)) in elements.iter().enumerate() {
+                if i > 0 {
+                    s.push_str(", ");
+                }
+                s.push_str(&Cow::from(text.slice(*start..*end)));
+            }
+            s
+        } else {
+            let mut s = String::from("\n");
+            for (start, end) in &elements {
+                s.push_str(&indent.repeat(base_indent + 1));
+                s.push_str(&Cow::from(text.slice(*start..*end)));
+                s.push_str(",\n");
+            }
+            s.push_str(&indent.repeat(base_indent));
+            s
+        };
+
+        changes.push((open_end, close_start, Some(Tendril::from(replacement))));
+    }
+
+    if changes.is_empty() {
+        return;
+    }
+    changes.sort_unstable_by_key(|(from, _, _)| *from);
+    let transaction = Transaction::change(doc.text(), changes.into_iter());
+    doc.apply(&transaction, view.id);
+}
+
 fn join_selections(cx: &mut Context) {
     join_selections_impl(cx, false)
 }
@@ -4641,6 +5338,37 @@ fn reorder_selection_contents(cx: &mut Context, strategy: ReorderStrategy) {
     doc.apply(&transaction, view.id);
 }
 
+/// Swap the textual contents of exactly two selections, e.g. to swap two
+/// function arguments or two lines without disturbing selection count or
+/// requiring a count-based rotation group.
+fn swap_selections_content(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+
+    let selection = doc.selection(view.id);
+    if selection.len() != 2 {
+        cx.editor
+            .set_status("Swap requires exactly two selections");
+        return;
+    }
+
+    let fragments: Vec<Tendril> = selection
+        .slices(text)
+        .map(|fragment| fragment.chunks().collect())
+        .collect();
+
+    let transaction = Transaction::change(
+        doc.text(),
+        selection
+            .ranges()
+            .iter()
+            .zip(fragments.into_iter().rev())
+            .map(|(range, fragment)| (range.from(), range.to(), Some(fragment))),
+    );
+
+    doc.apply(&transaction, view.id);
+}
+
 fn rotate_selection_contents_forward(cx: &mut Context) {
     reorder_selection_contents(cx, ReorderStrategy::RotateForward)
 }
@@ -4938,6 +5666,11 @@ fn wonly(cx: &mut Context) {
     }
 }
 
+fn toggle_view_pin(cx: &mut Context) {
+    let view = view_mut!(cx.editor);
+    view.pinned = !view.pinned;
+}
+
 fn select_register(cx: &mut Context) {
     cx.editor.autoinfo = Some(Info::from_registers(&cx.editor.registers));
     cx.on_next_key(move |cx, event| {
@@ -4952,6 +5685,151 @@ fn select_register(cx: &mut Context) {
     })
 }
 
+fn inspect_register(cx: &mut Context) {
+    cx.editor.autoinfo = Some(Info::from_registers(&cx.editor.registers));
+    cx.on_next_key(move |cx, event| {
+        cx.editor.autoinfo = None;
+        if let Some(ch) = event.char() {
+            register_values_picker(cx, ch);
+        }
+    })
+}
+
+/// Builds the picker listing each value stored in `register`, one row per
+/// selection it was yanked from, so a mismatched yank/paste can be
+/// inspected without guessing at what will be pasted where. Returns `None`
+/// if the register is empty, since there is nothing useful to show.
+fn register_values_picker_component(
+    editor: &Editor,
+    register: char,
+) -> Option<Box<dyn Component>> {
+    let values: Vec<(usize, String)> = editor
+        .registers
+        .read(register, editor)?
+        .map(|value| value.to_string())
+        .enumerate()
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+
+    let columns = vec![
+        PickerColumn::new("index", |(i, _): &(usize, String), _| {
+            (i + 1).to_string().into()
+        }),
+        PickerColumn::new("value", |(_, value): &(usize, String), _| {
+            value.replace('\n', "\\n").into()
+        }),
+    ];
+    let picker = Picker::new(columns, 1, values, (), |_cx, _item, _action| {});
+    Some(Box::new(overlaid(picker)))
+}
+
+fn register_values_picker(cx: &mut Context, register: char) {
+    match register_values_picker_component(cx.editor, register) {
+        Some(component) => cx.push_layer(component),
+        None => cx.editor.set_error(format!("Register {register} is empty")),
+    }
+}
+
+/// Opens a picker listing every register alongside a one-line preview of
+/// its contents (the same summary `Info::from_registers` shows). Since the
+/// picker's preview pane only knows how to render files and documents,
+/// multi-line contents aren't shown there; press `Enter` to drill into a
+/// register's full, per-value contents instead. `Alt-d` clears the
+/// register under the cursor and `Alt-e` opens its contents in a prompt
+/// for editing, replacing all of its values with the edited line.
+fn registers_picker(cx: &mut Context) {
+    struct RegisterItem {
+        name: char,
+        preview: String,
+    }
+
+    fn registers(editor: &Editor) -> Vec<RegisterItem> {
+        editor
+            .registers
+            .iter_preview()
+            .map(|(name, preview)| RegisterItem {
+                name,
+                preview: preview.to_string(),
+            })
+            .collect()
+    }
+
+    let columns = vec![
+        PickerColumn::new("register", |item: &RegisterItem, _| {
+            item.name.to_string().into()
+        }),
+        PickerColumn::new("contents", |item: &RegisterItem, _| {
+            item.preview.as_str().into()
+        }),
+    ];
+
+    let picker = Picker::new(
+        columns,
+        1, // contents
+        registers(cx.editor),
+        (),
+        |cx, item: &RegisterItem, _action| {
+            let name = item.name;
+            let callback = Callback::EditorCompositor(Box::new(move |editor, compositor| {
+                match register_values_picker_component(editor, name) {
+                    Some(component) => compositor.push(component),
+                    None => editor.set_error(format!("Register {name} is empty")),
+                }
+            }));
+            cx.jobs.callback(async move { Ok(callback) });
+        },
+    )
+    .with_action(alt!('d'), "clear register", |cx, item: &RegisterItem| {
+        let name = item.name;
+        cx.editor.registers.remove(name);
+        cx.editor.set_status(format!("Cleared register {name}"));
+        let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+            if let Some(picker) =
+                compositor.find::<ui::overlay::Overlay<Picker<RegisterItem, ()>>>()
+            {
+                picker.content.remove_matching_items(|item| item.name == name);
+            }
+        }));
+        cx.jobs.callback(async move { Ok(callback) });
+    })
+    .with_action(alt!('e'), "edit register", |cx, item: &RegisterItem| {
+        let name = item.name;
+        let line = cx
+            .editor
+            .registers
+            .read(name, cx.editor)
+            .map(|values| {
+                values
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\\n")
+            })
+            .unwrap_or_default();
+        let callback = Callback::EditorCompositor(Box::new(move |editor, compositor| {
+            let prompt = ui::Prompt::new(
+                format!("register {name}:").into(),
+                None,
+                ui::completers::none,
+                move |cx: &mut compositor::Context, input: &str, event: PromptEvent| {
+                    if event != PromptEvent::Validate {
+                        return;
+                    }
+                    let value = input.replace("\\n", "\n");
+                    if let Err(err) = cx.editor.registers.write(name, vec![value]) {
+                        cx.editor.set_error(err.to_string());
+                    }
+                },
+            )
+            .with_line(line, editor);
+            compositor.push(Box::new(prompt));
+        }));
+        cx.jobs.callback(async move { Ok(callback) });
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
 fn insert_register(cx: &mut Context) {
     cx.editor.autoinfo = Some(Info::from_registers(&cx.editor.registers));
     cx.on_next_key(move |cx, event| {
@@ -5124,7 +6002,7 @@ fn select_textobject(cx: &mut Context, objtype: textobject::TextObject) {
                     )
                 };
 
-                if ch == 'g' && doc.diff_handle().is_none() {
+                if (ch == 'g' || ch == 'h') && doc.diff_handle().is_none() {
                     editor.set_status("Diff is not available in current buffer");
                     return;
                 }
@@ -5149,6 +6027,7 @@ fn select_textobject(cx: &mut Context, objtype: textobject::TextObject) {
                     match ch {
                         'w' => textobject::textobject_word(text, range, objtype, count, false),
                         'W' => textobject::textobject_word(text, range, objtype, count, true),
+                        's' => textobject::textobject_sub_word(text, range, objtype),
                         't' => textobject_treesitter("class", range),
                         'f' => textobject_treesitter("function", range),
                         'a' => textobject_treesitter("parameter", range),
@@ -5158,7 +6037,7 @@ fn select_textobject(cx: &mut Context, objtype: textobject::TextObject) {
                         'm' => textobject::textobject_pair_surround_closest(
                             text, range, objtype, count,
                         ),
-                        'g' => textobject_change(range),
+                        'g' | 'h' => textobject_change(range),
                         // TODO: cancel new ranges if inconsistent surround matches across lines
                         ch if !ch.is_ascii_alphanumeric() => {
                             textobject::textobject_pair_surround(text, range, objtype, ch, count)
@@ -5180,6 +6059,7 @@ fn select_textobject(cx: &mut Context, objtype: textobject::TextObject) {
     let help_text = [
         ("w", "Word"),
         ("W", "WORD"),
+        ("s", "Sub word"),
         ("p", "Paragraph"),
         ("t", "Type definition (tree-sitter)"),
         ("f", "Function (tree-sitter)"),
@@ -5187,6 +6067,8 @@ fn select_textobject(cx: &mut Context, objtype: textobject::TextObject) {
         ("c", "Comment (tree-sitter)"),
         ("T", "Test (tree-sitter)"),
         ("m", "Closest surrounding pair"),
+        ("g", "Change"),
+        ("h", "Diff hunk (alias of 'g')"),
         (" ", "... or any character acting as a pair"),
     ];
 
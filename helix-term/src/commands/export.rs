@@ -0,0 +1,142 @@
+//! Rendering a document's syntax highlighting to a static format, reusing
+//! the same highlight pipeline used by the on-screen renderer so that the
+//! exported output matches what is shown in the editor.
+
+use helix_core::syntax::HighlightEvent;
+use helix_core::RopeSlice;
+use helix_view::graphics::Color;
+use helix_view::theme::Style;
+use helix_view::{Document, Theme};
+
+/// Merges the layered highlight events for `doc` into a flat list of
+/// `(style, text)` spans covering the whole document.
+fn highlighted_spans(doc: &Document, theme: &Theme) -> Vec<(Style, String)> {
+    let text = doc.text().slice(..);
+    let Some(syntax) = doc.syntax() else {
+        return vec![(theme.get("ui.text"), text.to_string())];
+    };
+
+    let mut spans = Vec::new();
+    let mut active_highlights: Vec<helix_core::syntax::Highlight> = Vec::new();
+    let mut pos = 0;
+
+    for event in syntax.highlight_iter(text, None, None).flatten() {
+        match event {
+            HighlightEvent::HighlightStart(highlight) => active_highlights.push(highlight),
+            HighlightEvent::HighlightEnd => {
+                active_highlights.pop();
+            }
+            HighlightEvent::Source { start, end } => {
+                if start > pos {
+                    spans.push((theme.get("ui.text"), slice_to_string(text, pos, start)));
+                }
+                let style = active_highlights
+                    .iter()
+                    .fold(theme.get("ui.text"), |acc, span| acc.patch(theme.highlight(span.0)));
+                spans.push((style, slice_to_string(text, start, end)));
+                pos = end;
+            }
+        }
+    }
+    if pos < text.len_bytes() {
+        spans.push((theme.get("ui.text"), slice_to_string(text, pos, text.len_bytes())));
+    }
+    spans
+}
+
+fn slice_to_string(text: RopeSlice, start: usize, end: usize) -> String {
+    text.byte_slice(start..end).to_string()
+}
+
+fn color_to_rgb(color: Color, theme: &Theme) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::LightGray => (127, 127, 127),
+        Color::White => (255, 255, 255),
+        Color::Indexed(_) | Color::Reset => {
+            let fallback = theme.get("ui.text").fg.unwrap_or(Color::White);
+            if fallback == color {
+                (255, 255, 255)
+            } else {
+                color_to_rgb(fallback, theme)
+            }
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Renders `doc` as a standalone HTML document, preserving syntax
+/// highlighting from `theme`.
+pub fn document_to_html(doc: &Document, theme: &Theme) -> String {
+    let bg = theme
+        .get("ui.background")
+        .bg
+        .map(|color| color_to_rgb(color, theme))
+        .unwrap_or((0, 0, 0));
+    let fg = theme
+        .get("ui.text")
+        .fg
+        .map(|color| color_to_rgb(color, theme))
+        .unwrap_or((255, 255, 255));
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"></head>\n");
+    html.push_str(&format!(
+        "<body style=\"background-color: rgb({},{},{}); color: rgb({},{},{})\">\n<pre>\n",
+        bg.0, bg.1, bg.2, fg.0, fg.1, fg.2
+    ));
+
+    for (style, text) in highlighted_spans(doc, theme) {
+        let text = escape_html(&text);
+        match style.fg.map(|color| color_to_rgb(color, theme)) {
+            Some((r, g, b)) => {
+                html.push_str(&format!("<span style=\"color: rgb({r},{g},{b})\">{text}</span>"))
+            }
+            None => html.push_str(&text),
+        }
+    }
+
+    html.push_str("</pre>\n</body>\n</html>\n");
+    html
+}
+
+/// Renders `doc` as plain text with embedded 24-bit ANSI color escapes,
+/// suitable for pasting into a terminal that supports true color.
+pub fn document_to_ansi(doc: &Document, theme: &Theme) -> String {
+    let mut ansi = String::new();
+    for (style, text) in highlighted_spans(doc, theme) {
+        match style.fg.map(|color| color_to_rgb(color, theme)) {
+            Some((r, g, b)) => {
+                ansi.push_str(&format!("\x1b[38;2;{r};{g};{b}m{text}\x1b[0m"));
+            }
+            None => ansi.push_str(&text),
+        }
+    }
+    ansi
+}
@@ -11,23 +11,32 @@
 use tokio_stream::StreamExt;
 use tui::{text::Span, widgets::Row};
 
-use super::{align_view, push_jump, Align, Context, Editor};
+use super::{align_view, filter_picker_entry, push_jump, Align, Context, Editor};
+
+use grep_regex::RegexMatcherBuilder;
+use grep_searcher::{sinks, BinaryDetection, SearcherBuilder};
+use ignore::WalkBuilder;
 
 use helix_core::{
-    path, syntax::LanguageServerFeature, text_annotations::InlineAnnotation, Selection,
+    path,
+    regex::Regex,
+    syntax::LanguageServerFeature,
+    text_annotations::InlineAnnotation,
+    textobject, Selection, Transaction,
 };
+use once_cell::sync::Lazy;
 use helix_view::{
     document::{DocumentInlayHints, DocumentInlayHintsId},
-    editor::Action,
+    editor::{Action, WorkspaceEditUndo},
     handlers::lsp::SignatureHelpInvoked,
     theme::Style,
     Document, View,
 };
 
 use crate::{
-    compositor::{self, Compositor},
+    compositor::{self, Component, Compositor},
     job::Callback,
-    ui::{self, overlay::overlaid, DynamicPicker, FileLocation, Picker, Popup, PromptEvent},
+    ui::{self, overlay::overlaid, DynamicPicker, FileLocation, Injector, Picker, Popup, PromptEvent},
 };
 
 use std::{
@@ -35,6 +44,8 @@
     collections::{BTreeMap, HashSet},
     fmt::Write,
     future::Future,
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicBool, Arc, Mutex},
 };
 
 /// Gets the first language server that is attached to a document which supports a specific feature.
@@ -59,9 +70,14 @@ macro_rules! language_server_with_feature {
     }};
 }
 
+#[derive(Clone)]
 struct SymbolInformationItem {
     symbol: lsp::SymbolInformation,
     offset_encoding: OffsetEncoding,
+    /// Nesting depth within the document's `DocumentSymbol` tree, used to
+    /// render the document symbol picker as an indented tree. Always 0 for
+    /// workspace symbols, which the LSP reports as a flat list.
+    depth: usize,
 }
 
 struct DiagnosticStyles {
@@ -193,14 +209,23 @@ fn sym_picker(symbols: Vec<SymbolInformationItem>, workspace: bool) -> SymbolPic
             symbol_kind_column,
             // Some symbols in the document symbol picker may have a URI that isn't
             // the current file. It should be rare though, so we concatenate that
-            // URI in with the symbol name in this picker.
+            // URI in with the symbol name in this picker. The name is indented
+            // to mirror the symbol's depth in the `DocumentSymbol` tree.
             ui::PickerColumn::new("name", |item: &SymbolInformationItem, _| {
-                item.symbol.name.as_str().into()
+                if item.depth == 0 {
+                    item.symbol.name.as_str().into()
+                } else {
+                    format!("{}{}", "  ".repeat(item.depth), item.symbol.name).into()
+                }
             }),
         ]
     };
 
-    Picker::new(
+    // Kept around so the "expand" action can restore rows that "collapse"
+    // removed from the live picker below.
+    let all_symbols = Arc::new(symbols.clone());
+
+    let picker = Picker::new(
         columns,
         1, // name column
         symbols,
@@ -215,7 +240,58 @@ fn sym_picker(symbols: Vec<SymbolInformationItem>, workspace: bool) -> SymbolPic
         },
     )
     .with_preview(move |_editor, item| Some(location_to_file_location(&item.symbol.location)))
-    .truncate_start(false)
+    .truncate_start(false);
+
+    if workspace {
+        return picker;
+    }
+
+    // The document symbol tree only ever has two states in this picker:
+    // fully expanded (the default) or collapsed down to top-level symbols.
+    // Collapsing removes every nested row from the live picker; expanding
+    // re-injects the original nested rows. `collapsed` guards against
+    // double-collapsing or double-expanding, which would otherwise leave
+    // duplicate rows behind.
+    let collapsed = Arc::new(AtomicBool::new(false));
+
+    let collapse_flag = collapsed.clone();
+    let expand_flag = collapsed;
+    let expand_symbols = all_symbols;
+
+    picker
+        .with_action(
+            alt!('h'),
+            "collapse to top-level symbols",
+            move |cx, _item| {
+                if collapse_flag.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                    return;
+                }
+                let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+                    if let Some(picker) = compositor.find::<ui::overlay::Overlay<SymbolPicker>>()
+                    {
+                        picker
+                            .content
+                            .remove_matching_items(|item: &SymbolInformationItem| item.depth > 0);
+                    }
+                }));
+                cx.jobs.callback(async move { Ok(callback) });
+            },
+        )
+        .with_action(alt!('l'), "expand all symbols", move |cx, _item| {
+            if !expand_flag.swap(false, std::sync::atomic::Ordering::Relaxed) {
+                return;
+            }
+            let symbols = expand_symbols.clone();
+            let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+                if let Some(picker) = compositor.find::<ui::overlay::Overlay<SymbolPicker>>() {
+                    let injector = picker.content.injector();
+                    for item in symbols.iter().filter(|item| item.depth > 0) {
+                        let _ = injector.push(item.clone());
+                    }
+                }
+            }));
+            cx.jobs.callback(async move { Ok(callback) });
+        })
 }
 
 #[derive(Copy, Clone, PartialEq)]
@@ -226,6 +302,50 @@ enum DiagnosticsFormat {
 
 type DiagnosticsPicker = Picker<PickerDiagnostic, DiagnosticStyles>;
 
+/// The [`Injector`] of the most recently opened workspace diagnostics picker,
+/// for as long as that picker is still open, so [`refresh_workspace_diagnostics_picker`]
+/// can push newly published diagnostics into it live rather than leaving it
+/// showing a stale snapshot from when it was opened. The per-document
+/// diagnostics picker opened by [`diagnostics_picker`] is intentionally not
+/// tracked here: it's a narrower, short-lived view that's cheap to reopen.
+static WORKSPACE_DIAGNOSTICS_INJECTOR: Mutex<Option<Injector<PickerDiagnostic, DiagnosticStyles>>> =
+    Mutex::new(None);
+
+/// Pushes newly published diagnostics for `url` into the open workspace
+/// diagnostics picker, if any. Diagnostics that are resolved or withdrawn
+/// while the picker is open are deliberately left in the list: the picker's
+/// backing `nucleo` matcher has no API for removing an already-injected item.
+pub(crate) fn refresh_workspace_diagnostics_picker(
+    editor: &Editor,
+    url: &lsp::Url,
+    diagnostics: &[(lsp::Diagnostic, usize)],
+) {
+    let mut injector = WORKSPACE_DIAGNOSTICS_INJECTOR.lock().unwrap();
+    let Some(inj) = injector.as_ref() else {
+        return;
+    };
+
+    for (diag, ls) in diagnostics {
+        // low number is high severity weirdly enough
+        if diag.severity > Some(DiagnosticSeverity::WARNING) {
+            continue;
+        }
+        let Some(ls) = editor.language_server_by_id(*ls) else {
+            continue;
+        };
+        let item = PickerDiagnostic {
+            url: url.clone(),
+            diag: diag.clone(),
+            offset_encoding: ls.offset_encoding(),
+        };
+        if inj.push(item).is_err() {
+            // The picker has since closed; drop the stale injector.
+            *injector = None;
+            return;
+        }
+    }
+}
+
 fn diag_picker(
     cx: &Context,
     diagnostics: BTreeMap<lsp::Url, Vec<(lsp::Diagnostic, usize)>>,
@@ -335,6 +455,7 @@ fn nested_to_flat(
         file: &lsp::TextDocumentIdentifier,
         symbol: lsp::DocumentSymbol,
         offset_encoding: OffsetEncoding,
+        depth: usize,
     ) {
         #[allow(deprecated)]
         list.push(SymbolInformationItem {
@@ -347,9 +468,10 @@ fn nested_to_flat(
                 container_name: None,
             },
             offset_encoding,
+            depth,
         });
         for child in symbol.children.into_iter().flatten() {
-            nested_to_flat(list, file, child, offset_encoding);
+            nested_to_flat(list, file, child, offset_encoding, depth + 1);
         }
     }
     let doc = doc!(cx.editor);
@@ -379,12 +501,13 @@ fn nested_to_flat(
                         .map(|symbol| SymbolInformationItem {
                             symbol,
                             offset_encoding,
+                            depth: 0,
                         })
                         .collect(),
                     lsp::DocumentSymbolResponse::Nested(symbols) => {
                         let mut flat_symbols = Vec::new();
                         for symbol in symbols {
-                            nested_to_flat(&mut flat_symbols, &doc_id, symbol, offset_encoding)
+                            nested_to_flat(&mut flat_symbols, &doc_id, symbol, offset_encoding, 0)
                         }
                         flat_symbols
                     }
@@ -448,6 +571,7 @@ pub fn workspace_symbol_picker(cx: &mut Context) {
                             .map(|symbol| SymbolInformationItem {
                                 symbol,
                                 offset_encoding,
+                                depth: 0,
                             })
                             .collect();
 
@@ -522,6 +646,167 @@ pub fn workspace_symbol_picker(cx: &mut Context) {
     });
 }
 
+/// One entry of a document's `DocumentSymbol` tree flattened into document
+/// order (pre-order, parent before children), as produced by
+/// [`flatten_symbol_spans`]. Used by [`goto_parent_symbol`], [`goto_next_symbol`]
+/// and [`goto_previous_symbol`] to walk the tree without needing a picker.
+#[derive(Debug, Clone, Copy)]
+struct SymbolSpan {
+    depth: usize,
+    range: lsp::Range,
+}
+
+fn flatten_symbol_spans(list: &mut Vec<SymbolSpan>, symbol: lsp::DocumentSymbol, depth: usize) {
+    list.push(SymbolSpan {
+        depth,
+        range: symbol.range,
+    });
+    for child in symbol.children.into_iter().flatten() {
+        flatten_symbol_spans(list, child, depth + 1);
+    }
+}
+
+fn range_contains(range: lsp::Range, pos: lsp::Position) -> bool {
+    range.start <= pos && pos <= range.end
+}
+
+/// Innermost symbol whose range contains `pos`. Relies on `spans` being in
+/// DFS pre-order (parent pushed before its children): the containing spans
+/// form a contiguous run ending at the deepest match, so the last match in
+/// list order is the innermost one.
+fn symbol_containing(spans: &[SymbolSpan], pos: lsp::Position) -> Option<usize> {
+    spans.iter().rposition(|span| range_contains(span.range, pos))
+}
+
+fn sibling_index(spans: &[SymbolSpan], from: usize, forward: bool) -> Option<usize> {
+    let depth = spans[from].depth;
+    let indices: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(from + 1..spans.len())
+    } else {
+        Box::new((0..from).rev())
+    };
+    for i in indices {
+        match spans[i].depth.cmp(&depth) {
+            Ordering::Equal => return Some(i),
+            Ordering::Less => return None,
+            Ordering::Greater => continue,
+        }
+    }
+    None
+}
+
+/// Fetches the current document's symbol tree and runs `f` on the flattened
+/// spans together with the index of the symbol enclosing the cursor. Used by
+/// [`goto_parent_symbol`], [`goto_next_symbol`] and [`goto_previous_symbol`],
+/// which only differ in how they pick a target index from that pair.
+///
+/// Symbols reported as a flat [`lsp::DocumentSymbolResponse::Flat`] list have
+/// no tree to walk, so they're treated as depth-0 siblings in range order;
+/// "goto parent" goes no further than the enclosing symbol's start for those
+/// language servers.
+fn with_enclosing_symbol(
+    cx: &mut Context,
+    f: impl FnOnce(&mut Editor, OffsetEncoding, &[SymbolSpan], usize) + 'static,
+) {
+    let (view, doc) = current!(cx.editor);
+    let Some(language_server) = doc
+        .language_servers_with_feature(LanguageServerFeature::DocumentSymbols)
+        .next()
+    else {
+        cx.editor
+            .set_error("No configured language server supports document symbols");
+        return;
+    };
+
+    let offset_encoding = language_server.offset_encoding();
+    let pos = doc.position(view.id, offset_encoding);
+    let future = language_server.document_symbols(doc.identifier()).unwrap();
+
+    cx.jobs.callback(async move {
+        let json = future.await?;
+        let response: Option<lsp::DocumentSymbolResponse> = serde_json::from_value(json)?;
+        let mut spans = Vec::new();
+        match response {
+            Some(lsp::DocumentSymbolResponse::Nested(symbols)) => {
+                for symbol in symbols {
+                    flatten_symbol_spans(&mut spans, symbol, 0);
+                }
+            }
+            Some(lsp::DocumentSymbolResponse::Flat(symbols)) => {
+                spans.extend(symbols.into_iter().map(|symbol| SymbolSpan {
+                    depth: 0,
+                    range: symbol.location.range,
+                }));
+                spans.sort_by_key(|span| span.range.start);
+            }
+            None => {}
+        }
+
+        let call = move |editor: &mut Editor, _compositor: &mut Compositor| {
+            match symbol_containing(&spans, pos) {
+                Some(idx) => f(editor, offset_encoding, &spans, idx),
+                None => editor.set_error("No enclosing symbol found"),
+            }
+        };
+        Ok(Callback::EditorCompositor(Box::new(call)))
+    });
+}
+
+fn goto_symbol_span(editor: &mut Editor, offset_encoding: OffsetEncoding, span: SymbolSpan) {
+    let (view, doc) = current!(editor);
+    push_jump(view, doc);
+
+    let Some(pos) = helix_lsp::util::lsp_pos_to_pos(doc.text(), span.range.start, offset_encoding)
+    else {
+        return;
+    };
+    doc.set_selection(view.id, Selection::point(pos));
+    align_view(doc, view, Align::Center);
+}
+
+/// Jumps to the start of the symbol enclosing the cursor or, when the cursor
+/// is already at that start, to the start of that symbol's parent, using the
+/// `DocumentSymbol` tree reported by the LSP (**LSP**).
+pub fn goto_parent_symbol(cx: &mut Context) {
+    with_enclosing_symbol(cx, move |editor, offset_encoding, spans, idx| {
+        let at_start = {
+            let (view, doc) = current_ref!(editor);
+            doc.position(view.id, offset_encoding) == spans[idx].range.start
+        };
+        if !at_start {
+            goto_symbol_span(editor, offset_encoding, spans[idx]);
+            return;
+        }
+        let depth = spans[idx].depth;
+        match spans[..idx].iter().rposition(|span| span.depth + 1 == depth) {
+            Some(parent_idx) => goto_symbol_span(editor, offset_encoding, spans[parent_idx]),
+            None => editor.set_error("No parent symbol"),
+        }
+    });
+}
+
+/// Jumps to the start of the next sibling of the symbol enclosing the
+/// cursor, using the `DocumentSymbol` tree reported by the LSP (**LSP**).
+pub fn goto_next_symbol(cx: &mut Context) {
+    with_enclosing_symbol(cx, move |editor, offset_encoding, spans, idx| {
+        match sibling_index(spans, idx, true) {
+            Some(next_idx) => goto_symbol_span(editor, offset_encoding, spans[next_idx]),
+            None => editor.set_error("No next sibling symbol"),
+        }
+    });
+}
+
+/// Jumps to the start of the previous sibling of the symbol enclosing the
+/// cursor, using the `DocumentSymbol` tree reported by the LSP (**LSP**).
+pub fn goto_previous_symbol(cx: &mut Context) {
+    with_enclosing_symbol(cx, move |editor, offset_encoding, spans, idx| {
+        match sibling_index(spans, idx, false) {
+            Some(prev_idx) => goto_symbol_span(editor, offset_encoding, spans[prev_idx]),
+            None => editor.set_error("No previous sibling symbol"),
+        }
+    });
+}
+
 pub fn diagnostics_picker(cx: &mut Context) {
     let doc = doc!(cx.editor);
     if let Some(current_url) = doc.url() {
@@ -552,6 +837,7 @@ pub fn workspace_diagnostics_picker(cx: &mut Context) {
         current_url,
         DiagnosticsFormat::ShowSourcePath,
     );
+    *WORKSPACE_DIAGNOSTICS_INJECTOR.lock().unwrap() = Some(picker.injector());
     cx.push_layer(Box::new(overlaid(picker)));
 }
 
@@ -781,7 +1067,12 @@ pub fn code_action(cx: &mut Context) {
 
                         if let Some(ref workspace_edit) = resolved_code_action.edit {
                             log::debug!("edit: {:?}", workspace_edit);
-                            let _ = apply_workspace_edit(editor, offset_encoding, workspace_edit);
+                            let _ = apply_workspace_edit(
+                                editor,
+                                offset_encoding,
+                                workspace_edit,
+                                "code action",
+                            );
                         }
 
                         // if code action provides both edit and command first the edit
@@ -825,7 +1116,7 @@ pub fn execute_lsp_command(editor: &mut Editor, language_server_id: usize, cmd:
     });
 }
 
-pub fn apply_document_resource_op(op: &lsp::ResourceOp) -> std::io::Result<()> {
+pub fn apply_document_resource_op(op: &lsp::ResourceOp, trash_delete: bool) -> std::io::Result<()> {
     use lsp::ResourceOp;
     use std::fs;
     match op {
@@ -849,6 +1140,13 @@ pub fn apply_document_resource_op(op: &lsp::ResourceOp) -> std::io::Result<()> {
         }
         ResourceOp::Delete(op) => {
             let path = op.uri.to_file_path().unwrap();
+            if trash_delete {
+                // Soft-delete: move to the trash instead of removing outright,
+                // since a workspace edit can delete files the user never
+                // directly asked to remove.
+                return helix_loader::trash::move_to_trash(&path).map(|_| ());
+            }
+
             if path.is_dir() {
                 let recursive = op
                     .options
@@ -909,12 +1207,40 @@ fn to_string(&self) -> String {
     }
 }
 
+/// The number of (files, edits) a [`lsp::WorkspaceEdit`] touches, shown to
+/// the user before/after applying a workspace-wide edit such as a rename.
+fn workspace_edit_summary(edit: &lsp::WorkspaceEdit) -> (usize, usize) {
+    if let Some(document_changes) = &edit.document_changes {
+        match document_changes {
+            lsp::DocumentChanges::Edits(document_edits) => (
+                document_edits.len(),
+                document_edits.iter().map(|e| e.edits.len()).sum(),
+            ),
+            lsp::DocumentChanges::Operations(operations) => operations.iter().fold(
+                (0, 0),
+                |(files, edit_count), operation| match operation {
+                    lsp::DocumentChangeOperation::Edit(e) => {
+                        (files + 1, edit_count + e.edits.len())
+                    }
+                    lsp::DocumentChangeOperation::Op(_) => (files, edit_count),
+                },
+            ),
+        }
+    } else if let Some(changes) = &edit.changes {
+        (changes.len(), changes.values().map(Vec::len).sum())
+    } else {
+        (0, 0)
+    }
+}
+
 ///TODO make this transactional (and set failureMode to transactional)
 pub fn apply_workspace_edit(
     editor: &mut Editor,
     offset_encoding: OffsetEncoding,
     workspace_edit: &lsp::WorkspaceEdit,
+    label: &str,
 ) -> Result<(), ApplyEditError> {
+    let mut file_undos: Vec<(PathBuf, Transaction)> = Vec::new();
     let mut apply_edits = |uri: &helix_lsp::Url,
                            version: Option<i32>,
                            text_edits: Vec<lsp::TextEdit>|
@@ -964,6 +1290,7 @@ pub fn apply_workspace_edit(
                 .expect("No view_id available")
         };
 
+        let original = doc.text().clone();
         let transaction = helix_lsp::util::generate_transaction_from_edits(
             doc.text(),
             text_edits,
@@ -972,6 +1299,7 @@ pub fn apply_workspace_edit(
         let view = view_mut!(editor, view_id);
         doc.apply(&transaction, view.id);
         doc.append_changes_to_history(view);
+        file_undos.push((path, transaction.invert(&original)));
         Ok(())
     };
 
@@ -1006,9 +1334,12 @@ pub fn apply_workspace_edit(
                 for (i, operation) in operations.iter().enumerate() {
                     match operation {
                         lsp::DocumentChangeOperation::Op(op) => {
-                            apply_document_resource_op(op).map_err(|io| ApplyEditError {
-                                kind: ApplyEditErrorKind::IoError(io),
-                                failed_change_idx: i,
+                            let trash_delete = editor.config().trash_delete;
+                            apply_document_resource_op(op, trash_delete).map_err(|io| {
+                                ApplyEditError {
+                                    kind: ApplyEditErrorKind::IoError(io),
+                                    failed_change_idx: i,
+                                }
                             })?;
                         }
 
@@ -1038,11 +1369,7 @@ pub fn apply_workspace_edit(
                 }
             }
         }
-
-        return Ok(());
-    }
-
-    if let Some(ref changes) = workspace_edit.changes {
+    } else if let Some(ref changes) = workspace_edit.changes {
         log::debug!("workspace changes: {:?}", changes);
         for (i, (uri, text_edits)) in changes.iter().enumerate() {
             let text_edits = text_edits.to_vec();
@@ -1053,6 +1380,13 @@ pub fn apply_workspace_edit(
         }
     }
 
+    if !file_undos.is_empty() {
+        editor.workspace_edit_history.push(WorkspaceEditUndo {
+            label: label.to_string(),
+            file_undos,
+        });
+    }
+
     Ok(())
 }
 
@@ -1061,11 +1395,12 @@ fn goto_impl(
     compositor: &mut Compositor,
     locations: Vec<lsp::Location>,
     offset_encoding: OffsetEncoding,
+    peek: bool,
 ) {
     let cwdir = helix_loader::current_working_dir();
 
     match locations.as_slice() {
-        [location] => {
+        [location] if !peek => {
             jump_to_location(editor, location, offset_encoding, Action::Replace);
         }
         [] => {
@@ -1143,7 +1478,7 @@ fn goto_single_impl<P, F>(cx: &mut Context, feature: LanguageServerFeature, requ
         future,
         move |editor, compositor, response: Option<lsp::GotoDefinitionResponse>| {
             let items = to_locations(response);
-            goto_impl(editor, compositor, items, offset_encoding);
+            goto_impl(editor, compositor, items, offset_encoding, false);
         },
     );
 }
@@ -1156,14 +1491,266 @@ pub fn goto_declaration(cx: &mut Context) {
     );
 }
 
+/// `gd`. Tries the LSP first; if no language server supports
+/// goto-definition, or the server has nothing to offer, falls back to a
+/// ctags `tags` file in the workspace root and finally a word-boundary
+/// workspace grep, so jumping around still mostly works without a server.
+/// There's no tree-sitter-locals step: resolving a name against
+/// `locals.scm`'s scope captures needs a proper scope-tree walk, which
+/// doesn't exist anywhere in the tree yet and is too much to add here.
 pub fn goto_definition(cx: &mut Context) {
-    goto_single_impl(
-        cx,
-        LanguageServerFeature::GotoDefinition,
-        |ls, pos, doc_id| ls.goto_definition(doc_id, pos, None),
+    let (view, doc) = current_ref!(cx.editor);
+    let language_server = doc
+        .language_servers_with_feature(LanguageServerFeature::GotoDefinition)
+        .next();
+
+    let Some(language_server) = language_server else {
+        goto_definition_fallback(cx);
+        return;
+    };
+
+    let offset_encoding = language_server.offset_encoding();
+    let pos = doc.position(view.id, offset_encoding);
+    let future = language_server
+        .goto_definition(doc.identifier(), pos, None)
+        .unwrap();
+
+    cx.callback(
+        future,
+        move |editor, compositor, response: Option<lsp::GotoDefinitionResponse>| {
+            let items = to_locations(response);
+            if items.is_empty() {
+                goto_definition_fallback_impl(editor, compositor);
+            } else {
+                goto_impl(editor, compositor, items, offset_encoding, false);
+            }
+        },
     );
 }
 
+/// `gP`. Like [`goto_definition`], but always opens the picker — even for a
+/// single result — instead of jumping immediately, so the target can be
+/// previewed (with syntax highlighting and scrolling) without touching the
+/// jumplist. `Enter` jumps normally; `Ctrl-s`/`Ctrl-v` promote the peeked
+/// location into a real horizontal/vertical split, same as any other picker.
+/// Skips the ctags/grep fallback chain: those results are one-off grep hits,
+/// not a good fit for a preview-first workflow.
+pub fn goto_definition_peek(cx: &mut Context) {
+    let (view, doc) = current_ref!(cx.editor);
+    let Some(language_server) = doc
+        .language_servers_with_feature(LanguageServerFeature::GotoDefinition)
+        .next()
+    else {
+        cx.editor
+            .set_error("No language server supports goto-definition");
+        return;
+    };
+
+    let offset_encoding = language_server.offset_encoding();
+    let pos = doc.position(view.id, offset_encoding);
+    let future = language_server
+        .goto_definition(doc.identifier(), pos, None)
+        .unwrap();
+
+    cx.callback(
+        future,
+        move |editor, compositor, response: Option<lsp::GotoDefinitionResponse>| {
+            let items = to_locations(response);
+            goto_impl(editor, compositor, items, offset_encoding, true);
+        },
+    );
+}
+
+/// Word under the cursor, used as the symbol name for the non-LSP fallback
+/// steps of [`goto_definition`]. `None` if it isn't a plain identifier.
+fn symbol_under_cursor(editor: &Editor) -> Option<String> {
+    let (view, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
+    let range = doc.selection(view.id).primary();
+    let word_range = textobject::textobject_word(text, range, textobject::TextObject::Inside, 1, false);
+    let word = word_range.fragment(text).into_owned();
+    if word.is_empty() || !word.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        None
+    } else {
+        Some(word)
+    }
+}
+
+fn goto_definition_fallback(cx: &mut Context) {
+    let Some(word) = symbol_under_cursor(cx.editor) else {
+        cx.editor.set_error("No definition found.");
+        return;
+    };
+
+    if let Some((path, line)) = lookup_tag(&word) {
+        jump_to_file_line(cx.editor, &path, line, Action::Replace);
+        return;
+    }
+
+    match goto_definition_grep(cx.editor, &word) {
+        Some(picker) => cx.push_layer(picker),
+        None => cx.editor.set_error("No definition found."),
+    }
+}
+
+fn goto_definition_fallback_impl(editor: &mut Editor, compositor: &mut Compositor) {
+    let Some(word) = symbol_under_cursor(editor) else {
+        editor.set_error("No definition found.");
+        return;
+    };
+
+    if let Some((path, line)) = lookup_tag(&word) {
+        jump_to_file_line(editor, &path, line, Action::Replace);
+        return;
+    }
+
+    match goto_definition_grep(editor, &word) {
+        Some(picker) => compositor.push(picker),
+        None => editor.set_error("No definition found."),
+    }
+}
+
+/// A single entry (`{name}\t{file}\t{excmd}`) parsed out of a ctags-format
+/// `tags` file. Only the subset needed to jump to a name is handled: exact
+/// name matches and line-number excmds (`123;"`), not the general ex-command
+/// search patterns ctags can also emit.
+fn lookup_tag(word: &str) -> Option<(PathBuf, usize)> {
+    let (workspace, _) = helix_loader::find_workspace();
+    let tags_path = workspace.join("tags");
+    let contents = std::fs::read_to_string(&tags_path).ok()?;
+
+    for line in contents.lines() {
+        if line.starts_with('!') {
+            continue;
+        }
+        let mut fields = line.splitn(3, '\t');
+        let (name, file, excmd) = (fields.next()?, fields.next()?, fields.next()?);
+        if name != word {
+            continue;
+        }
+        let line_num = excmd
+            .split(';')
+            .next()?
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .map(|l| l.saturating_sub(1))
+            .unwrap_or(0);
+        return Some((workspace.join(file), line_num));
+    }
+    None
+}
+
+fn jump_to_file_line(editor: &mut Editor, path: &Path, line: usize, action: Action) {
+    let (view, doc) = current!(editor);
+    push_jump(view, doc);
+
+    let doc_id = match editor.open(path, action) {
+        Ok(id) => id,
+        Err(err) => {
+            editor.set_error(format!("failed to open {}: {err}", path.display()));
+            return;
+        }
+    };
+    let doc = doc_mut!(editor, &doc_id);
+    let view = view_mut!(editor);
+    let text = doc.text().slice(..);
+    let line = line.min(text.len_lines().saturating_sub(1));
+    let pos = text.line_to_char(line);
+    doc.set_selection(view.id, Selection::point(pos));
+    align_view(doc, view, Align::Center);
+}
+
+/// Last-resort fallback: a word-boundary grep across the workspace, opened
+/// as a picker just like `:global-search`'s results. Returns `None` (and
+/// sets no error itself) when the pattern can't be built or nothing matched,
+/// leaving the caller to report "No definition found.".
+fn goto_definition_grep(editor: &mut Editor, word: &str) -> Option<Box<dyn Component>> {
+    #[derive(Debug)]
+    struct FileResult {
+        path: PathBuf,
+        line_num: usize,
+        line_content: String,
+    }
+
+    let file_picker_config = editor.config().file_picker.clone();
+    let search_root = helix_loader::current_working_dir();
+    let absolute_root = search_root
+        .canonicalize()
+        .unwrap_or_else(|_| search_root.clone());
+
+    let matcher = RegexMatcherBuilder::new()
+        .build(&format!(r"\b{}\b", regex::escape(word)))
+        .ok()?;
+
+    let results = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let searcher = SearcherBuilder::new()
+        .binary_detection(BinaryDetection::quit(b'\x00'))
+        .build();
+    let dedup_symlinks = file_picker_config.deduplicate_links;
+
+    WalkBuilder::new(&search_root)
+        .hidden(file_picker_config.hidden)
+        .parents(file_picker_config.parents)
+        .ignore(file_picker_config.ignore)
+        .follow_links(file_picker_config.follow_symlinks)
+        .git_ignore(file_picker_config.git_ignore)
+        .git_global(file_picker_config.git_global)
+        .git_exclude(file_picker_config.git_exclude)
+        .max_depth(file_picker_config.max_depth)
+        .filter_entry(move |entry| filter_picker_entry(entry, &absolute_root, dedup_symlinks))
+        .build_parallel()
+        .run(|| {
+            let mut searcher = searcher.clone();
+            let matcher = matcher.clone();
+            let results = Arc::clone(&results);
+            Box::new(move |entry: Result<ignore::DirEntry, ignore::Error>| -> ignore::WalkState {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(_) => return ignore::WalkState::Continue,
+                };
+                if !matches!(entry.file_type(), Some(ft) if ft.is_file()) {
+                    return ignore::WalkState::Continue;
+                }
+
+                let sink = sinks::UTF8(|line_num, line_content| {
+                    results.lock().unwrap().push(FileResult {
+                        path: entry.path().to_path_buf(),
+                        line_num: line_num as usize - 1,
+                        line_content: line_content.to_string(),
+                    });
+                    Ok(true)
+                });
+                if let Err(err) = searcher.search_path(&matcher, entry.path(), sink) {
+                    log::error!("goto-definition grep fallback: {}, {}", entry.path().display(), err);
+                }
+                ignore::WalkState::Continue
+            })
+        });
+
+    let results = Arc::try_unwrap(results).unwrap().into_inner().unwrap();
+    if results.is_empty() {
+        return None;
+    }
+
+    let columns = vec![
+        ui::PickerColumn::new("path", |item: &FileResult, _| {
+            helix_core::path::get_relative_path(&item.path)
+                .to_string_lossy()
+                .into_owned()
+                .into()
+        }),
+        ui::PickerColumn::new("contents", |item: &FileResult, _| {
+            item.line_content.as_str().into()
+        })
+        .without_filtering(),
+    ];
+    let picker = ui::Picker::new(columns, 1, results, (), |cx, item: &FileResult, action| {
+        jump_to_file_line(cx.editor, &item.path, item.line_num, action);
+    });
+    Some(Box::new(overlaid(picker)))
+}
+
 pub fn goto_type_definition(cx: &mut Context) {
     goto_single_impl(
         cx,
@@ -1203,11 +1790,335 @@ pub fn goto_reference(cx: &mut Context) {
         future,
         move |editor, compositor, response: Option<Vec<lsp::Location>>| {
             let items = response.unwrap_or_default();
-            goto_impl(editor, compositor, items, offset_encoding);
+            goto_impl(editor, compositor, items, offset_encoding, false);
+        },
+    );
+}
+
+/// A `CallHierarchyItem` resolved via `textDocument/prepareCallHierarchy`,
+/// flattened into a tree the same way [`SymbolInformationItem`] is for
+/// document symbols: each row's "expand" action fetches one more level of
+/// calls for the selected row and appends them at `depth + 1`, rather than
+/// eagerly recursing. Callers/callees can be cyclic, and most of the time a
+/// user only wants to look one or two levels deep.
+#[derive(Clone)]
+struct CallHierarchyItem {
+    item: lsp::CallHierarchyItem,
+    offset_encoding: OffsetEncoding,
+    depth: usize,
+}
+
+fn call_hierarchy_item_location(item: &lsp::CallHierarchyItem) -> FileLocation {
+    let line = Some((
+        item.selection_range.start.line as usize,
+        item.selection_range.end.line as usize,
+    ));
+    (item.uri.to_file_path().unwrap().into(), line)
+}
+
+/// Extracts the `from`/`to` items out of a `callHierarchy/incomingCalls` or
+/// `callHierarchy/outgoingCalls` response, discarding the individual call
+/// site ranges -- the picker's preview jumps to the callee/caller's own
+/// `selectionRange` instead of each call site.
+fn call_hierarchy_items(
+    response: serde_json::Value,
+    incoming: bool,
+) -> anyhow::Result<Vec<lsp::CallHierarchyItem>> {
+    let items = if incoming {
+        serde_json::from_value::<Option<Vec<lsp::CallHierarchyIncomingCall>>>(response)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| call.from)
+            .collect()
+    } else {
+        serde_json::from_value::<Option<Vec<lsp::CallHierarchyOutgoingCall>>>(response)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| call.to)
+            .collect()
+    };
+    Ok(items)
+}
+
+type CallHierarchyPicker = Picker<CallHierarchyItem, ()>;
+
+fn call_hierarchy_picker(
+    language_server_id: usize,
+    items: Vec<CallHierarchyItem>,
+    incoming: bool,
+) -> CallHierarchyPicker {
+    let columns = vec![
+        ui::PickerColumn::new("name", |item: &CallHierarchyItem, _| {
+            if item.depth == 0 {
+                item.item.name.as_str().into()
+            } else {
+                format!("{}{}", "  ".repeat(item.depth), item.item.name).into()
+            }
+        }),
+        ui::PickerColumn::new("path", |item: &CallHierarchyItem, _| {
+            match item.item.uri.to_file_path() {
+                Ok(path) => path::get_relative_path(path.as_path())
+                    .to_string_lossy()
+                    .to_string()
+                    .into(),
+                Err(_) => item.item.uri.to_string().into(),
+            }
+        }),
+    ];
+
+    let picker = Picker::new(columns, 0, items, (), move |cx, item, action| {
+        jump_to_location(
+            cx.editor,
+            &lsp::Location::new(item.item.uri.clone(), item.item.selection_range),
+            item.offset_encoding,
+            action,
+        );
+    })
+    .with_preview(|_editor, item| Some(call_hierarchy_item_location(&item.item)))
+    .truncate_start(false);
+
+    picker.with_action(
+        alt!('l'),
+        if incoming {
+            "expand callers"
+        } else {
+            "expand callees"
+        },
+        move |cx, item: &CallHierarchyItem| {
+            let Some(language_server) = cx.editor.language_server_by_id(language_server_id) else {
+                cx.editor
+                    .set_error("Language server for call hierarchy exited");
+                return;
+            };
+            let offset_encoding = language_server.offset_encoding();
+            let future = if incoming {
+                language_server.incoming_calls(item.item.clone())
+            } else {
+                language_server.outgoing_calls(item.item.clone())
+            };
+            let Some(future) = future else { return };
+            let depth = item.depth + 1;
+
+            cx.jobs.callback(async move {
+                let response = future.await?;
+                let items = call_hierarchy_items(response, incoming)?
+                    .into_iter()
+                    .map(|item| CallHierarchyItem {
+                        item,
+                        offset_encoding,
+                        depth,
+                    })
+                    .collect::<Vec<_>>();
+                let call = move |_editor: &mut Editor, compositor: &mut Compositor| {
+                    if let Some(picker) =
+                        compositor.find::<ui::overlay::Overlay<CallHierarchyPicker>>()
+                    {
+                        let injector = picker.content.injector();
+                        for item in items {
+                            let _ = injector.push(item);
+                        }
+                    }
+                };
+                Ok(Callback::EditorCompositor(Box::new(call)))
+            });
+        },
+    )
+}
+
+fn call_hierarchy(cx: &mut Context, incoming: bool) {
+    let (view, doc) = current!(cx.editor);
+    let language_server =
+        language_server_with_feature!(cx.editor, doc, LanguageServerFeature::CallHierarchy);
+    let language_server_id = language_server.id();
+    let offset_encoding = language_server.offset_encoding();
+    let pos = doc.position(view.id, offset_encoding);
+    let future = language_server
+        .prepare_call_hierarchy(doc.identifier(), pos)
+        .unwrap();
+
+    cx.jobs.callback(async move {
+        let response = future.await?;
+        let items: Vec<lsp::CallHierarchyItem> =
+            serde_json::from_value::<Option<Vec<lsp::CallHierarchyItem>>>(response)?
+                .unwrap_or_default();
+        let Some(item) = items.into_iter().next() else {
+            let call = move |editor: &mut Editor, _compositor: &mut Compositor| {
+                editor.set_error("No call hierarchy item found under the cursor");
+            };
+            return Ok(Callback::EditorCompositor(Box::new(call)));
+        };
+
+        let call = move |editor: &mut Editor, _compositor: &mut Compositor| {
+            let Some(language_server) = editor.language_server_by_id(language_server_id) else {
+                editor.set_error("Language server for call hierarchy exited");
+                return;
+            };
+            let future = if incoming {
+                language_server.incoming_calls(item.clone())
+            } else {
+                language_server.outgoing_calls(item.clone())
+            };
+            let Some(future) = future else { return };
+
+            editor.reset_idle_timer();
+            tokio::spawn(async move {
+                match future.await {
+                    Ok(response) => {
+                        let items = call_hierarchy_items(response, incoming).map(|items| {
+                            items
+                                .into_iter()
+                                .map(|item| CallHierarchyItem {
+                                    item,
+                                    offset_encoding,
+                                    depth: 0,
+                                })
+                                .collect::<Vec<_>>()
+                        });
+                        crate::job::dispatch(move |editor, compositor| match items {
+                            Ok(items) => {
+                                let picker =
+                                    call_hierarchy_picker(language_server_id, items, incoming);
+                                compositor.push(Box::new(overlaid(picker)));
+                            }
+                            Err(err) => editor.set_error(err.to_string()),
+                        })
+                        .await
+                    }
+                    Err(err) => log::error!("call hierarchy request failed: {err}"),
+                }
+            });
+        };
+        Ok(Callback::EditorCompositor(Box::new(call)))
+    });
+}
+
+pub fn call_hierarchy_incoming(cx: &mut Context) {
+    call_hierarchy(cx, true);
+}
+
+pub fn call_hierarchy_outgoing(cx: &mut Context) {
+    call_hierarchy(cx, false);
+}
+
+/// A URL appearing as plain text, used as a fallback for [`goto_link`] when
+/// no language server supports `textDocument/documentLink`, or the server
+/// didn't return a link under the cursor.
+static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"https?://\S+[^\s.,:;!?'\")\]]").unwrap());
+
+/// `gx`. Opens the link under the cursor: a file is opened in the editor,
+/// anything else (e.g. an `http(s)://` URL) is handed to the OS via the
+/// platform opener, the same way [`open_file_manager`] reveals a directory.
+/// Prefers a `textDocument/documentLink` result if any attached language
+/// server supports it; otherwise falls back to matching a bare URL on the
+/// cursor's line. Doesn't resolve unresolved links via `documentLink/resolve`
+/// since none of the servers we target need it for their `target` field to
+/// already be populated.
+pub fn goto_link(cx: &mut Context) {
+    let (view, doc) = current_ref!(cx.editor);
+    let view_id = view.id;
+    let pos = doc.selection(view_id).primary().cursor(doc.text().slice(..));
+
+    let language_server = doc
+        .language_servers_with_feature(LanguageServerFeature::DocumentLink)
+        .next();
+
+    let Some(language_server) = language_server else {
+        goto_link_fallback(cx.editor);
+        return;
+    };
+
+    let offset_encoding = language_server.offset_encoding();
+    let future = language_server
+        .text_document_document_link(doc.identifier())
+        .unwrap();
+
+    cx.callback(
+        future,
+        move |editor, _compositor, response: Option<Vec<lsp::DocumentLink>>| {
+            let link = response.and_then(|links| {
+                let (view, doc) = current_ref!(editor);
+                let text = doc.text();
+                links.into_iter().find(|link| {
+                    lsp_range_to_range(text, link.range, offset_encoding)
+                        .is_some_and(|range| range.contains(pos))
+                        && view.id == view_id
+                })
+            });
+
+            match link.and_then(|link| link.target) {
+                Some(uri) => open_link(editor, &uri),
+                None => goto_link_fallback(editor),
+            }
         },
     );
 }
 
+/// Matches a bare URL on the cursor's current line for [`goto_link`] when no
+/// language server offers `textDocument/documentLink`.
+fn goto_link_fallback(editor: &mut Editor) {
+    let (view, doc) = current_ref!(editor);
+    let text = doc.text().slice(..);
+    let cursor = doc.selection(view.id).primary().cursor(text);
+    let line = text.char_to_line(cursor);
+    let line_start = text.line_to_char(line);
+    let line_text = text.line(line).to_string();
+
+    let Some(mat) = URL_REGEX.find(&line_text) else {
+        editor.set_error("No link found under cursor");
+        return;
+    };
+
+    // Only follow the match the cursor is actually on, not just any URL on the line.
+    // `mat.start()`/`mat.end()` are byte offsets into `line_text`, but `cursor` is
+    // a char index, so convert before comparing to avoid misaligning on
+    // multi-byte characters earlier in the line.
+    let cursor_offset = cursor - line_start;
+    let match_start = line_text[..mat.start()].chars().count();
+    let match_end = line_text[..mat.end()].chars().count();
+    if cursor_offset < match_start || cursor_offset > match_end {
+        editor.set_error("No link found under cursor");
+        return;
+    }
+
+    match lsp::Url::parse(mat.as_str()) {
+        Ok(uri) => open_link(editor, &uri),
+        Err(err) => editor.set_error(format!("Invalid link: {err}")),
+    }
+}
+
+/// Opens `uri` in the editor if it's a `file://` link, or hands it to the
+/// platform's URL/file opener otherwise.
+fn open_link(editor: &mut Editor, uri: &lsp::Url) {
+    if uri.scheme() == "file" {
+        jump_to_location(
+            editor,
+            &lsp::Location {
+                uri: uri.clone(),
+                range: lsp::Range::default(),
+            },
+            OffsetEncoding::Utf8,
+            Action::Replace,
+        );
+        return;
+    }
+
+    let (cmd, args): (&str, &[&str]) = if cfg!(target_os = "macos") {
+        ("open", &[])
+    } else if cfg!(target_os = "windows") {
+        ("explorer", &[])
+    } else {
+        ("xdg-open", &[])
+    };
+
+    if let Err(err) = std::process::Command::new(cmd)
+        .args(args)
+        .arg(uri.as_str())
+        .spawn()
+    {
+        editor.set_error(format!("failed to open link: {err}"));
+    }
+}
+
 pub fn signature_help(cx: &mut Context) {
     cx.editor
         .handlers
@@ -1289,12 +2200,19 @@ fn get_prefill_from_lsp_response(
             Some(lsp::PrepareRenameResponse::Range(range)) => {
                 let text = doc!(editor).text();
 
-                Ok(lsp_range_to_range(text, range, offset_encoding)
+                let prefill: String = lsp_range_to_range(text, range, offset_encoding)
                     .ok_or("lsp sent invalid selection range for rename")?
                     .fragment(text.slice(..))
-                    .into())
+                    .into();
+                if prefill.trim().is_empty() {
+                    return Err("lsp reported this position cannot be renamed");
+                }
+                Ok(prefill)
             }
             Some(lsp::PrepareRenameResponse::RangeWithPlaceholder { placeholder, .. }) => {
+                if placeholder.trim().is_empty() {
+                    return Err("lsp reported this position cannot be renamed");
+                }
                 Ok(placeholder)
             }
             Some(lsp::PrepareRenameResponse::DefaultBehavior { .. }) => {
@@ -1317,6 +2235,10 @@ fn create_rename_prompt(
                 if event != PromptEvent::Validate {
                     return;
                 }
+                if input.trim().is_empty() {
+                    cx.editor.set_error("New name cannot be empty");
+                    return;
+                }
                 let (view, doc) = current!(cx.editor);
 
                 let Some(language_server) = doc
@@ -1336,7 +2258,14 @@ fn create_rename_prompt(
 
                 match block_on(future) {
                     Ok(edits) => {
-                        let _ = apply_workspace_edit(cx.editor, offset_encoding, &edits);
+                        let (files, edit_count) = workspace_edit_summary(&edits);
+                        match apply_workspace_edit(cx.editor, offset_encoding, &edits, "rename symbol")
+                        {
+                            Ok(()) => cx.editor.set_status(format!(
+                                "renamed {edit_count} occurrence(s) across {files} file(s)"
+                            )),
+                            Err(err) => cx.editor.set_error(err.kind.to_string()),
+                        }
                     }
                     Err(err) => cx.editor.set_error(err.to_string()),
                 }
@@ -1432,6 +2361,96 @@ pub fn select_references_to_symbol_under_cursor(cx: &mut Context) {
     );
 }
 
+pub fn compute_code_lens_for_all_views(editor: &mut Editor, jobs: &mut crate::job::Jobs) {
+    for (view, _) in editor.tree.views() {
+        let doc = match editor.documents.get(&view.doc) {
+            Some(doc) => doc,
+            None => continue,
+        };
+        if let Some(callback) = compute_code_lens_for_view(view, doc) {
+            jobs.callback(callback);
+        }
+    }
+}
+
+fn compute_code_lens_for_view(
+    view: &View,
+    doc: &Document,
+) -> Option<std::pin::Pin<Box<impl Future<Output = Result<crate::job::Callback, anyhow::Error>>>>> {
+    let doc_id = view.doc;
+
+    // Only refetch once per edit: `reset_code_lens` clears `code_lens` on
+    // every change, so a non-empty cache with the document otherwise idle
+    // means the current lenses are still fresh.
+    if !doc.code_lens.is_empty() {
+        return None;
+    }
+
+    let language_server = doc
+        .language_servers_with_feature(LanguageServerFeature::CodeLens)
+        .next()?;
+    let language_server_id = language_server.id();
+    let offset_encoding = language_server.offset_encoding();
+
+    let callback = super::make_job_callback(
+        language_server.text_document_code_lens(doc.identifier())?,
+        move |editor, _compositor, response: Option<Vec<lsp::CodeLens>>| {
+            let doc = match editor.documents.get_mut(&doc_id) {
+                Some(doc) => doc,
+                None => return,
+            };
+            doc.set_code_lens(language_server_id, response.unwrap_or_default(), offset_encoding);
+        },
+    );
+
+    Some(callback)
+}
+
+pub fn compute_document_colors_for_all_views(editor: &mut Editor, jobs: &mut crate::job::Jobs) {
+    for (view, _) in editor.tree.views() {
+        let doc = match editor.documents.get(&view.doc) {
+            Some(doc) => doc,
+            None => continue,
+        };
+        if let Some(callback) = compute_document_colors_for_view(view, doc) {
+            jobs.callback(callback);
+        }
+    }
+}
+
+fn compute_document_colors_for_view(
+    view: &View,
+    doc: &Document,
+) -> Option<std::pin::Pin<Box<impl Future<Output = Result<crate::job::Callback, anyhow::Error>>>>> {
+    let doc_id = view.doc;
+
+    // Mirrors `compute_code_lens_for_view`: a non-empty cache means the
+    // colors fetched for the current buffer state are still fresh, since
+    // edits clear it via `Document::reset_document_colors`.
+    if !doc.document_colors.is_empty() {
+        return None;
+    }
+
+    let language_server = doc
+        .language_servers_with_feature(LanguageServerFeature::DocumentColor)
+        .next()?;
+    let language_server_id = language_server.id();
+    let offset_encoding = language_server.offset_encoding();
+
+    let callback = super::make_job_callback(
+        language_server.text_document_document_color(doc.identifier())?,
+        move |editor, _compositor, response: Option<Vec<lsp::ColorInformation>>| {
+            let doc = match editor.documents.get_mut(&doc_id) {
+                Some(doc) => doc,
+                None => return,
+            };
+            doc.set_document_colors(language_server_id, response.unwrap_or_default(), offset_encoding);
+        },
+    );
+
+    Some(callback)
+}
+
 pub fn compute_inlay_hints_for_all_views(editor: &mut Editor, jobs: &mut crate::job::Jobs) {
     if !editor.config().lsp.display_inlay_hints {
         return;
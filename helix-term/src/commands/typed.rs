@@ -1,5 +1,7 @@
+use std::collections::HashSet;
 use std::fmt::Write;
 use std::ops::Deref;
+use std::time::SystemTime;
 
 use crate::job::Job;
 
@@ -8,7 +10,7 @@
 use helix_core::fuzzy::fuzzy_match;
 use helix_core::{encoding, line_ending, shellwords::Shellwords};
 use helix_view::document::DEFAULT_LANGUAGE_NAME;
-use helix_view::editor::{Action, CloseError, ConfigEvent};
+use helix_view::editor::{Action, CloseError, ConfigEvent, Severity};
 use serde_json::Value;
 use ui::completers::{self, Completer};
 
@@ -75,7 +77,16 @@ fn quit(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
 
     // last view and we have unsaved changes
     if cx.editor.tree.views().count() == 1 {
-        buffers_remaining_impl(cx.editor)?
+        let modified_ids: Vec<_> = cx
+            .editor
+            .documents()
+            .filter(|doc| doc.is_modified())
+            .map(|doc| doc.id())
+            .collect();
+        if !modified_ids.is_empty() {
+            push_buffer_close_review(cx, modified_ids, ui::PendingQuit::View(view!(cx.editor).id));
+            return Ok(());
+        }
     }
 
     cx.block_try_flush_writes()?;
@@ -84,6 +95,23 @@ fn quit(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) ->
     Ok(())
 }
 
+/// Pushes [`ui::BufferCloseReview`] instead of bailing with a plain "N
+/// unsaved buffer(s) remaining" error, so the quit it's blocking can be
+/// resolved interactively.
+fn push_buffer_close_review(
+    cx: &mut compositor::Context,
+    doc_ids: Vec<DocumentId>,
+    pending: ui::PendingQuit,
+) {
+    let callback: crate::job::Callback = crate::job::Callback::EditorCompositor(Box::new(
+        move |editor, compositor| {
+            let review = ui::BufferCloseReview::new(editor, doc_ids, pending);
+            compositor.push(Box::new(review));
+        },
+    ));
+    cx.jobs.callback(async move { Ok(callback) });
+}
+
 fn force_quit(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -325,6 +353,174 @@ fn buffer_previous(
     Ok(())
 }
 
+fn buffer_pin(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).pinned = true;
+    Ok(())
+}
+
+fn buffer_unpin(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).pinned = false;
+    Ok(())
+}
+
+/// Toggles `editor.lsp.inline-diagnostics.enabled` for the current buffer
+/// only, overriding the global setting either way.
+fn toggle_inline_diagnostics(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let default = cx.editor.config().lsp.inline_diagnostics.enabled;
+    let doc = doc_mut!(cx.editor);
+    doc.inline_diagnostics = Some(!doc.inline_diagnostics.unwrap_or(default));
+    Ok(())
+}
+
+/// Runs the `textDocument/codeLens` command under the cursor (e.g. `Run
+/// test`, `3 references`), as last fetched by the idle-timeout job in
+/// `commands::lsp`. Errors if the current line has no lens, or if its lens
+/// hasn't been resolved to a command yet.
+fn code_lens_execute(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let line = doc.text().char_to_line(doc.selection(view.id).primary().cursor(doc.text().slice(..)));
+    let (language_server_id, lens) = doc
+        .code_lens_at_line(line)
+        .ok_or_else(|| anyhow::anyhow!("no code lens on the current line"))?;
+    let command = lens
+        .command
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("code lens has not been resolved to a command yet"))?;
+
+    execute_lsp_command(cx.editor, language_server_id, command);
+    Ok(())
+}
+
+/// Offers alternate textual representations (hex, `rgb()`, `hsl()`, ...) for
+/// the color literal under the cursor via `textDocument/colorPresentation`,
+/// applying whichever one the user picks as a workspace edit.
+fn document_color_presentation(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let cursor = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+
+    let language_server = doc
+        .language_servers_with_feature(LanguageServerFeature::DocumentColor)
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("no language server supports document colors"))?;
+    let offset_encoding = language_server.offset_encoding();
+
+    let (_, color_info) = doc
+        .document_color_at(cursor, offset_encoding)
+        .ok_or_else(|| anyhow::anyhow!("no color literal under the cursor"))?;
+    let range = color_info.range;
+    let color = color_info.color;
+
+    let future = language_server
+        .text_document_color_presentation(doc.identifier(), color, range)
+        .ok_or_else(|| anyhow::anyhow!("language server does not support color presentation"))?;
+
+    cx.jobs.callback(async move {
+        let json = future.await?;
+        let presentations: Vec<lsp::ColorPresentation> = serde_json::from_value(json)?;
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                let columns = vec![ui::PickerColumn::new(
+                    "label",
+                    |presentation: &lsp::ColorPresentation, _| presentation.label.as_str().into(),
+                )];
+                let picker = ui::Picker::new(
+                    columns,
+                    0,
+                    presentations,
+                    (),
+                    move |cx, presentation: &lsp::ColorPresentation, _action| {
+                        let Some(text_edit) = presentation.text_edit.clone() else {
+                            return;
+                        };
+                        let mut edits = vec![text_edit];
+                        edits.extend(presentation.additional_text_edits.iter().flatten().cloned());
+                        let (view, doc) = current!(cx.editor);
+                        let transaction = helix_lsp::util::generate_transaction_from_edits(
+                            doc.text(),
+                            edits,
+                            offset_encoding,
+                        );
+                        doc.apply(&transaction, view.id);
+                        doc.append_changes_to_history(view);
+                    },
+                );
+                compositor.push(Box::new(overlaid(picker)))
+            },
+        ));
+        Ok(call)
+    });
+
+    Ok(())
+}
+
+fn buffer_move_left(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc_id = doc!(cx.editor).id();
+    cx.editor.move_buffer(doc_id, Direction::Backward);
+    Ok(())
+}
+
+fn buffer_move_right(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc_id = doc!(cx.editor).id();
+    cx.editor.move_buffer(doc_id, Direction::Forward);
+    Ok(())
+}
+
 fn write_impl(
     cx: &mut compositor::Context,
     path: Option<&Cow<str>>,
@@ -339,7 +535,8 @@ fn write_impl(
         insert_final_newline(doc, view);
     }
 
-    let fmt = if config.auto_format {
+    let fmt = if config.auto_format && !config.auto_format_excluded(doc.path().map(PathBuf::as_path))
+    {
         doc.auto_format().map(|fmt| {
             let callback = make_format_callback(
                 doc.id(),
@@ -481,6 +678,16 @@ fn set_indent_style(
         return Ok(());
     }
 
+    // `:indent-style detect` re-runs the heuristic detector, ignoring the
+    // language config fallback it would otherwise use on open.
+    if matches!(args.get(0), Some(arg) if arg.eq_ignore_ascii_case("detect")) {
+        let doc = doc_mut!(cx.editor);
+        let style = helix_core::indent::auto_detect_indent_style(&doc.text())
+            .context("could not confidently detect an indent style for this buffer")?;
+        doc.indent_style = style;
+        return Ok(());
+    }
+
     // Attempt to parse argument as an indent style.
     let style = match args.get(0) {
         Some(arg) if "tabs".starts_with(&arg.to_lowercase()) => Some(Tabs),
@@ -574,6 +781,419 @@ fn set_line_ending(
     Ok(())
 }
 
+/// One match shown in the picker opened by [`structural_search_replace`].
+struct StructuralMatchItem {
+    start_char: usize,
+    end_char: usize,
+    preview: String,
+}
+
+/// Runs a tree-sitter query (`args[0]`) against the current buffer's syntax
+/// tree and previews the capture-based replacement template (`args[1]`) for
+/// every match in a picker, following it there with the same
+/// select-then-edit model as `select_regex`/`split_selection` rather than
+/// applying edits sight-unseen. Pressing `Alt-a` in the picker applies every
+/// previewed replacement to the buffer at once; scoped to the current
+/// buffer, not workspace-wide, since matching across every open and
+/// on-disk file's syntax tree is a much larger undertaking than the
+/// template-expansion machinery this adds (see `helix_core::structural_search`).
+fn structural_search_replace(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let [query_source, template] = args else {
+        anyhow::bail!(
+            "Bad arguments. Usage: `:structural-search-replace <tree-sitter-query> <replacement-template>`"
+        );
+    };
+
+    let (view, doc) = current!(cx.editor);
+    let doc_id = doc.id();
+    let view_id = view.id;
+    let syntax = doc
+        .syntax()
+        .ok_or_else(|| anyhow::anyhow!("current buffer has no syntax tree"))?;
+    let tree = syntax.tree();
+    let query = helix_core::tree_sitter::Query::new(tree.language(), query_source)
+        .map_err(|err| anyhow::anyhow!("invalid tree-sitter query: {err}"))?;
+
+    let text = doc.text().clone();
+    let matches =
+        helix_core::structural_search::find_matches(text.slice(..), tree, &query, template)?;
+
+    if matches.is_empty() {
+        cx.editor.set_status("structural search: no matches");
+        return Ok(());
+    }
+
+    let match_count = matches.len();
+
+    let items: Vec<StructuralMatchItem> = matches
+        .iter()
+        .map(|m| {
+            let start_char = text.byte_to_char(m.byte_range.start);
+            let end_char = text.byte_to_char(m.byte_range.end);
+            let old = text.slice(start_char..end_char).to_string();
+            StructuralMatchItem {
+                start_char,
+                end_char,
+                preview: format!("{old} => {}", m.replacement),
+            }
+        })
+        .collect();
+
+    let mut replacements: Vec<(usize, usize, String)> = items
+        .iter()
+        .zip(&matches)
+        .map(|(item, m)| (item.start_char, item.end_char, m.replacement.clone()))
+        .collect();
+    replacements.sort_by_key(|(start, ..)| *start);
+
+    let columns = vec![ui::PickerColumn::new(
+        "replacement",
+        |item: &StructuralMatchItem, _| item.preview.as_str().into(),
+    )];
+    let picker = ui::Picker::new(columns, 0, items, (), move |cx, item, _action| {
+        let view = view_mut!(cx.editor, view_id);
+        let doc = doc_mut!(cx.editor, &doc_id);
+        doc.set_selection(view_id, Selection::single(item.start_char, item.end_char));
+        align_view(doc, view, Align::Center);
+    })
+    .with_action(
+        alt!('a'),
+        "apply all matches",
+        move |cx, _item: &StructuralMatchItem| {
+            let mut last_end = 0;
+            let changes = replacements.iter().filter_map(|(start, end, replacement)| {
+                if *start < last_end {
+                    // Overlapping matches (possible with quantified capture
+                    // patterns): keep the earlier one, skip this one.
+                    return None;
+                }
+                last_end = *end;
+                Some((*start, *end, Some(replacement.as_str().into())))
+            });
+            let view = view_mut!(cx.editor, view_id);
+            let doc = doc_mut!(cx.editor, &doc_id);
+            let transaction = Transaction::change(doc.text(), changes);
+            doc.apply(&transaction, view_id);
+            doc.append_changes_to_history(view);
+            cx.editor
+                .set_status(format!("structural search: replaced {match_count} matches"));
+        },
+    );
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+/// One match found by [`global_replace`], along with the text that match
+/// would be replaced with. `old_line`/`new_line` are the surrounding line
+/// before and after the substitution, trimmed of their line ending, for
+/// display in the picker; `replacement_text` is just the substituted
+/// fragment, used to build the actual edit.
+#[derive(Clone)]
+struct GlobalReplaceMatch {
+    path: PathBuf,
+    line_num: usize,
+    start_char: usize,
+    end_char: usize,
+    replacement_text: String,
+    old_line: String,
+    new_line: String,
+}
+
+/// Applies `changes` (start char, end char, replacement text) to the
+/// document at `path`, opening it in the background without switching focus
+/// to it if it isn't already open. Mirrors how
+/// [`lsp::apply_workspace_edit`](super::lsp::apply_workspace_edit) picks a
+/// view to attribute edits to for documents that may not be the current
+/// buffer.
+fn apply_global_replacements(
+    editor: &mut Editor,
+    path: &Path,
+    mut changes: Vec<(usize, usize, String)>,
+) -> anyhow::Result<()> {
+    changes.sort_by_key(|(start, ..)| *start);
+
+    let current_view_id = view!(editor).id;
+    let doc_id = editor
+        .open(path, Action::Load)
+        .map_err(|err| anyhow::anyhow!("failed to open {}: {err}", path.display()))?;
+    let doc = doc_mut!(editor, &doc_id);
+
+    let view_id = if doc.selections().contains_key(&current_view_id) {
+        current_view_id
+    } else {
+        // Hack: we take the first available view_id
+        doc.selections()
+            .keys()
+            .next()
+            .copied()
+            .expect("document has no view associated with it")
+    };
+
+    let mut last_end = 0;
+    let changes = changes.into_iter().filter_map(|(start, end, text)| {
+        if start < last_end {
+            // Overlapping matches: keep the earlier one, skip this one.
+            return None;
+        }
+        last_end = end;
+        Some((start, end, Some(text.as_str().into())))
+    });
+    let transaction = Transaction::change(doc.text(), changes);
+    doc.create_checkpoint(GLOBAL_REPLACE_CHECKPOINT.to_owned());
+    let view = view_mut!(editor, view_id);
+    doc.apply(&transaction, view.id);
+    doc.append_changes_to_history(view);
+    Ok(())
+}
+
+/// Checkpoint name recorded on every document touched by `:global-replace`'s
+/// "replace all" action, right before its changes are applied.
+const GLOBAL_REPLACE_CHECKPOINT: &str = "before-global-replace";
+
+/// Searches every file in the workspace for `pattern` (`args[0]`) and shows
+/// what it would look like with `replacement` (`args[1]`) substituted in,
+/// one row per match, in a picker following the same select-then-edit model
+/// as [`structural_search_replace`]. Capture groups in `replacement` use
+/// `regex::Regex`'s `$1`/`$name` expansion syntax.
+///
+/// Scoped down from a live-typed, per-match-toggle find-and-replace panel to
+/// a static picker built from one pattern/replacement pair up front:
+/// `Enter` jumps to a match, `Alt-r` replaces just the selected match, and
+/// `Alt-a` replaces every match shown. Each file's matches are applied as a
+/// single buffered transaction to that file's document, so normal per-file
+/// undo still works and nothing is written to disk until the user saves.
+fn global_replace(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let [pattern, replacement] = args else {
+        anyhow::bail!("Bad arguments. Usage: `:global-replace <pattern> <replacement>`");
+    };
+
+    let config = cx.editor.config();
+    let case_insensitive = if config.search.smart_case {
+        !pattern.chars().any(char::is_uppercase)
+    } else {
+        false
+    };
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .multi_line(true)
+        .build()
+        .map_err(|err| anyhow::anyhow!("invalid regex: {err}"))?;
+    let replacement = replacement.to_string();
+
+    let file_picker_config = config.file_picker.clone();
+    let search_root = helix_loader::current_working_dir();
+    if !search_root.exists() {
+        anyhow::bail!("current working directory does not exist");
+    }
+    let dedup_symlinks = file_picker_config.deduplicate_links;
+    let absolute_root = search_root
+        .canonicalize()
+        .unwrap_or_else(|_| search_root.clone());
+
+    let documents: Vec<_> = cx
+        .editor
+        .documents()
+        .map(|doc| (doc.path().cloned(), doc.text().clone()))
+        .collect();
+
+    let callback = async move {
+        let matches: Vec<GlobalReplaceMatch> = tokio::task::spawn_blocking(move || {
+            let mut matches = Vec::new();
+            let walker = WalkBuilder::new(&search_root)
+                .hidden(file_picker_config.hidden)
+                .parents(file_picker_config.parents)
+                .ignore(file_picker_config.ignore)
+                .follow_links(file_picker_config.follow_symlinks)
+                .git_ignore(file_picker_config.git_ignore)
+                .git_global(file_picker_config.git_global)
+                .git_exclude(file_picker_config.git_exclude)
+                .max_depth(file_picker_config.max_depth)
+                .filter_entry(move |entry| {
+                    filter_picker_entry(entry, &absolute_root, dedup_symlinks)
+                })
+                .build();
+
+            for entry in walker {
+                let Ok(entry) = entry else { continue };
+                if !entry.file_type().map_or(false, |ft| ft.is_file()) {
+                    continue;
+                }
+                let path = entry.path();
+                let open_doc = documents
+                    .iter()
+                    .find(|(doc_path, _)| doc_path.as_deref() == Some(path));
+                let text = match open_doc {
+                    Some((_, rope)) => rope.clone(),
+                    None => match std::fs::read_to_string(path) {
+                        Ok(content) => Rope::from_str(&content),
+                        Err(_) => continue,
+                    },
+                };
+                let content = text.to_string();
+
+                for caps in regex.captures_iter(&content) {
+                    let mat = caps.get(0).expect("capture group 0 is always present");
+                    let start_char = text.byte_to_char(mat.start());
+                    let end_char = text.byte_to_char(mat.end());
+                    let line = text.char_to_line(start_char);
+                    let line_start = text.line_to_char(line);
+                    let line_end = text.line_to_char((line + 1).min(text.len_lines()));
+
+                    let mut replacement_text = String::new();
+                    caps.expand(&replacement, &mut replacement_text);
+                    let new_line = format!(
+                        "{}{}{}",
+                        text.slice(line_start..start_char),
+                        replacement_text,
+                        text.slice(end_char..line_end),
+                    );
+
+                    matches.push(GlobalReplaceMatch {
+                        path: path.to_path_buf(),
+                        line_num: line,
+                        start_char,
+                        end_char,
+                        replacement_text,
+                        old_line: text.slice(line_start..line_end).to_string().trim_end().to_string(),
+                        new_line: new_line.trim_end().to_string(),
+                    });
+                }
+            }
+            matches
+        })
+        .await?;
+
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                if matches.is_empty() {
+                    editor.set_status("global replace: no matches");
+                    return;
+                }
+
+                let all_matches = matches.clone();
+
+                let columns = vec![
+                    ui::PickerColumn::new("path", |item: &GlobalReplaceMatch, _| {
+                        format!(
+                            "{}:{}",
+                            helix_core::path::get_relative_path(&item.path).to_string_lossy(),
+                            item.line_num + 1
+                        )
+                        .into()
+                    }),
+                    ui::PickerColumn::new("change", |item: &GlobalReplaceMatch, _| {
+                        format!("{} => {}", item.old_line, item.new_line).into()
+                    }),
+                ];
+
+                let picker = ui::Picker::new(
+                    columns,
+                    1, // change
+                    matches,
+                    (),
+                    |cx, item: &GlobalReplaceMatch, action| {
+                        let doc_id = match cx.editor.open(&item.path, action) {
+                            Ok(id) => id,
+                            Err(err) => {
+                                cx.editor.set_error(format!(
+                                    "failed to open {}: {err}",
+                                    item.path.display()
+                                ));
+                                return;
+                            }
+                        };
+                        let view = view_mut!(cx.editor);
+                        let doc = doc_mut!(cx.editor, &doc_id);
+                        doc.set_selection(view.id, Selection::single(item.start_char, item.end_char));
+                        if action.align_view(view, doc.id()) {
+                            align_view(doc, view, Align::Center);
+                        }
+                    },
+                )
+                .with_preview(|_editor, item| {
+                    Some((
+                        item.path.clone().into(),
+                        Some((item.line_num, item.line_num)),
+                    ))
+                })
+                .with_action(
+                    alt!('r'),
+                    "replace this match",
+                    |cx, item: &GlobalReplaceMatch| {
+                        let changes = vec![(item.start_char, item.end_char, item.replacement_text.clone())];
+                        match apply_global_replacements(cx.editor, &item.path, changes) {
+                            Ok(()) => cx.editor.set_status(format!(
+                                "global replace: replaced 1 match in {}",
+                                item.path.display()
+                            )),
+                            Err(err) => cx.editor.set_error(err.to_string()),
+                        }
+                    },
+                )
+                .with_action(
+                    alt!('a'),
+                    "replace all matches",
+                    move |cx, _item: &GlobalReplaceMatch| {
+                        let mut by_path: HashMap<PathBuf, Vec<(usize, usize, String)>> =
+                            HashMap::new();
+                        for m in &all_matches {
+                            by_path.entry(m.path.clone()).or_default().push((
+                                m.start_char,
+                                m.end_char,
+                                m.replacement_text.clone(),
+                            ));
+                        }
+                        let file_count = by_path.len();
+                        let mut replaced = 0;
+                        let mut failed = 0;
+                        for (path, changes) in by_path {
+                            let n = changes.len();
+                            match apply_global_replacements(cx.editor, &path, changes) {
+                                Ok(()) => replaced += n,
+                                Err(err) => {
+                                    failed += 1;
+                                    log::error!("global replace: {err}");
+                                }
+                            }
+                        }
+                        if failed == 0 {
+                            cx.editor.set_status(format!(
+                                "global replace: replaced {replaced} matches in {file_count} files"
+                            ));
+                        } else {
+                            cx.editor.set_error(format!(
+                                "global replace: replaced {replaced} matches, {failed} files failed"
+                            ));
+                        }
+                    },
+                );
+                compositor.push(Box::new(overlaid(picker)));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
 fn earlier(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -613,21 +1233,63 @@ fn later(
     Ok(())
 }
 
-fn write_quit(
+/// Reverts the most recent grouped multi-file edit recorded by
+/// `apply_workspace_edit` (an LSP rename or code action touching several
+/// files), reopening any of its files that have since been closed. Unlike
+/// `:earlier`, which only rewinds the current buffer's own history, this
+/// walks every file the edit touched as one unit.
+fn undo_workspace(
     cx: &mut compositor::Context,
-    args: &[Cow<str>],
+    _args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    write_impl(cx, args.first(), false)?;
-    cx.block_try_flush_writes()?;
-    quit(cx, &[], event)
+    let Some(workspace_edit) = cx.editor.workspace_edit_history.pop() else {
+        cx.editor.set_status("no workspace edit to undo");
+        return Ok(());
+    };
+
+    let current_view_id = view!(cx.editor).id;
+    for (path, transaction) in workspace_edit.file_undos {
+        let doc_id = match cx.editor.open(&path, Action::Load) {
+            Ok(doc_id) => doc_id,
+            Err(err) => {
+                cx.editor
+                    .set_error(format!("failed to open {}: {err}", path.display()));
+                continue;
+            }
+        };
+
+        let doc = doc_mut!(cx.editor, &doc_id);
+        let selections = doc.selections();
+        let view_id = if selections.contains_key(&current_view_id) {
+            current_view_id
+        } else {
+            selections
+                .keys()
+                .next()
+                .copied()
+                .expect("No view_id available")
+        };
+
+        let view = view_mut!(cx.editor, view_id);
+        doc.apply(&transaction, view.id);
+        doc.append_changes_to_history(view);
+    }
+
+    cx.editor
+        .set_status(format!("undid workspace edit: {}", workspace_edit.label));
+
+    Ok(())
 }
 
-fn force_write_quit(
+/// Attaches a review note to the current line, replacing any note already
+/// there. Notes are workspace-scoped (see `helix_view::notes`) and are only
+/// visible if a `notes` gutter is configured in `editor.gutters.layout`.
+fn note_add(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
     event: PromptEvent,
@@ -635,44 +1297,315 @@ fn force_write_quit(
     if event != PromptEvent::Validate {
         return Ok(());
     }
+    if args.is_empty() {
+        anyhow::bail!("Usage: `:note-add <text>`");
+    }
+    let text = args.join(" ");
 
-    write_impl(cx, args.first(), true)?;
-    cx.block_try_flush_writes()?;
-    force_quit(cx, &[], event)
-}
+    let (view, doc) = current!(cx.editor);
+    let path = doc
+        .path()
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("cannot add a note to a buffer with no path"))?;
+    let line = doc
+        .selection(view.id)
+        .primary()
+        .cursor_line(doc.text().slice(..));
 
-/// Results in an error if there are modified buffers remaining and sets editor
-/// error, otherwise returns `Ok(())`. If the current document is unmodified,
-/// and there are modified documents, switches focus to one of them.
-pub(super) fn buffers_remaining_impl(editor: &mut Editor) -> anyhow::Result<()> {
-    let (modified_ids, modified_names): (Vec<_>, Vec<_>) = editor
-        .documents()
-        .filter(|doc| doc.is_modified())
-        .map(|doc| (doc.id(), doc.display_name()))
-        .unzip();
-    if let Some(first) = modified_ids.first() {
-        let current = doc!(editor);
-        // If the current document is unmodified, and there are modified
-        // documents, switch focus to the first modified doc.
-        if !modified_ids.contains(&current.id()) {
-            editor.switch(*first, Action::Replace);
-        }
-        bail!(
-            "{} unsaved buffer(s) remaining: {:?}",
-            modified_names.len(),
-            modified_names
-        );
+    cx.editor.notes.add(path, line, text);
+    if let Err(err) = cx.editor.notes.save() {
+        cx.editor.set_error(format!("failed to save notes: {err}"));
     }
+
     Ok(())
 }
 
-pub fn write_all_impl(
+/// Removes the review note on the current line, if any.
+fn note_remove(
     cx: &mut compositor::Context,
-    force: bool,
-    write_scratch: bool,
+    _args: &[Cow<str>],
+    event: PromptEvent,
 ) -> anyhow::Result<()> {
-    let mut errors: Vec<&'static str> = Vec::new();
-    let config = cx.editor.config();
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let Some(path) = doc.path() else {
+        cx.editor.set_status("current buffer has no path");
+        return Ok(());
+    };
+    let line = doc
+        .selection(view.id)
+        .primary()
+        .cursor_line(doc.text().slice(..));
+
+    if cx.editor.notes.remove(path, line) {
+        if let Err(err) = cx.editor.notes.save() {
+            cx.editor.set_error(format!("failed to save notes: {err}"));
+        }
+    } else {
+        cx.editor.set_status("no note on this line");
+    }
+
+    Ok(())
+}
+
+/// One row shown in the picker opened by `:note-list`.
+struct NoteItem {
+    path: PathBuf,
+    line: usize,
+    text: String,
+}
+
+/// Opens a picker listing every review note in the workspace, jumping to
+/// the note's file and line on selection.
+fn note_list(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let items: Vec<NoteItem> = cx
+        .editor
+        .notes
+        .iter()
+        .map(|(path, note)| NoteItem {
+            path: path.to_path_buf(),
+            line: note.line,
+            text: note.text.clone(),
+        })
+        .collect();
+
+    if items.is_empty() {
+        cx.editor.set_status("no notes in this workspace");
+        return Ok(());
+    }
+
+    let columns = vec![
+        ui::PickerColumn::new("path", |item: &NoteItem, _| {
+            format!("{}:{}", item.path.display(), item.line + 1).into()
+        }),
+        ui::PickerColumn::new("note", |item: &NoteItem, _| item.text.as_str().into()),
+    ];
+    let picker = ui::Picker::new(columns, 1, items, (), |cx, item, action| {
+        if let Err(err) = cx.editor.open(&item.path, action) {
+            cx.editor
+                .set_error(format!("failed to open {}: {err}", item.path.display()));
+            return;
+        }
+        let (view, doc) = current!(cx.editor);
+        let line = item.line.min(doc.text().len_lines().saturating_sub(1));
+        let pos = doc.text().line_to_char(line);
+        doc.set_selection(view.id, Selection::point(pos));
+        align_view(doc, view, Align::Center);
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+/// Lists the files that differ from `base` (`HEAD` if omitted) via `git
+/// diff --name-only`, in a picker that opens the selected file for review.
+/// Combine with `:note-add` while reading through the diff and
+/// `:review-export` to write the notes out afterwards; there's no inline
+/// hunk view yet, so the picker's preview shows the file's current
+/// contents rather than a diff.
+fn review(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let base = args.first().map_or("HEAD", |arg| arg.as_ref()).to_string();
+    let root = find_workspace().0;
+    let shell = cx.editor.config().shell.clone();
+    let cmd = format!("git diff --name-only {base}");
+
+    let callback = async move {
+        let (output, _) = shell_impl_async(&shell, &cmd, None).await?;
+        let files: Vec<PathBuf> = output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| root.join(line))
+            .collect();
+
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                if files.is_empty() {
+                    editor.set_status(format!("no changes against {base}"));
+                    return;
+                }
+
+                let columns = vec![ui::PickerColumn::new(
+                    "path",
+                    |item: &PathBuf, root: &PathBuf| {
+                        item.strip_prefix(root)
+                            .unwrap_or(item)
+                            .to_string_lossy()
+                            .into()
+                    },
+                )];
+                let picker = ui::Picker::new(columns, 0, files, root, |cx, path: &PathBuf, action| {
+                    if let Err(err) = cx.editor.open(path, action) {
+                        cx.editor
+                            .set_error(format!("failed to open {}: {err}", path.display()));
+                    }
+                })
+                .with_preview(|_editor, path| Some((path.clone().into(), None)));
+                compositor.push(Box::new(overlaid(picker)));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+/// Writes every review note in the workspace out as a markdown report.
+/// Accepts an optional destination path, defaulting to `review-notes.md`
+/// in the current working directory.
+fn review_export(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let path = args
+        .first()
+        .map(|arg| PathBuf::from(arg.as_ref()))
+        .unwrap_or_else(|| PathBuf::from("review-notes.md"));
+
+    std::fs::write(&path, cx.editor.notes.to_markdown())
+        .map_err(|err| anyhow::anyhow!("failed to write {}: {err}", path.display()))?;
+    cx.editor
+        .set_status(format!("wrote review notes to {}", path.display()));
+
+    Ok(())
+}
+
+/// One row shown in the picker opened by `:schema-check`.
+struct SchemaViolationItem {
+    message: String,
+    pos: usize,
+}
+
+/// Validates the current buffer against a bundled schema for its file name
+/// (see [`helix_core::schema`]), opening a picker of violations. Does
+/// nothing but report status if the file name isn't recognized.
+fn schema_check(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc = doc!(cx.editor);
+    let file_name = doc
+        .path()
+        .and_then(|path| path.file_name())
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow::anyhow!("current buffer has no file name"))?;
+    let schema = helix_core::schema::schema_for_file_name(file_name)
+        .ok_or_else(|| anyhow::anyhow!("no bundled schema for `{file_name}`"))?;
+
+    let text = doc.text().to_string();
+    let violations = helix_core::schema::validate(schema, &text);
+    if violations.is_empty() {
+        cx.editor.set_status(format!("{file_name} matches its schema"));
+        return Ok(());
+    }
+
+    let items: Vec<SchemaViolationItem> = violations
+        .into_iter()
+        .map(|violation| SchemaViolationItem {
+            message: violation.message,
+            pos: doc.text().slice(..).byte_to_char(violation.byte_range.start),
+        })
+        .collect();
+
+    let columns = vec![ui::PickerColumn::new("violation", |item: &SchemaViolationItem, _| {
+        item.message.as_str().into()
+    })];
+    let picker = ui::Picker::new(columns, 0, items, (), |cx, item, _action| {
+        let (view, doc) = current!(cx.editor);
+        let pos = item.pos.min(doc.text().len_chars());
+        doc.set_selection(view.id, Selection::point(pos));
+        align_view(doc, view, Align::Center);
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+fn write_quit(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    write_impl(cx, args.first(), false)?;
+    cx.block_try_flush_writes()?;
+    quit(cx, &[], event)
+}
+
+fn force_write_quit(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    write_impl(cx, args.first(), true)?;
+    cx.block_try_flush_writes()?;
+    force_quit(cx, &[], event)
+}
+
+/// Results in an error if there are modified buffers remaining and sets editor
+/// error, otherwise returns `Ok(())`. If the current document is unmodified,
+/// and there are modified documents, switches focus to one of them.
+pub(super) fn buffers_remaining_impl(editor: &mut Editor) -> anyhow::Result<()> {
+    let (modified_ids, modified_names): (Vec<_>, Vec<_>) = editor
+        .documents()
+        .filter(|doc| doc.is_modified())
+        .map(|doc| (doc.id(), doc.display_name()))
+        .unzip();
+    if let Some(first) = modified_ids.first() {
+        let current = doc!(editor);
+        // If the current document is unmodified, and there are modified
+        // documents, switch focus to the first modified doc.
+        if !modified_ids.contains(&current.id()) {
+            editor.switch(*first, Action::Replace);
+        }
+        bail!(
+            "{} unsaved buffer(s) remaining: {:?}",
+            modified_names.len(),
+            modified_names
+        );
+    }
+    Ok(())
+}
+
+pub fn write_all_impl(
+    cx: &mut compositor::Context,
+    force: bool,
+    write_scratch: bool,
+) -> anyhow::Result<()> {
+    let mut errors: Vec<&'static str> = Vec::new();
+    let config = cx.editor.config();
     let jobs = &mut cx.jobs;
     let current_view = view!(cx.editor);
 
@@ -717,7 +1650,9 @@ pub fn write_all_impl(
             insert_final_newline(doc, view_mut!(cx.editor, target_view));
         }
 
-        let fmt = if config.auto_format {
+        let fmt = if config.auto_format
+            && !config.auto_format_excluded(doc.path().map(PathBuf::as_path))
+        {
             doc.auto_format().map(|fmt| {
                 let callback = make_format_callback(
                     doc_id,
@@ -795,31 +1730,783 @@ fn force_write_all_quit(
 fn quit_all_impl(cx: &mut compositor::Context, force: bool) -> anyhow::Result<()> {
     cx.block_try_flush_writes()?;
     if !force {
-        buffers_remaining_impl(cx.editor)?;
+        let modified_ids: Vec<_> = cx
+            .editor
+            .documents()
+            .filter(|doc| doc.is_modified())
+            .map(|doc| doc.id())
+            .collect();
+        if !modified_ids.is_empty() {
+            push_buffer_close_review(cx, modified_ids, ui::PendingQuit::AllViews);
+            return Ok(());
+        }
+    }
+
+    // close all views
+    let views: Vec<_> = cx.editor.tree.views().map(|(view, _)| view.id).collect();
+    for view_id in views {
+        cx.editor.close(view_id);
+    }
+
+    Ok(())
+}
+
+fn quit_all(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    quit_all_impl(cx, false)
+}
+
+fn force_quit_all(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    quit_all_impl(cx, true)
+}
+
+fn cquit(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let exit_code = args
+        .first()
+        .and_then(|code| code.parse::<i32>().ok())
+        .unwrap_or(1);
+
+    cx.editor.exit_code = exit_code;
+    quit_all_impl(cx, false)
+}
+
+fn force_cquit(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let exit_code = args
+        .first()
+        .and_then(|code| code.parse::<i32>().ok())
+        .unwrap_or(1);
+    cx.editor.exit_code = exit_code;
+
+    quit_all_impl(cx, true)
+}
+
+fn theme(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    let true_color = cx.editor.config.load().true_color || crate::true_color();
+    match event {
+        PromptEvent::Abort => {
+            cx.editor.unset_theme_preview();
+        }
+        PromptEvent::Update => {
+            if args.is_empty() {
+                // Ensures that a preview theme gets cleaned up if the user backspaces until the prompt is empty.
+                cx.editor.unset_theme_preview();
+            } else if let Some(theme_name) = args.first() {
+                if let Ok(theme) = cx.editor.theme_loader.load(theme_name) {
+                    if !(true_color || theme.is_16_color()) {
+                        bail!("Unsupported theme: theme requires true color support");
+                    }
+                    cx.editor.set_theme_preview(theme);
+                };
+            };
+        }
+        PromptEvent::Validate => {
+            if let Some(theme_name) = args.first() {
+                let theme = cx
+                    .editor
+                    .theme_loader
+                    .load(theme_name)
+                    .map_err(|err| anyhow::anyhow!("Could not load theme: {}", err))?;
+                if !(true_color || theme.is_16_color()) {
+                    bail!("Unsupported theme: theme requires true color support");
+                }
+                cx.editor.set_theme(theme);
+            } else {
+                let name = cx.editor.theme.name().to_string();
+
+                cx.editor.set_status(name);
+            }
+        }
+    };
+
+    Ok(())
+}
+
+/// Opens the current theme's own source file for editing and marks it as
+/// the live-preview target: as it's edited, a `PostCommand` hook re-parses
+/// the buffer and calls [`Editor::set_theme_preview`] so changes are
+/// visible immediately, without round-tripping through `:theme`. Ended with
+/// `:theme-edit-stop`, which reverts the preview whether or not the file
+/// was saved (saving it and running `:theme <name>` applies it for good).
+fn theme_edit(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let name = cx.editor.theme.name().to_string();
+    let path = cx
+        .editor
+        .theme_loader
+        .find_theme_file(&name)
+        .ok_or_else(|| anyhow::anyhow!("Theme '{}' has no file on disk to edit", name))?;
+
+    let doc_id = cx.editor.open(&path, Action::Replace)?;
+    let revision = doc_mut!(cx.editor, &doc_id).get_current_revision();
+    cx.editor.theme_edit = Some((doc_id, revision));
+    Ok(())
+}
+
+/// Stops live-previewing the theme opened with `:theme-edit`, reverting to
+/// the theme that was active before, since the edited file was never
+/// (necessarily) saved.
+fn theme_edit_stop(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if cx.editor.theme_edit.take().is_some() {
+        cx.editor.unset_theme_preview();
+    }
+    Ok(())
+}
+
+/// Starts tailing the current buffer like `tail -f`: jumps to its last
+/// line and watches its file with the polling `helix_view::fs_watcher`;
+/// whenever an idle-timeout poll finds the file's modification time
+/// changed, it's reloaded from disk and the cursor jumps to the (possibly
+/// new) last line again, so appended lines show up without the user doing
+/// anything. Files with a `.log` extension also get their
+/// `ERROR`/`WARN`/`INFO`/`DEBUG` words highlighted (see
+/// `helix_core::syntax::heuristic`), matching the diagnostic severity
+/// colors. There's no OS-level change notification in this codebase, so
+/// changes show up on the next poll rather than instantly.
+///
+/// Scoped down from the original ask: there's no line-folding mechanism in
+/// the rendering layer that hides lines by an arbitrary predicate (folding
+/// here is tree-sitter/indent based), so filtering lines by regex into a
+/// folded view isn't implemented. `/` combined with `n`/`N` still works for
+/// jumping between matches while following.
+fn log_follow(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let scrolloff = cx.editor.config().scrolloff;
+    let (view, doc) = current!(cx.editor);
+    let pos = doc.text().len_chars();
+    doc.set_selection(view.id, Selection::point(pos));
+    view.ensure_cursor_in_view(doc, scrolloff);
+
+    cx.editor.log_follow = Some(doc.id());
+    if let Some(path) = doc.path() {
+        let config = cx.editor.config();
+        cx.editor.fs_watcher.watch(path.clone(), &config.file_watcher);
+    }
+    cx.editor.reset_idle_timer();
+    Ok(())
+}
+
+/// Stops tailing the buffer started with `:log-follow`.
+fn log_follow_stop(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if let Some(doc_id) = cx.editor.log_follow.take() {
+        if let Some(path) = cx.editor.document(doc_id).and_then(|doc| doc.path()) {
+            cx.editor.fs_watcher.unwatch(path);
+        }
+    }
+    Ok(())
+}
+
+/// Aligns delimiter-separated columns in the current buffer with virtual
+/// padding, without touching the file itself (see
+/// `helix_core::csv::column_annotations`). Defaults to tab for `.tsv` files
+/// and comma otherwise; an explicit single-character argument overrides
+/// that, e.g. `:csv-align ;`.
+///
+/// Scoped down from the original ask: there's no sticky/pinned-row
+/// rendering in the view layer, so a header row can't be kept on screen
+/// while scrolling, and there's no column-hiding mechanism (concealing a
+/// whole field isn't something `TextAnnotations` supports today). Column
+/// navigation is added separately as the `csv_next_column` /
+/// `csv_previous_column` commands.
+fn csv_align(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let delimiter = match args.first() {
+        Some(arg) => arg
+            .chars()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!(":csv-align delimiter must not be empty"))?,
+        None => {
+            let doc = doc!(cx.editor);
+            match doc.path().and_then(|path| path.extension()) {
+                Some(ext) if ext == "tsv" => '\t',
+                _ => ',',
+            }
+        }
+    };
+
+    doc_mut!(cx.editor).set_csv_delimiter(Some(delimiter));
+    Ok(())
+}
+
+/// Stops `:csv-align` column alignment for the current buffer.
+fn csv_align_stop(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).set_csv_delimiter(None);
+    Ok(())
+}
+
+/// Copies the breadcrumb path to the cursor (see the `structure-path`
+/// statusline element) into the system clipboard.
+fn structure_path_copy(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let pos = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+    let path = doc
+        .structure_path(pos)
+        .ok_or_else(|| anyhow::anyhow!("cursor isn't inside a keyed or indexed node"))?;
+
+    cx.editor.registers.write('*', vec![path])?;
+    cx.editor.set_status("yanked structure path to system clipboard");
+
+    Ok(())
+}
+
+/// Sets a mark named by the first character of `args[0]` at the cursor
+/// position. Lowercase names are buffer-local and kept on the `Document`;
+/// uppercase names are global, kept on the `Editor` and persisted to disk
+/// immediately so they survive a restart.
+fn mark_set(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let name = args
+        .first()
+        .and_then(|arg| arg.chars().next())
+        .ok_or_else(|| anyhow::anyhow!("Usage: `:mark-set <name>`, e.g. `:mark-set a`"))?;
+
+    let (view, doc) = current!(cx.editor);
+    let cursor = doc.selection(view.id).primary().cursor(doc.text().slice(..));
+    let text = doc.text().slice(..);
+    let line = text.char_to_line(cursor);
+    let column = cursor - text.line_to_char(line);
+
+    if helix_view::marks::is_global_mark(name) {
+        let path = doc
+            .path()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("cannot set a global mark in a buffer with no path"))?;
+        cx.editor.global_marks.set(name, path, line, column);
+        if let Err(err) = cx.editor.global_marks.save() {
+            cx.editor.set_error(format!("failed to save marks: {err}"));
+        }
+    } else {
+        doc.marks.insert(name, (line, column));
+    }
+
+    Ok(())
+}
+
+/// Jumps to the mark named by the first character of `args[0]`, opening its
+/// file first if it's a global mark in a different document.
+fn mark_goto(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let name = args
+        .first()
+        .and_then(|arg| arg.chars().next())
+        .ok_or_else(|| anyhow::anyhow!("Usage: `:mark-goto <name>`, e.g. `:mark-goto a`"))?;
+
+    let (line, column) = if helix_view::marks::is_global_mark(name) {
+        let mark = cx
+            .editor
+            .global_marks
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("no such mark: {name}"))?;
+        cx.editor.open(&mark.path, Action::Replace)?;
+        (mark.line, mark.column)
+    } else {
+        let doc = doc!(cx.editor);
+        *doc
+            .marks
+            .get(&name)
+            .ok_or_else(|| anyhow::anyhow!("no such mark: {name}"))?
+    };
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let line = line.min(text.len_lines().saturating_sub(1));
+    let pos = (text.line_to_char(line) + column).min(text.line_to_char(line + 1).saturating_sub(1));
+    doc.set_selection(view.id, Selection::point(pos));
+    align_view(doc, view, Align::Center);
+
+    Ok(())
+}
+
+/// One row shown in the picker opened by `:marks`.
+struct MarkItem {
+    name: char,
+    path: Option<PathBuf>,
+    line: usize,
+    column: usize,
+}
+
+/// Opens a picker listing every mark (buffer-local marks on the current
+/// document plus every global mark), jumping to it on selection.
+fn marks_picker(cx: &mut compositor::Context, _args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc = doc!(cx.editor);
+    let local_path = doc.path().cloned();
+    let mut items: Vec<MarkItem> = doc
+        .marks
+        .iter()
+        .map(|(&name, &(line, column))| MarkItem {
+            name,
+            path: local_path.clone(),
+            line,
+            column,
+        })
+        .collect();
+    items.extend(cx.editor.global_marks.iter().map(|(name, mark)| MarkItem {
+        name,
+        path: Some(mark.path.clone()),
+        line: mark.line,
+        column: mark.column,
+    }));
+    items.sort_unstable_by_key(|item| item.name);
+
+    if items.is_empty() {
+        cx.editor.set_status("no marks set");
+        return Ok(());
+    }
+
+    let columns = vec![
+        ui::PickerColumn::new("mark", |item: &MarkItem, _| item.name.to_string().into()),
+        ui::PickerColumn::new("location", |item: &MarkItem, _| match &item.path {
+            Some(path) => format!("{}:{}", path.display(), item.line + 1).into(),
+            None => "<no path>".into(),
+        }),
+    ];
+    let picker = ui::Picker::new(columns, 1, items, (), |cx, item, action| {
+        let Some(path) = &item.path else {
+            cx.editor.set_error("mark has no associated file");
+            return;
+        };
+        if let Err(err) = cx.editor.open(path, action) {
+            cx.editor
+                .set_error(format!("failed to open {}: {err}", path.display()));
+            return;
+        }
+        let (view, doc) = current!(cx.editor);
+        let text = doc.text().slice(..);
+        let line = item.line.min(text.len_lines().saturating_sub(1));
+        let pos = (text.line_to_char(line) + item.column).min(text.line_to_char(line + 1).saturating_sub(1));
+        doc.set_selection(view.id, Selection::point(pos));
+        align_view(doc, view, Align::Center);
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+/// Records a named checkpoint of the current buffer, so it can later be
+/// restored with `:restore-checkpoint` regardless of intervening edits. With
+/// no name, one is generated from the current time.
+fn checkpoint_create(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let name = match args.first() {
+        Some(name) => name.to_string(),
+        None => checkpoint_default_name(),
+    };
+
+    let doc = doc_mut!(cx.editor);
+    doc.create_checkpoint(name.clone());
+    cx.editor.set_status(format!("saved checkpoint '{name}'"));
+
+    Ok(())
+}
+
+/// `:checkpoint` names a checkpoint after the wall-clock time it was taken,
+/// to the second, so consecutive unnamed checkpoints don't collide.
+fn checkpoint_default_name() -> String {
+    let now = SystemTime::now();
+    let secs = now
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("checkpoint-{secs}")
+}
+
+fn checkpoint_restore(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let name = args
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Usage: `:restore-checkpoint <name>`"))?;
+
+    let (view, doc) = current!(cx.editor);
+    if !doc.restore_checkpoint(view, name) {
+        anyhow::bail!("no such checkpoint: {name}");
+    }
+
+    Ok(())
+}
+
+/// One row shown in the picker opened by `:checkpoints`.
+struct CheckpointItem {
+    name: String,
+    created_at: SystemTime,
+}
+
+/// Opens a picker listing every checkpoint recorded on the current buffer,
+/// most recent first. Selecting one restores it, the same as
+/// `:restore-checkpoint`.
+fn checkpoints_picker(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc = doc!(cx.editor);
+    let mut items: Vec<CheckpointItem> = doc
+        .checkpoints()
+        .iter()
+        .map(|checkpoint| CheckpointItem {
+            name: checkpoint.name.clone(),
+            created_at: checkpoint.created_at,
+        })
+        .collect();
+    items.reverse();
+
+    if items.is_empty() {
+        cx.editor.set_status("no checkpoints recorded for this buffer");
+        return Ok(());
+    }
+
+    let columns = vec![
+        ui::PickerColumn::new("name", |item: &CheckpointItem, _| item.name.clone().into()),
+        ui::PickerColumn::new("created", |item: &CheckpointItem, _| {
+            match item.created_at.elapsed() {
+                Ok(elapsed) => format!("{}s ago", elapsed.as_secs()).into(),
+                Err(_) => "just now".into(),
+            }
+        }),
+    ];
+    let picker = ui::Picker::new(columns, 0, items, (), |cx, item, _action| {
+        let (view, doc) = current!(cx.editor);
+        if !doc.restore_checkpoint(view, &item.name) {
+            cx.editor
+                .set_error(format!("checkpoint '{}' no longer exists", item.name));
+        }
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+/// Opens a picker listing every notification received this session (LSP
+/// progress, job completion, background errors, ...), most recent first.
+/// Selecting an entry has no associated action; this is a read-only history
+/// browser, not a jump-to-location picker like `:marks`.
+fn notifications_picker(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let items: Vec<_> = cx
+        .editor
+        .notifications
+        .history()
+        .rev()
+        .map(|n| (n.severity, n.message.clone()))
+        .collect();
+
+    if items.is_empty() {
+        cx.editor.set_status("no notifications yet");
+        return Ok(());
+    }
+
+    let columns = vec![
+        ui::PickerColumn::new("severity", |item: &(Severity, Cow<'static, str>), _| {
+            match item.0 {
+                Severity::Hint => "hint",
+                Severity::Info => "info",
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            }
+            .into()
+        }),
+        ui::PickerColumn::new("message", |item: &(Severity, Cow<'static, str>), _| {
+            item.1.clone().into()
+        }),
+    ];
+    let picker = ui::Picker::new(columns, 1, items, (), |_cx, _item, _action| {});
+    cx.push_layer(Box::new(overlaid(picker)));
+
+    Ok(())
+}
+
+/// Masks values matching a small set of bundled secret patterns (AWS access
+/// keys, `PASSWORD=...`-style assignments, bearer tokens, private key
+/// headers — see [`helix_core::redact::DEFAULT_PATTERNS`]) as virtual
+/// overlays, without touching the buffer.
+fn redact(cx: &mut compositor::Context, _args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).set_redact_enabled(true);
+    Ok(())
+}
+
+/// Stops `:redact` secret masking for the current buffer.
+fn redact_stop(cx: &mut compositor::Context, _args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).set_redact_enabled(false);
+    Ok(())
+}
+
+/// Interprets ANSI SGR color escapes (e.g. a captured build log) as colored
+/// virtual overlays, without touching the buffer. See
+/// [`helix_core::ansi::ansi_overlays`] for exactly which SGR parameters are
+/// understood.
+fn ansi_view(cx: &mut compositor::Context, _args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).set_ansi_view_enabled(true);
+    Ok(())
+}
+
+/// Stops `:ansi-view` ANSI color interpretation for the current buffer.
+fn ansi_view_stop(cx: &mut compositor::Context, _args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    doc_mut!(cx.editor).set_ansi_view_enabled(false);
+    Ok(())
+}
+
+/// Renames the current buffer's file on disk to `new_path`. Language
+/// servers that support `workspace/willRenameFiles` get a chance to return
+/// a [`lsp::WorkspaceEdit`] (e.g. import rewrites), but it's only applied
+/// once the rename has actually succeeded on disk -- otherwise buffers
+/// would end up rewritten to point at a file that was never moved. Servers
+/// that support `workspace/didRenameFiles` are notified once it has.
+fn move_file(cx: &mut compositor::Context, args: &[Cow<str>], event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let Some(new_path) = args.first() else {
+        bail!("new path required");
+    };
+
+    let doc = doc!(cx.editor);
+    let Some(old_path) = doc.path().cloned() else {
+        bail!("buffer has no path to rename");
+    };
+    let Some(old_uri) = doc.url() else {
+        bail!("buffer has no path to rename");
+    };
+    let new_path = helix_core::path::get_canonicalized_path(std::path::Path::new(new_path.as_ref()));
+    let Ok(new_uri) = helix_lsp::lsp::Url::from_file_path(&new_path) else {
+        bail!("new path is not representable as a URL");
+    };
+
+    let language_servers: Vec<_> = doc
+        .language_servers_with_feature(LanguageServerFeature::RenameFiles)
+        .map(|ls| (ls.id(), ls.offset_encoding()))
+        .collect();
+
+    let mut edits = Vec::new();
+    for (ls_id, offset_encoding) in &language_servers {
+        let Some(language_server) = cx.editor.language_server_by_id(*ls_id) else {
+            continue;
+        };
+        let Some(future) = language_server.will_rename_files(old_uri.clone(), new_uri.clone()) else {
+            continue;
+        };
+        let json = helix_lsp::block_on(future)?;
+        let edit: Option<helix_lsp::lsp::WorkspaceEdit> = serde_json::from_value(json)?;
+        if let Some(edit) = edit {
+            edits.push((*offset_encoding, edit));
+        }
+    }
+
+    if let Some(parent) = new_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::rename(&old_path, &new_path)?;
+
+    doc_mut!(cx.editor).set_path(Some(&new_path));
+
+    for (offset_encoding, edit) in &edits {
+        if let Err(err) = apply_workspace_edit(cx.editor, *offset_encoding, edit, "rename file") {
+            cx.editor.set_error(err.kind.to_string());
+        }
     }
 
-    // close all views
-    let views: Vec<_> = cx.editor.tree.views().map(|(view, _)| view.id).collect();
-    for view_id in views {
-        cx.editor.close(view_id);
+    for (ls_id, _) in &language_servers {
+        if let Some(language_server) = cx.editor.language_server_by_id(*ls_id) {
+            if let Some(future) = language_server.did_rename_files(old_uri.clone(), new_uri.clone()) {
+                tokio::spawn(future);
+            }
+        }
     }
 
+    cx.editor.set_status(format!(
+        "renamed {} to {}",
+        old_path.display(),
+        new_path.display()
+    ));
     Ok(())
 }
 
-fn quit_all(
+/// Deletes `args[0]`, or the current buffer's file if no argument is given.
+/// Honors `trash-delete` (see [`helix_view::editor::Config::trash_delete`]):
+/// moved into [`helix_loader::trash`] by default, removed permanently if
+/// that's disabled.
+fn remove_file(
     cx: &mut compositor::Context,
-    _args: &[Cow<str>],
+    args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    quit_all_impl(cx, false)
+    let path = match args.first() {
+        Some(arg) => {
+            helix_core::path::get_canonicalized_path(std::path::Path::new(arg.as_ref()))
+        }
+        None => doc!(cx.editor)
+            .path()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("buffer has no path to remove"))?,
+    };
+
+    if !path.exists() {
+        bail!("\"{}\" does not exist", path.display());
+    }
+
+    if cx.editor.config().trash_delete {
+        let trashed_to = helix_loader::trash::move_to_trash(&path)
+            .map_err(|err| anyhow::anyhow!("unable to delete \"{}\": {err}", path.display()))?;
+        cx.editor.set_status(format!(
+            "moved \"{}\" to \"{}\"",
+            path.display(),
+            trashed_to.display()
+        ));
+    } else {
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        result.map_err(|err| anyhow::anyhow!("unable to delete \"{}\": {err}", path.display()))?;
+        cx.editor.set_status(format!("deleted \"{}\"", path.display()));
+    }
+
+    Ok(())
 }
 
-fn force_quit_all(
+/// Toggles the [`ui::FileTree`] side panel rooted at the current working
+/// directory. See its docs for the exact key bindings and the panel's
+/// (deliberately not viewport-reserving) rendering model.
+fn explorer_toggle(
     cx: &mut compositor::Context,
     _args: &[Cow<str>],
     event: PromptEvent,
@@ -828,87 +2515,71 @@ fn force_quit_all(
         return Ok(());
     }
 
-    quit_all_impl(cx, true)
+    let root = helix_loader::current_working_dir();
+    let callback: crate::job::Callback = crate::job::Callback::EditorCompositor(Box::new(
+        move |_editor, compositor| {
+            if compositor.remove(ui::file_tree::ID).is_none() {
+                compositor.push(Box::new(ui::FileTree::new(root)));
+            }
+        },
+    ));
+    cx.jobs.callback(async move { Ok(callback) });
+    Ok(())
 }
 
-fn cquit(
+/// Toggles the [`ui::Terminal`] panel: opens a new shell at the current
+/// working directory if none is open, closes the existing one otherwise.
+fn terminal_toggle(
     cx: &mut compositor::Context,
-    args: &[Cow<str>],
+    _args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    let exit_code = args
-        .first()
-        .and_then(|code| code.parse::<i32>().ok())
-        .unwrap_or(1);
-
-    cx.editor.exit_code = exit_code;
-    quit_all_impl(cx, false)
+    let shell = cx.editor.config().shell.clone();
+    let cwd = helix_loader::current_working_dir();
+    let callback: crate::job::Callback = crate::job::Callback::EditorCompositor(Box::new(
+        move |editor, compositor| {
+            if compositor.remove(ui::terminal::ID).is_some() {
+                return;
+            }
+            match ui::Terminal::new(&shell, cwd) {
+                Ok(terminal) => compositor.push(Box::new(terminal)),
+                Err(err) => editor.set_error(format!("unable to start shell: {err}")),
+            }
+        },
+    ));
+    cx.jobs.callback(async move { Ok(callback) });
+    Ok(())
 }
 
-fn force_cquit(
+/// Sends the current primary selection, followed by a newline, to the
+/// shell running in the `:terminal-toggle` panel.
+fn terminal_send_selection(
     cx: &mut compositor::Context,
-    args: &[Cow<str>],
+    _args: &[Cow<str>],
     event: PromptEvent,
 ) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    let exit_code = args
-        .first()
-        .and_then(|code| code.parse::<i32>().ok())
-        .unwrap_or(1);
-    cx.editor.exit_code = exit_code;
-
-    quit_all_impl(cx, true)
-}
+    let (view, doc) = current_ref!(cx.editor);
+    let text = doc.text().slice(..);
+    let selection = doc.selection(view.id).primary().fragment(text).to_string();
 
-fn theme(
-    cx: &mut compositor::Context,
-    args: &[Cow<str>],
-    event: PromptEvent,
-) -> anyhow::Result<()> {
-    let true_color = cx.editor.config.load().true_color || crate::true_color();
-    match event {
-        PromptEvent::Abort => {
-            cx.editor.unset_theme_preview();
-        }
-        PromptEvent::Update => {
-            if args.is_empty() {
-                // Ensures that a preview theme gets cleaned up if the user backspaces until the prompt is empty.
-                cx.editor.unset_theme_preview();
-            } else if let Some(theme_name) = args.first() {
-                if let Ok(theme) = cx.editor.theme_loader.load(theme_name) {
-                    if !(true_color || theme.is_16_color()) {
-                        bail!("Unsupported theme: theme requires true color support");
-                    }
-                    cx.editor.set_theme_preview(theme);
-                };
+    let callback: crate::job::Callback = crate::job::Callback::EditorCompositor(Box::new(
+        move |editor, compositor| {
+            let Some(terminal) = compositor.find_id::<ui::Terminal>(ui::terminal::ID) else {
+                editor.set_error("no terminal panel open, see `:terminal-toggle`");
+                return;
             };
-        }
-        PromptEvent::Validate => {
-            if let Some(theme_name) = args.first() {
-                let theme = cx
-                    .editor
-                    .theme_loader
-                    .load(theme_name)
-                    .map_err(|err| anyhow::anyhow!("Could not load theme: {}", err))?;
-                if !(true_color || theme.is_16_color()) {
-                    bail!("Unsupported theme: theme requires true color support");
-                }
-                cx.editor.set_theme(theme);
-            } else {
-                let name = cx.editor.theme.name().to_string();
-
-                cx.editor.set_status(name);
-            }
-        }
-    };
-
+            terminal.send_line(&selection);
+        },
+    ));
+    cx.jobs.callback(async move { Ok(callback) });
     Ok(())
 }
 
@@ -1281,8 +2952,10 @@ fn reload(
         return Ok(());
     }
 
-    let scrolloff = cx.editor.config().scrolloff;
+    let config = cx.editor.config();
+    let scrolloff = config.scrolloff;
     let (view, doc) = current!(cx.editor);
+    let is_followed = cx.editor.log_follow == Some(doc.id());
     doc.reload(view, &cx.editor.diff_providers).map(|_| {
         view.ensure_cursor_in_view(doc, scrolloff);
     })?;
@@ -1291,6 +2964,9 @@ fn reload(
             .language_servers
             .file_event_handler
             .file_changed(path.clone());
+        if is_followed {
+            cx.editor.fs_watcher.watch(path.clone(), &config.file_watcher);
+        }
     }
     Ok(())
 }
@@ -1304,7 +2980,8 @@ fn reload_all(
         return Ok(());
     }
 
-    let scrolloff = cx.editor.config().scrolloff;
+    let config = cx.editor.config();
+    let scrolloff = config.scrolloff;
     let view_id = view!(cx.editor).id;
 
     let docs_view_ids: Vec<(DocumentId, Vec<ViewId>)> = cx
@@ -1331,12 +3008,16 @@ fn reload_all(
         // Ensure that the view is synced with the document's history.
         view.sync_changes(doc);
 
+        let is_followed = cx.editor.log_follow == Some(doc_id);
         doc.reload(view, &cx.editor.diff_providers)?;
         if let Some(path) = doc.path() {
             cx.editor
                 .language_servers
                 .file_event_handler
                 .file_changed(path.clone());
+            if is_followed {
+                cx.editor.fs_watcher.watch(path.clone(), &config.file_watcher);
+            }
         }
 
         for view_id in view_ids {
@@ -1515,6 +3196,219 @@ fn lsp_stop(
     Ok(())
 }
 
+/// Reload tree-sitter grammar shared libraries and query files from the
+/// runtime directory, and re-parse every open document. Lets grammar and
+/// query developers iterate without restarting the editor.
+fn tree_sitter_reload(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    cx.editor.config_events.0.send(ConfigEvent::ReloadGrammars)?;
+    Ok(())
+}
+
+/// Fetch-and-build grammars in-editor instead of via `hx --grammar build`.
+/// Builds run in parallel on a blocking thread pool so the UI stays
+/// responsive; `:grammar-build workspace` restricts the build to grammars
+/// for languages that are actually present in the current workspace,
+/// determined by walking the workspace and checking each file's language
+/// against the configured grammar for that language.
+///
+/// There's no persistent progress panel yet (the TUI has no gauge/progress
+/// widget to build one out of), so progress is reported as a single status
+/// message once the whole batch finishes; failures are opened in a picker
+/// so each one can be inspected individually.
+fn grammar_build(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let workspace_only = args.first().map_or(false, |arg| arg == "workspace");
+    let syn_loader = cx.editor.syn_loader.clone();
+
+    cx.editor.set_status("Building grammars…");
+
+    let callback = async move {
+        let summary = tokio::task::spawn_blocking(move || {
+            let only = if workspace_only {
+                let root = find_workspace().0;
+                let mut grammars = HashSet::new();
+                let walker = WalkBuilder::new(&root).build();
+                for entry in walker {
+                    let Ok(entry) = entry else { continue };
+                    if !entry.file_type().map_or(false, |ft| ft.is_file()) {
+                        continue;
+                    }
+                    if let Some(config) = syn_loader.language_config_for_file_name(entry.path()) {
+                        grammars.insert(config.grammar.clone().unwrap_or_else(|| {
+                            config.language_id.clone()
+                        }));
+                    }
+                }
+                Some(grammars)
+            } else {
+                None
+            };
+
+            helix_loader::grammar::build_grammars_with_progress(None, only.as_ref(), |_event| {})
+        })
+        .await??;
+
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                if summary.failed.is_empty() {
+                    editor.set_status(format!(
+                        "grammar-build: {} built, {} already built",
+                        summary.built.len(),
+                        summary.already_built
+                    ));
+                    return;
+                }
+
+                editor.set_error(format!(
+                    "grammar-build: {} built, {} already built, {} failed",
+                    summary.built.len(),
+                    summary.already_built,
+                    summary.failed.len()
+                ));
+
+                let columns = vec![
+                    ui::PickerColumn::new("grammar", |item: &(String, String), _| {
+                        item.0.as_str().into()
+                    }),
+                    ui::PickerColumn::new("error", |item: &(String, String), _| {
+                        item.1.as_str().into()
+                    }),
+                ];
+                let picker = ui::Picker::new(columns, 0, summary.failed, (), |_cx, _item, _action| {});
+                compositor.push(Box::new(overlaid(picker)));
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
+/// Shows the fully-resolved `LanguageConfiguration` for the current
+/// document: the language server(s) it uses (with their configured
+/// command), formatter, indent settings, comment tokens and workspace
+/// roots. Languages are merged from the default and user `languages.toml`
+/// before a document ever sees them, so this can't say which file a given
+/// value came from — only whether a user `languages.toml` exists at all,
+/// which is noted at the top of the output as a hint for where to look.
+fn language_info(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc = doc!(cx.editor);
+    let Some(config) = doc.language_config() else {
+        cx.editor.set_status("No language configured for this document");
+        return Ok(());
+    };
+
+    let user_languages_toml = helix_loader::config_dir().join("languages.toml");
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}", config.language_id);
+    let _ = writeln!(
+        out,
+        "\n_Merged from the default `languages.toml` and, if present, `{}`._",
+        user_languages_toml.display()
+    );
+    let _ = writeln!(out, "\n- scope: `{}`", config.scope);
+    let _ = writeln!(
+        out,
+        "- grammar: `{}`",
+        config.grammar.as_deref().unwrap_or(&config.language_id)
+    );
+    let _ = writeln!(out, "- roots: `{:?}`", config.roots);
+    let _ = writeln!(
+        out,
+        "- comment tokens: `{:?}`",
+        config.comment_tokens.clone().unwrap_or_default()
+    );
+
+    if let Some(indent) = &config.indent {
+        let _ = writeln!(
+            out,
+            "- indent: `{}` width `{}`",
+            indent.unit, indent.tab_width
+        );
+    } else {
+        let _ = writeln!(out, "- indent: _not configured_");
+    }
+
+    match &config.formatter {
+        Some(formatter) => {
+            let _ = writeln!(
+                out,
+                "- formatter: `{} {}`",
+                formatter.command,
+                formatter.args.join(" ")
+            );
+        }
+        None => {
+            let _ = writeln!(out, "- formatter: _not configured_");
+        }
+    }
+
+    if config.language_servers.is_empty() {
+        let _ = writeln!(out, "- language servers: _none configured_");
+    } else {
+        let _ = writeln!(out, "- language servers:");
+        for features in &config.language_servers {
+            match cx.editor.syn_loader.language_server_configs().get(&features.name) {
+                Some(server) => {
+                    let _ = writeln!(
+                        out,
+                        "  - `{}`: `{} {}`",
+                        features.name,
+                        server.command,
+                        server.args.join(" ")
+                    );
+                }
+                None => {
+                    let _ = writeln!(
+                        out,
+                        "  - `{}`: _no `[language-server.{}]` entry found_",
+                        features.name, features.name
+                    );
+                }
+            }
+        }
+    }
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |editor: &mut Editor, compositor: &mut Compositor| {
+                let contents = ui::Markdown::new(out, editor.syn_loader.clone());
+                let popup = Popup::new("language-info", contents).auto_close(true);
+                compositor.replace_or_push("language-info", popup);
+            },
+        ));
+        Ok(call)
+    };
+
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
 fn tree_sitter_scopes(
     cx: &mut compositor::Context,
     _args: &[Cow<str>],
@@ -1776,6 +3670,80 @@ fn tutor(
     Ok(())
 }
 
+/// Checks the tutor exercise above the cursor. Tutor exercises mark their
+/// prompt with a `-->` prefixed line immediately followed by a line showing
+/// the expected result; this compares the two so learners get feedback
+/// without having to eyeball the diff themselves.
+fn tutor_check(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text();
+    let cursor_line = doc
+        .selection(view.id)
+        .primary()
+        .cursor_line(text.slice(..));
+
+    let Some(prompt_line) = (0..=cursor_line)
+        .rev()
+        .find(|&line| text.line(line).to_string().trim_start().starts_with("-->"))
+    else {
+        cx.editor
+            .set_error("No exercise found above the cursor. Move to a line under a '-->' prompt.");
+        return Ok(());
+    };
+
+    let Some(expected_line) = text.get_line(prompt_line + 1) else {
+        cx.editor
+            .set_error("This exercise has no expected result to check against.");
+        return Ok(());
+    };
+
+    let actual = text.line(prompt_line).to_string();
+    let actual = actual.trim_start().trim_start_matches("-->").trim();
+    let expected = expected_line.to_string();
+    let expected = expected.trim();
+
+    if actual == expected {
+        cx.editor
+            .set_status("Correct! Move on to the next lesson.");
+    } else {
+        cx.editor.set_error(
+            "Not quite there yet — compare your line to the one below the prompt and try again.",
+        );
+    }
+
+    Ok(())
+}
+
+/// Closes an open command-line window (`ui::Prompt::open_command_line_window`)
+/// without running its content. The prompt it was opened from is left
+/// untouched, still showing whatever was last typed into it.
+fn cmdline_window_cancel(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let Some(doc_id) = cx.editor.command_line_window.take() else {
+        cx.editor.set_error("No command-line window is open");
+        return Ok(());
+    };
+    if let Err(err) = cx.editor.close_document(doc_id, true) {
+        cx.editor.set_error(err.to_string());
+    }
+    Ok(())
+}
+
 fn abort_goto_line_number_preview(cx: &mut compositor::Context) {
     if let Some(last_selection) = cx.editor.last_selection.take() {
         let scrolloff = cx.editor.config().scrolloff;
@@ -1981,7 +3949,9 @@ fn toggle_option(
     Ok(())
 }
 
-/// Change the language of the current buffer at runtime.
+/// Change the language of the current buffer at runtime. Persists the
+/// choice to `Editor::language_overrides` so it's restored next time this
+/// file is opened.
 fn language(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -2009,7 +3979,22 @@ fn language(
     } else {
         doc.set_language_by_language_id(&args[0], cx.editor.syn_loader.clone())?;
     }
-    doc.detect_indent_and_line_ending();
+    doc.detect_indent_and_line_ending();
+
+    // Persist the override so reopening this file keeps the chosen
+    // language, see `helix_view::language_overrides`.
+    if let Some(path) = doc.path().cloned() {
+        if args[0] == DEFAULT_LANGUAGE_NAME {
+            cx.editor.language_overrides.remove(&path);
+        } else {
+            cx.editor
+                .language_overrides
+                .set(path, args[0].to_string());
+        }
+        if let Err(err) = cx.editor.language_overrides.save() {
+            log::warn!("failed to persist language override: {}", err);
+        }
+    }
 
     let id = doc.id();
     cx.editor.refresh_language_servers(id);
@@ -2156,6 +4141,45 @@ fn tree_sitter_subtree(
     Ok(())
 }
 
+fn export_impl(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+    render: fn(&Document, &helix_view::Theme) -> String,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let path = args
+        .first()
+        .ok_or_else(|| anyhow!("expected a destination path"))?;
+    let theme = cx.editor.theme.clone();
+    let doc = doc!(cx.editor);
+    let contents = render(doc, &theme);
+    std::fs::write(path.as_ref(), contents)
+        .map_err(|err| anyhow!("failed to write '{path}': {err}"))?;
+
+    cx.editor.set_status(format!("Exported to '{path}'"));
+    Ok(())
+}
+
+fn export_html(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    export_impl(cx, args, event, crate::commands::export::document_to_html)
+}
+
+fn export_ansi(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    export_impl(cx, args, event, crate::commands::export::document_to_ansi)
+}
+
 fn open_config(
     cx: &mut compositor::Context,
     _args: &[Cow<str>],
@@ -2197,6 +4221,43 @@ fn open_log(
     Ok(())
 }
 
+fn set_workspace_trust(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+    trusted: bool,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let workspace = helix_loader::find_workspace().0;
+    helix_loader::trust::set_trust_decision(&workspace, trusted)?;
+    cx.editor.config_events.0.send(ConfigEvent::Refresh)?;
+    cx.editor.set_status(format!(
+        "workspace {} ({})",
+        if trusted { "trusted" } else { "untrusted" },
+        workspace.display()
+    ));
+    Ok(())
+}
+
+fn trust(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    set_workspace_trust(cx, args, event, true)
+}
+
+fn distrust(
+    cx: &mut compositor::Context,
+    args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    set_workspace_trust(cx, args, event, false)
+}
+
 fn refresh_config(
     cx: &mut compositor::Context,
     _args: &[Cow<str>],
@@ -2210,6 +4271,41 @@ fn refresh_config(
     Ok(())
 }
 
+/// Reveal the current buffer's containing directory in the platform's file
+/// manager (Finder, Explorer, or an XDG-compliant file manager on Linux).
+fn open_file_manager(
+    cx: &mut compositor::Context,
+    _args: &[Cow<str>],
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let doc = doc!(cx.editor);
+    let path = doc
+        .path()
+        .and_then(|path| path.parent())
+        .map(|path| path.to_path_buf())
+        .unwrap_or_else(helix_loader::current_working_dir);
+
+    let (cmd, args): (&str, &[&str]) = if cfg!(target_os = "macos") {
+        ("open", &[])
+    } else if cfg!(target_os = "windows") {
+        ("explorer", &[])
+    } else {
+        ("xdg-open", &[])
+    };
+
+    std::process::Command::new(cmd)
+        .args(args)
+        .arg(&path)
+        .spawn()
+        .map_err(|err| anyhow!("failed to open file manager: {err}"))?;
+
+    Ok(())
+}
+
 fn append_output(
     cx: &mut compositor::Context,
     args: &[Cow<str>],
@@ -2491,6 +4587,55 @@ fn redraw(
         fun: buffer_previous,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "buffer-pin",
+        aliases: &[],
+        doc: "Pin the current buffer to the left of the bufferline.",
+        fun: buffer_pin,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "buffer-unpin",
+        aliases: &[],
+        doc: "Unpin the current buffer from the bufferline.",
+        fun: buffer_unpin,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "toggle-inline-diagnostics",
+        aliases: &[],
+        doc: "Toggle rendering of multi-line virtual diagnostic blocks (editor.lsp.inline-diagnostics) for the current buffer only.",
+        fun: toggle_inline_diagnostics,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "code-lens-execute",
+        aliases: &[],
+        doc: "Run the code lens command on the current line (e.g. 'Run test', 'N references').",
+        fun: code_lens_execute,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "document-color-presentation",
+        aliases: &[],
+        doc: "Pick an alternate textual representation (hex, rgb(), hsl(), ...) for the color literal under the cursor.",
+        fun: document_color_presentation,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "buffer-move-left",
+        aliases: &[],
+        doc: "Move the current buffer one slot left in the bufferline.",
+        fun: buffer_move_left,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "buffer-move-right",
+        aliases: &[],
+        doc: "Move the current buffer one slot right in the bufferline.",
+        fun: buffer_move_right,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "write",
         aliases: &["w"],
@@ -2536,7 +4681,7 @@ fn redraw(
     TypableCommand {
         name: "indent-style",
         aliases: &[],
-        doc: "Set the indentation style for editing. ('t' for tabs or 1-8 for number of spaces.)",
+        doc: "Set the indentation style for editing. ('t' for tabs, 1-8 for number of spaces, or 'detect' to re-run heuristic detection on the buffer contents.)",
         fun: set_indent_style,
         signature: CommandSignature::none(),
     },
@@ -2564,6 +4709,48 @@ fn redraw(
         fun: later,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "undo-workspace",
+        aliases: &["uw"],
+        doc: "Undo the most recent multi-file edit (e.g. an LSP rename or code action) as a single step, reopening any of its files that have since been closed.",
+        fun: undo_workspace,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "note-add",
+        aliases: &[],
+        doc: "Attach a review note to the current line. Notes are only shown if a `notes` gutter is configured.",
+        fun: note_add,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "note-remove",
+        aliases: &["note-rm"],
+        doc: "Remove the review note on the current line.",
+        fun: note_remove,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "note-list",
+        aliases: &["note-ls"],
+        doc: "Open a picker listing every review note in the workspace.",
+        fun: note_list,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "review",
+        aliases: &[],
+        doc: "List files changed against a base ref (HEAD if omitted) in a picker, e.g. `:review main`.",
+        fun: review,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "review-export",
+        aliases: &[],
+        doc: "Write every review note in the workspace out as a markdown report. Accepts an optional destination path.",
+        fun: review_export,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
     TypableCommand {
         name: "write-quit",
         aliases: &["wq", "x"],
@@ -2641,6 +4828,174 @@ fn redraw(
         fun: theme,
         signature: CommandSignature::positional(&[completers::theme]),
     },
+    TypableCommand {
+        name: "theme-edit",
+        aliases: &[],
+        doc: "Open the current theme's file for editing and live-preview changes as they're made.",
+        fun: theme_edit,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "theme-edit-stop",
+        aliases: &[],
+        doc: "Stop live-previewing a theme opened with `:theme-edit`, reverting the preview.",
+        fun: theme_edit_stop,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "log-follow",
+        aliases: &[],
+        doc: "Follow the current buffer like `tail -f`, reloading it on idle and jumping to its end; `.log` files also get severity words highlighted.",
+        fun: log_follow,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "log-follow-stop",
+        aliases: &[],
+        doc: "Stop following a buffer started with `:log-follow`.",
+        fun: log_follow_stop,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "csv-align",
+        aliases: &[],
+        doc: "Align delimiter-separated columns virtually (tab for .tsv, comma otherwise, or pass a delimiter).",
+        fun: csv_align,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "csv-align-stop",
+        aliases: &[],
+        doc: "Stop `:csv-align` column alignment for the current buffer.",
+        fun: csv_align_stop,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "redact",
+        aliases: &[],
+        doc: "Mask values matching common secret patterns (AWS keys, PASSWORD=..., bearer tokens, private key headers) as virtual overlays.",
+        fun: redact,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "redact-stop",
+        aliases: &[],
+        doc: "Stop `:redact` secret masking for the current buffer.",
+        fun: redact_stop,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "move",
+        aliases: &["rename-file"],
+        doc: "Rename the current file on disk, then apply any edits language servers returned via `workspace/willRenameFiles` and notify them via `workspace/didRenameFiles`.",
+        fun: move_file,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
+    TypableCommand {
+        name: "remove",
+        aliases: &["delete-file", "rm"],
+        doc: "Delete the given file, or the current buffer's file if no path is given. Moved to the trash unless `trash-delete` is disabled.",
+        fun: remove_file,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
+    TypableCommand {
+        name: "ansi-view",
+        aliases: &[],
+        doc: "Interpret ANSI SGR color escapes (e.g. a captured build log) as colored virtual overlays.",
+        fun: ansi_view,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "ansi-view-stop",
+        aliases: &[],
+        doc: "Stop `:ansi-view` ANSI color interpretation for the current buffer.",
+        fun: ansi_view_stop,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "explorer-toggle",
+        aliases: &[],
+        doc: "Toggle a file tree panel over the current working directory, with expand/collapse, open (in place or in a split), create/rename/delete, and VCS status markers.",
+        fun: explorer_toggle,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "terminal-toggle",
+        aliases: &[],
+        doc: "Toggle a terminal panel running a shell at the current working directory. `Ctrl-t` blurs/focuses it, `Ctrl-q` closes it.",
+        fun: terminal_toggle,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "terminal-send-selection",
+        aliases: &[],
+        doc: "Send the current primary selection to the shell running in the `:terminal-toggle` panel.",
+        fun: terminal_send_selection,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "schema-check",
+        aliases: &[],
+        doc: "Validate the current buffer against a bundled schema for its file name (currently package.json, tsconfig.json), opening a picker of violations.",
+        fun: schema_check,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "structure-path-copy",
+        aliases: &[],
+        doc: "Copy the breadcrumb path to the cursor (JSON `spec.containers[0].image`-style) into the system clipboard.",
+        fun: structure_path_copy,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "mark-set",
+        aliases: &[],
+        doc: "Set a named mark at the cursor. Lowercase names are buffer-local; uppercase names are global and persist across sessions.",
+        fun: mark_set,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "mark-goto",
+        aliases: &[],
+        doc: "Jump to a named mark, opening its file first if needed.",
+        fun: mark_goto,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "marks",
+        aliases: &[],
+        doc: "Open a picker listing every mark on the current buffer and every global mark.",
+        fun: marks_picker,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "checkpoint",
+        aliases: &[],
+        doc: "Record a named undo checkpoint for the current buffer, e.g. `:checkpoint before-refactor`. Defaults to a timestamp if no name is given.",
+        fun: checkpoint_create,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "restore-checkpoint",
+        aliases: &[],
+        doc: "Restore the current buffer to a checkpoint recorded with `:checkpoint`.",
+        fun: checkpoint_restore,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "checkpoints",
+        aliases: &[],
+        doc: "Open a picker listing every checkpoint recorded on the current buffer.",
+        fun: checkpoints_picker,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "notifications",
+        aliases: &[],
+        doc: "Open a picker listing every notification received this session, most recent first.",
+        fun: notifications_picker,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "yank-join",
         aliases: &[],
@@ -2795,6 +5150,13 @@ fn redraw(
         fun: lsp_stop,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "language-info",
+        aliases: &[],
+        doc: "Show the resolved language configuration (language servers, formatter, indent, comment tokens, roots) for the current document.",
+        fun: language_info,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "tree-sitter-scopes",
         aliases: &[],
@@ -2865,6 +5227,20 @@ fn redraw(
         fun: tutor,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "tutor-check",
+        aliases: &["tc"],
+        doc: "Check the tutor exercise above the cursor against its expected result.",
+        fun: tutor_check,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "cmdwin-cancel",
+        aliases: &["cwc"],
+        doc: "Close an open command-line window without running its content.",
+        fun: cmdline_window_cancel,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "goto",
         aliases: &["g"],
@@ -2875,7 +5251,7 @@ fn redraw(
     TypableCommand {
         name: "set-language",
         aliases: &["lang"],
-        doc: "Set the language of current buffer (show current language if no value specified).",
+        doc: "Set the language of current buffer (show current language if no value specified). Persisted across sessions for this file; also honors a `helix: language=<id>` modeline comment on open.",
         fun: language,
         signature: CommandSignature::positional(&[completers::language]),
     },
@@ -2929,6 +5305,55 @@ fn redraw(
         fun: tree_sitter_subtree,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "structural-search-replace",
+        aliases: &["ssr"],
+        doc: "Preview and apply a tree-sitter query search and capture-based replacement template in the current buffer, e.g. `:ssr '(call_expression) @call' '${call}'`.",
+        fun: structural_search_replace,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "global-replace",
+        aliases: &["gr"],
+        doc: "Preview a regex search-and-replace across every file in the workspace in a picker, e.g. `:global-replace foo bar`. `Alt-r` replaces the selected match, `Alt-a` replaces every match shown.",
+        fun: global_replace,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "export-html",
+        aliases: &[],
+        doc: "Export the current buffer as a syntax-highlighted, standalone HTML file.",
+        fun: export_html,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
+    TypableCommand {
+        name: "export-ansi",
+        aliases: &[],
+        doc: "Export the current buffer as text with embedded ANSI color escapes.",
+        fun: export_ansi,
+        signature: CommandSignature::positional(&[completers::filename]),
+    },
+    TypableCommand {
+        name: "open-file-manager",
+        aliases: &["reveal"],
+        doc: "Reveal the current buffer's directory in the system file manager.",
+        fun: open_file_manager,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "trust",
+        aliases: &[],
+        doc: "Trust the current workspace, allowing its `.helix/config.toml` to be loaded.",
+        fun: trust,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "distrust",
+        aliases: &[],
+        doc: "Revoke trust for the current workspace's local config.",
+        fun: distrust,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "config-reload",
         aliases: &[],
@@ -2936,6 +5361,20 @@ fn redraw(
         fun: refresh_config,
         signature: CommandSignature::none(),
     },
+    TypableCommand {
+        name: "tree-sitter-reload",
+        aliases: &[],
+        doc: "Reload tree-sitter grammars and queries from the runtime directory and re-parse open documents.",
+        fun: tree_sitter_reload,
+        signature: CommandSignature::none(),
+    },
+    TypableCommand {
+        name: "grammar-build",
+        aliases: &[],
+        doc: "Fetch and build tree-sitter grammars in parallel. Accepts an optional `workspace` argument to build only the grammars used by the current workspace.",
+        fun: grammar_build,
+        signature: CommandSignature::none(),
+    },
     TypableCommand {
         name: "config-open",
         aliases: &[],
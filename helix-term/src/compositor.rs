@@ -73,6 +73,12 @@ fn type_name(&self) -> &'static str {
     fn id(&self) -> Option<&'static str> {
         None
     }
+
+    /// Called when the component is being reactivated after previously
+    /// being closed and stashed away, e.g. `last_picker` reopening a picker
+    /// it had stored. Components that own a background data source that
+    /// stopped when they were closed can use this to restart it.
+    fn on_reopen(&mut self, _editor: &mut Editor) {}
 }
 
 pub struct Compositor {
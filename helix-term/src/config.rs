@@ -120,8 +120,15 @@ pub fn load(
     pub fn load_default() -> Result<Config, ConfigLoadError> {
         let global_config =
             fs::read_to_string(helix_loader::config_file()).map_err(ConfigLoadError::Error);
-        let local_config = fs::read_to_string(helix_loader::workspace_config_file())
-            .map_err(ConfigLoadError::Error);
+        let local_config = if helix_loader::trust::is_trusted(&helix_loader::find_workspace().0) {
+            fs::read_to_string(helix_loader::workspace_config_file())
+                .map_err(ConfigLoadError::Error)
+        } else {
+            Err(ConfigLoadError::Error(IOError::new(
+                std::io::ErrorKind::PermissionDenied,
+                "workspace is not trusted; run `:trust` to allow its local config",
+            )))
+        };
         Config::load(global_config, local_config)
     }
 }
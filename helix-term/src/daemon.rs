@@ -0,0 +1,221 @@
+//! Headless daemon mode: the editor core runs with no attached terminal of
+//! its own, and one or more lightweight clients attach/detach over a Unix
+//! domain socket, preserving editor state (documents, LSP sessions) across
+//! client restarts.
+//!
+//! The daemon reuses the normal [`crate::application::Application`] render
+//! pipeline unchanged: [`Application::new_headless`] gives it a
+//! [`CrosstermBackend`](tui::backend::CrosstermBackend) that writes into an
+//! [`AttachSink`] instead of a real stdout. Whatever bytes `Application`
+//! would have written to a terminal (cursor moves, styled spans, escape
+//! codes) are forwarded byte-for-byte to whichever client is currently
+//! attached; the client writes them straight to its own stdout. Input flows
+//! the other way as serialized [`crossterm::event::Event`]s, since those
+//! need to be reconstructed on the daemon side rather than just replayed.
+//!
+//! The daemon never calls [`Application::run`], since that claims the
+//! terminal (`enable_raw_mode`, alternate screen) which makes no sense for a
+//! process with no controlling terminal. It drives
+//! [`Application::event_loop`] directly instead; all real terminal mode
+//! management happens client-side, in [`run_attach`].
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::application::Application;
+use crate::args::Args;
+use crate::config::Config;
+use helix_core::syntax;
+
+/// Default socket path when `--headless`/`--attach` don't specify one.
+pub fn default_socket_path() -> PathBuf {
+    helix_loader::cache_dir().join("daemon.sock")
+}
+
+/// A terminal write sink that forwards every write to whichever client is
+/// currently attached, and silently drops writes when no client is
+/// attached. Plugged in as the daemon `Application`'s backend writer.
+#[derive(Clone, Default)]
+struct AttachSink {
+    client: Arc<Mutex<Option<UnboundedSender<Vec<u8>>>>>,
+}
+
+impl AttachSink {
+    fn attach(&self, sender: UnboundedSender<Vec<u8>>) {
+        *self.client.lock().unwrap() = Some(sender);
+    }
+
+    fn detach(&self) {
+        *self.client.lock().unwrap() = None;
+    }
+}
+
+impl Write for AttachSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some(sender) = self.client.lock().unwrap().as_ref() {
+            // Ignore the send error: it just means the client's write task
+            // has already exited (e.g. it disconnected); `detach` will run
+            // separately once that's noticed.
+            let _ = sender.send(buf.to_vec());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the editor core as a daemon listening on `args.socket_path` (or
+/// [`default_socket_path`]), accepting one attached client at a time.
+pub async fn run_daemon(
+    args: Args,
+    config: Config,
+    syn_loader_conf: syntax::Configuration,
+) -> Result<i32> {
+    let socket_path = args
+        .socket_path
+        .clone()
+        .unwrap_or_else(default_socket_path);
+    if let Some(parent) = socket_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if socket_path.exists() {
+        std::fs::remove_file(&socket_path)
+            .with_context(|| format!("failed to remove stale socket {:?}", socket_path))?;
+    }
+
+    let listener = UnixListener::bind(&socket_path)
+        .with_context(|| format!("failed to bind daemon socket at {:?}", socket_path))?;
+
+    let sink = AttachSink::default();
+    let writer: Box<dyn Write + Send> = Box::new(sink.clone());
+    let mut app = Application::new_headless(args, config, syn_loader_conf, writer)?;
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let (mut read_half, mut write_half) = stream.into_split();
+
+        let (output_tx, output_rx) = unbounded_channel::<Vec<u8>>();
+        sink.attach(output_tx);
+        app.request_full_redraw();
+
+        let writer_task = tokio::spawn(async move {
+            let mut output_rx = UnboundedReceiverStream::new(output_rx);
+            while let Some(bytes) = output_rx.next().await {
+                if write_half.write_all(&bytes).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let (input_tx, input_rx) = unbounded_channel::<std::io::Result<crossterm::event::Event>>();
+        let reader_task = tokio::spawn(async move {
+            let mut len_buf = [0u8; 4];
+            loop {
+                if read_half.read_exact(&mut len_buf).await.is_err() {
+                    break;
+                }
+                let len = u32::from_be_bytes(len_buf) as usize;
+                let mut body = vec![0u8; len];
+                if read_half.read_exact(&mut body).await.is_err() {
+                    break;
+                }
+                match serde_json::from_slice::<crossterm::event::Event>(&body) {
+                    Ok(event) => {
+                        if input_tx.send(Ok(event)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        log::warn!("failed to decode client input: {}", err);
+                        break;
+                    }
+                }
+            }
+        });
+
+        let mut input_stream = UnboundedReceiverStream::new(input_rx);
+        app.event_loop(&mut input_stream).await;
+
+        sink.detach();
+        writer_task.abort();
+        reader_task.abort();
+
+        if app.editor.should_close() {
+            break;
+        }
+    }
+
+    let close_errs = app.close().await;
+    for err in close_errs {
+        app.editor.exit_code = 1;
+        eprintln!("Error: {}", err);
+    }
+
+    let _ = std::fs::remove_file(&socket_path);
+    Ok(app.editor.exit_code)
+}
+
+/// Connects to a daemon listening at `socket_path`, claims the local
+/// terminal, and proxies input/output between it and the daemon until
+/// sending an input event to the daemon fails (the connection has closed,
+/// noticed on the next event) or reading local terminal events fails.
+/// Every key, `Ctrl-c` included, is forwarded to the daemon.
+pub async fn run_attach(socket_path: &Path) -> Result<i32> {
+    let stream = UnixStream::connect(socket_path)
+        .await
+        .with_context(|| format!("failed to connect to daemon at {:?}", socket_path))?;
+    let (mut read_half, mut write_half) = stream.into_split();
+
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen
+    )?;
+
+    let result = async {
+        let output_task = tokio::spawn(async move {
+            let mut stdout = std::io::stdout();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = match read_half.read(&mut buf).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => n,
+                };
+                if stdout.write_all(&buf[..n]).is_err() || stdout.flush().is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut events = crossterm::event::EventStream::new();
+        while let Some(event) = events.next().await {
+            let event = event?;
+            let bytes = serde_json::to_vec(&event)?;
+            write_half.write_all(&(bytes.len() as u32).to_be_bytes()).await?;
+            write_half.write_all(&bytes).await?;
+        }
+
+        output_task.abort();
+        Ok::<_, anyhow::Error>(())
+    }
+    .await;
+
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::LeaveAlternateScreen
+    )?;
+    crossterm::terminal::disable_raw_mode()?;
+
+    result?;
+    Ok(0)
+}
@@ -21,19 +21,26 @@ fn rope_ends_with(text: &str, rope: RopeSlice<'_>) -> bool {
     rope.byte_slice(len - text.len()..) == text
 }
 
+mod ansi_view;
 mod completion;
+mod csv_align;
+mod line_length_diagnostic;
+mod redact;
 mod signature_help;
+mod theme_edit;
 
 pub fn setup(config: Arc<ArcSwap<Config>>) -> Handlers {
     events::register();
 
     let completions = CompletionHandler::new(config).spawn();
     let signature_hints = SignatureHelpHandler::new().spawn();
-    let handlers = Handlers {
-        completions,
-        signature_hints,
-    };
+    let handlers = Handlers::new(completions, signature_hints);
     completion::register_hooks(&handlers);
     signature_help::register_hooks(&handlers);
+    theme_edit::register_hooks();
+    csv_align::register_hooks();
+    line_length_diagnostic::register_hooks();
+    redact::register_hooks();
+    ansi_view::register_hooks();
     handlers
 }
@@ -0,0 +1,13 @@
+use helix_event::register_hook;
+use helix_view::events::DocumentDidChange;
+
+/// Keeps `:ansi-view` overlays in sync with edits, the same way
+/// `handlers::redact` keeps `:redact` overlays in sync.
+pub(super) fn register_hooks() {
+    register_hook!(move |event: &mut DocumentDidChange<'_>| {
+        if event.doc.ansi_view_enabled {
+            event.doc.refresh_ansi_view();
+        }
+        Ok(())
+    });
+}
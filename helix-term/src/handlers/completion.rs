@@ -285,6 +285,37 @@ fn show_completion(
     }
 }
 
+/// Best-effort check for whether `pos` sits inside a comment or string, so
+/// that word-based auto-triggering doesn't pop up completions while prose
+/// is being typed. Uses the outermost syntax layer's tree-sitter node
+/// kinds, which name comment and string nodes consistently enough across
+/// grammars (e.g. `line_comment`, `string_literal`) for this heuristic to
+/// be useful without per-language query configuration. Explicit trigger
+/// characters advertised by the language server bypass this check.
+fn is_cursor_in_comment_or_string(doc: &helix_view::Document, pos: usize) -> bool {
+    let Some(syntax) = doc.syntax() else {
+        return false;
+    };
+    let byte = doc.text().char_to_byte(pos);
+    let Some(mut node) = syntax
+        .tree()
+        .root_node()
+        .descendant_for_byte_range(byte, byte)
+    else {
+        return false;
+    };
+    loop {
+        let kind = node.kind();
+        if kind.contains("comment") || kind.contains("string") {
+            return true;
+        }
+        match node.parent() {
+            Some(parent) => node = parent,
+            None => return false,
+        }
+    }
+}
+
 pub fn trigger_auto_completion(
     tx: &Sender<CompletionEvent>,
     editor: &Editor,
@@ -297,6 +328,11 @@ pub fn trigger_auto_completion(
         let primary_cursor = doc.selection(view.id).primary().cursor(text);
         text = doc.text().slice(..primary_cursor);
 
+        let language_config = doc.language_config();
+        let extra_trigger_characters = language_config
+            .map(|config| config.completion_trigger_characters.as_slice())
+            .unwrap_or_default();
+
         let is_trigger_char = doc
             .language_servers_with_feature(LanguageServerFeature::Completion)
             .any(|ls| {
@@ -304,15 +340,23 @@ pub fn trigger_auto_completion(
                         trigger_characters: Some(triggers),
                         ..
                     }) if triggers.iter().any(|trigger| rope_ends_with(trigger, text)))
-            });
+            })
+            || extra_trigger_characters
+                .iter()
+                .any(|trigger| rope_ends_with(trigger, text));
+
+        let trigger_len = language_config
+            .and_then(|config| config.completion_trigger_len)
+            .unwrap_or(config.completion_trigger_len);
 
         let is_auto_trigger = !trigger_char_only
             && doc
                 .text()
                 .chars_at(primary_cursor)
                 .reversed()
-                .take(config.completion_trigger_len as usize)
-                .all(char_is_word);
+                .take(trigger_len as usize)
+                .all(char_is_word)
+            && !is_cursor_in_comment_or_string(doc, primary_cursor);
 
         if is_trigger_char || is_auto_trigger {
             send_blocking(
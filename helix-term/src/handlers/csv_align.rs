@@ -0,0 +1,14 @@
+use helix_event::register_hook;
+use helix_view::events::DocumentDidChange;
+
+/// Keeps `:csv-align` column padding in sync with edits. Unlike the
+/// `:theme-edit` live preview, this only ever needs `&mut Document`, so it
+/// can run directly off `DocumentDidChange` without any extra indirection.
+pub(super) fn register_hooks() {
+    register_hook!(move |event: &mut DocumentDidChange<'_>| {
+        if event.doc.csv_delimiter.is_some() {
+            event.doc.refresh_csv_align();
+        }
+        Ok(())
+    });
+}
@@ -0,0 +1,12 @@
+use helix_event::register_hook;
+use helix_view::events::DocumentDidChange;
+
+/// Keeps the soft line-length budget diagnostics (see
+/// `editor.line-length-diagnostic`) in sync with edits, the same way
+/// `handlers::redact` keeps `:redact` overlays in sync.
+pub(super) fn register_hooks() {
+    register_hook!(move |event: &mut DocumentDidChange<'_>| {
+        event.doc.refresh_line_length_diagnostics();
+        Ok(())
+    });
+}
@@ -0,0 +1,13 @@
+use helix_event::register_hook;
+use helix_view::events::DocumentDidChange;
+
+/// Keeps `:redact` overlays in sync with edits, the same way
+/// `handlers::csv_align` keeps `:csv-align` padding in sync.
+pub(super) fn register_hooks() {
+    register_hook!(move |event: &mut DocumentDidChange<'_>| {
+        if event.doc.redact_enabled {
+            event.doc.refresh_redact();
+        }
+        Ok(())
+    });
+}
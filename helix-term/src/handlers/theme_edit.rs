@@ -0,0 +1,44 @@
+use helix_event::register_hook;
+use helix_view::theme::Theme;
+
+use crate::events::PostCommand;
+
+/// Watches the document opened by `:theme-edit` and re-previews the theme
+/// whenever it changes, so edits are visible immediately. Runs off the
+/// existing `PostCommand` hook (rather than `DocumentDidChange`, which has
+/// no `Editor` access) since re-parsing on every command is cheap enough
+/// for a TOML file this size and the revision check below makes it a no-op
+/// on commands that don't touch the buffer.
+pub(super) fn register_hooks() {
+    register_hook!(move |event: &mut PostCommand<'_, '_>| theme_edit_post_command_hook(event));
+}
+
+fn theme_edit_post_command_hook(
+    PostCommand { cx, .. }: &mut PostCommand<'_, '_>,
+) -> anyhow::Result<()> {
+    let Some((doc_id, last_revision)) = cx.editor.theme_edit else {
+        return Ok(());
+    };
+
+    let Some(doc) = cx.editor.documents.get_mut(&doc_id) else {
+        // The document was closed; stop tracking it and revert the preview.
+        cx.editor.theme_edit = None;
+        cx.editor.unset_theme_preview();
+        return Ok(());
+    };
+
+    let revision = doc.get_current_revision();
+    if revision == last_revision {
+        return Ok(());
+    }
+    cx.editor.theme_edit = Some((doc_id, revision));
+
+    // Parse errors are expected transiently while the user is mid-edit, so
+    // they're ignored here rather than surfaced; the previous preview stays
+    // in place until the buffer parses again.
+    if let Ok(value) = toml::from_str(&doc.text().to_string()) {
+        cx.editor.set_theme_preview(Theme::from(value));
+    }
+
+    Ok(())
+}
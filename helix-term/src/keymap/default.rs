@@ -44,10 +44,15 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "l" => goto_line_end,
             "s" => goto_first_nonwhitespace,
             "d" => goto_definition,
+            "P" => goto_definition_peek,
             "D" => goto_declaration,
+            "u" => goto_parent_symbol,
             "y" => goto_type_definition,
             "r" => goto_reference,
             "i" => goto_implementation,
+            "C" => call_hierarchy_incoming,
+            "O" => call_hierarchy_outgoing,
+            "x" => goto_link,
             "t" => goto_window_top,
             "c" => goto_window_center,
             "b" => goto_window_bottom,
@@ -60,6 +65,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "." => goto_last_modification,
         },
         ":" => command_mode,
+        "ret" => cmdline_window_accept,
 
         "i" => insert_mode,
         "I" => insert_at_line_start,
@@ -108,6 +114,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
         "[" => { "Left bracket"
             "d" => goto_prev_diag,
             "D" => goto_first_diag,
+            "q" => goto_prev_quickfix,
             "g" => goto_prev_change,
             "G" => goto_first_change,
             "f" => goto_prev_function,
@@ -116,11 +123,13 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "c" => goto_prev_comment,
             "T" => goto_prev_test,
             "p" => goto_prev_paragraph,
+            "s" => goto_previous_symbol,
             "space" => add_newline_above,
         },
         "]" => { "Right bracket"
             "d" => goto_next_diag,
             "D" => goto_last_diag,
+            "q" => goto_next_quickfix,
             "g" => goto_next_change,
             "G" => goto_last_change,
             "f" => goto_next_function,
@@ -129,6 +138,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "c" => goto_next_comment,
             "T" => goto_next_test,
             "p" => goto_next_paragraph,
+            "s" => goto_next_symbol,
             "space" => add_newline_below,
         },
 
@@ -191,6 +201,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "F" => goto_file_vsplit,
             "C-q" | "q" => wclose,
             "C-o" | "o" => wonly,
+            "p" => toggle_view_pin,
             "C-h" | "h" | "left" => jump_view_left,
             "C-j" | "j" | "down" => jump_view_down,
             "C-k" | "k" | "up" => jump_view_up,
@@ -217,8 +228,10 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
         "space" => { "Space"
             "f" => file_picker,
             "F" => file_picker_in_current_directory,
+            "e" => file_explorer,
             "b" => buffer_picker,
             "j" => jumplist_picker,
+            "L" => buffer_line_picker,
             "s" => symbol_picker,
             "S" => workspace_symbol_picker,
             "d" => diagnostics_picker,
@@ -255,6 +268,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
                 "F" => goto_file_vsplit,
                 "C-q" | "q" => wclose,
                 "C-o" | "o" => wonly,
+                "p" => toggle_view_pin,
                 "C-h" | "h" | "left" => jump_view_left,
                 "C-j" | "j" | "down" => jump_view_down,
                 "C-k" | "k" | "up" => jump_view_up,
@@ -272,11 +286,15 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "Y" => yank_main_selection_to_clipboard,
             "p" => paste_clipboard_after,
             "P" => paste_clipboard_before,
+            "A-p" => paste_clipboard_after_as_selections,
+            "A-P" => paste_clipboard_before_as_selections,
             "R" => replace_selections_with_clipboard,
             "/" => global_search,
             "k" => hover,
             "r" => rename_symbol,
             "h" => select_references_to_symbol_under_cursor,
+            "i" => inspect_register,
+            "I" => registers_picker,
             "c" => { "Toggle comments"
                 "c" => toggle_comments,
                 "l" => toggle_line_comments,
@@ -6,6 +6,8 @@
 pub mod commands;
 pub mod compositor;
 pub mod config;
+#[cfg(unix)]
+pub mod daemon;
 pub mod events;
 pub mod health;
 pub mod job;
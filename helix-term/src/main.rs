@@ -51,6 +51,8 @@ async fn main_impl() -> Result<i32> {
 
 ARGS:
     <files>...    Sets the input file to use, position can also be specified via file[:row[:col]]
+    +/<pattern>   Moves the cursor to the first match of <pattern> in the last file opened
+    +:<command>   Runs <command> (a typable command, without the leading `:`) once loading finishes
 
 FLAGS:
     -h, --help                     Prints help information
@@ -67,12 +69,18 @@ async fn main_impl() -> Result<i32> {
     --vsplit                       Splits all given files vertically into different windows
     --hsplit                       Splits all given files horizontally into different windows
     -w, --working-dir <path>       Specify an initial working directory
+    --headless                     Runs as a daemon with no attached terminal; clients
+                                   connect with --attach (Unix only)
+    --attach [PATH]                Attaches to a daemon started with --headless instead
+                                   of starting a new instance (default socket path:
+                                   {})
 ",
         env!("CARGO_PKG_NAME"),
         VERSION_AND_GIT_HASH,
         env!("CARGO_PKG_AUTHORS"),
         env!("CARGO_PKG_DESCRIPTION"),
         helix_loader::default_log_file().display(),
+        helix_loader::cache_dir().join("daemon.sock").display(),
     );
 
     let args = Args::parse_args().context("could not parse arguments")?;
@@ -147,6 +155,25 @@ async fn main_impl() -> Result<i32> {
         helix_core::config::default_syntax_loader()
     });
 
+    #[cfg(unix)]
+    if args.attach {
+        let socket_path = args
+            .socket_path
+            .clone()
+            .unwrap_or_else(helix_term::daemon::default_socket_path);
+        return helix_term::daemon::run_attach(&socket_path).await;
+    }
+
+    #[cfg(unix)]
+    if args.headless {
+        return helix_term::daemon::run_daemon(args, config, syn_loader_conf).await;
+    }
+
+    #[cfg(not(unix))]
+    if args.headless || args.attach {
+        anyhow::bail!("--headless and --attach are only supported on Unix");
+    }
+
     // TODO: use the thread local executor to spawn the application task separately from the work pool
     let mut app = Application::new(args, config, syn_loader_conf)
         .context("unable to create new application")?;
@@ -0,0 +1,276 @@
+use tui::{
+    buffer::Buffer as Surface,
+    widgets::{Block, Borders, Widget},
+};
+
+use helix_view::{Document, DocumentId, Editor, ViewId};
+
+use crate::{
+    compositor::{Callback, Component, Context, Event, EventResult},
+    key,
+};
+
+/// What to do once every buffer listed in a [`BufferCloseReview`] has been
+/// resolved (saved or discarded).
+pub enum PendingQuit {
+    /// Close a single view, e.g. `:q` with one view left.
+    View(ViewId),
+    /// Close every view, e.g. `:qa`/`:wqa`.
+    AllViews,
+}
+
+struct Entry {
+    doc_id: DocumentId,
+    name: String,
+    added: usize,
+    removed: usize,
+}
+
+/// Shown instead of the "N unsaved buffer(s) remaining" error when a quit
+/// would otherwise be blocked by modified buffers. Lists each dirty buffer
+/// with a added/removed line count, and a unified diff of the selected
+/// buffer against its last saved/VCS-base revision rendered below the list
+/// (sourced from the same [`helix_view::editor::DiffProviderRegistry`] the
+/// gutter diff markers use; a buffer without a diff base, e.g. a new file,
+/// just shows an empty diff). `s` saves the selected buffer, `d` discards
+/// its changes by reloading from disk. Once every entry is resolved the
+/// panel closes itself and performs the quit it was blocking.
+pub struct BufferCloseReview {
+    entries: Vec<Entry>,
+    selected: usize,
+    pending: PendingQuit,
+}
+
+impl BufferCloseReview {
+    pub fn new(editor: &Editor, doc_ids: Vec<DocumentId>, pending: PendingQuit) -> Self {
+        let entries = doc_ids
+            .into_iter()
+            .filter_map(|doc_id| {
+                let doc = editor.documents.get(&doc_id)?;
+                let (added, removed) = diff_stat(doc);
+                Some(Entry {
+                    doc_id,
+                    name: doc.display_name().into_owned(),
+                    added,
+                    removed,
+                })
+            })
+            .collect();
+        Self {
+            entries,
+            selected: 0,
+            pending,
+        }
+    }
+
+    fn clamp_selection(&mut self) {
+        if self.selected >= self.entries.len() {
+            self.selected = self.entries.len().saturating_sub(1);
+        }
+    }
+
+    fn save_selected(&mut self, editor: &mut Editor) {
+        let Some(entry) = self.entries.get(self.selected) else {
+            return;
+        };
+        if let Err(err) = editor.save::<std::path::PathBuf>(entry.doc_id, None, false) {
+            editor.set_error(format!("{err}"));
+            return;
+        }
+        self.entries.remove(self.selected);
+        self.clamp_selection();
+    }
+
+    fn discard_selected(&mut self, editor: &mut Editor) {
+        let Some(entry) = self.entries.get(self.selected) else {
+            return;
+        };
+        let doc_id = entry.doc_id;
+        let current = editor.tree.focus;
+        let view_id = {
+            let doc = doc_mut!(editor, &doc_id);
+            match doc.selections().keys().next().copied() {
+                Some(view_id) => view_id,
+                None => {
+                    doc.ensure_view_init(current);
+                    current
+                }
+            }
+        };
+        let view = view_mut!(editor, view_id);
+        let doc = doc_mut!(editor, &doc_id);
+        if let Err(err) = doc.reload(view, &editor.diff_providers) {
+            editor.set_error(format!("failed to discard changes: {err}"));
+            return;
+        }
+        self.entries.remove(self.selected);
+        self.clamp_selection();
+    }
+
+    /// If every buffer has been resolved, returns a callback that performs
+    /// the quit this panel was blocking and closes the panel; otherwise just
+    /// keeps the panel open with the selection clamped to the remaining list.
+    fn finish_if_done(&mut self) -> EventResult {
+        if !self.entries.is_empty() {
+            self.clamp_selection();
+            return EventResult::Consumed(None);
+        }
+
+        let pending = std::mem::replace(&mut self.pending, PendingQuit::AllViews);
+        EventResult::Consumed(Some(Box::new(move |compositor, cx: &mut Context| {
+            match pending {
+                PendingQuit::View(view_id) => cx.editor.close(view_id),
+                PendingQuit::AllViews => {
+                    let views: Vec<_> = cx.editor.tree.views().map(|(view, _)| view.id).collect();
+                    for view_id in views {
+                        cx.editor.close(view_id);
+                    }
+                }
+            }
+            compositor.remove(ID);
+        })))
+    }
+
+    fn close() -> Callback {
+        Box::new(|compositor, _| {
+            compositor.remove(ID);
+        })
+    }
+}
+
+/// Total added/removed line counts across every hunk of `doc`'s diff against
+/// its base, or `(0, 0)` if no diff is available (e.g. a new, unsaved file).
+fn diff_stat(doc: &Document) -> (usize, usize) {
+    let Some(handle) = doc.diff_handle() else {
+        return (0, 0);
+    };
+    let diff = handle.load();
+    let mut added = 0;
+    let mut removed = 0;
+    for i in 0..diff.len() {
+        let hunk = diff.nth_hunk(i);
+        added += (hunk.after.end - hunk.after.start) as usize;
+        removed += (hunk.before.end - hunk.before.start) as usize;
+    }
+    (added, removed)
+}
+
+/// Renders `doc`'s diff against its base as unified-diff-style lines, each
+/// tagged with `'-'` (removed, from the base) or `'+'` (added, from `doc`).
+fn diff_lines(doc: &Document) -> Vec<(char, String)> {
+    let Some(handle) = doc.diff_handle() else {
+        return Vec::new();
+    };
+    let diff = handle.load();
+    let mut lines = Vec::new();
+    for i in 0..diff.len() {
+        let hunk = diff.nth_hunk(i);
+        let diff_base = diff.diff_base();
+        for line in hunk.before.start..hunk.before.end {
+            let text = diff_base.line(line as usize).to_string();
+            lines.push(('-', text.trim_end_matches(['\n', '\r']).to_string()));
+        }
+        let doc_text = diff.doc();
+        for line in hunk.after.start..hunk.after.end {
+            let text = doc_text.line(line as usize).to_string();
+            lines.push(('+', text.trim_end_matches(['\n', '\r']).to_string()));
+        }
+    }
+    lines
+}
+
+impl Component for BufferCloseReview {
+    fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
+        let key = match event {
+            Event::Key(event) => *event,
+            _ => return EventResult::Ignored(None),
+        };
+
+        match key {
+            key!(Esc) | key!('q') => EventResult::Consumed(Some(Self::close())),
+            key!(Up) | key!('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                EventResult::Consumed(None)
+            }
+            key!(Down) | key!('j') => {
+                if self.selected + 1 < self.entries.len() {
+                    self.selected += 1;
+                }
+                EventResult::Consumed(None)
+            }
+            key!('s') => {
+                self.save_selected(cx.editor);
+                self.finish_if_done()
+            }
+            key!('d') => {
+                self.discard_selected(cx.editor);
+                self.finish_if_done()
+            }
+            _ => EventResult::Consumed(None),
+        }
+    }
+
+    fn render(&mut self, area: helix_view::graphics::Rect, surface: &mut Surface, cx: &mut Context) {
+        let width = area.width.saturating_sub(4).clamp(20, 100);
+        let height = area.height.saturating_sub(4).clamp(10, 40);
+        let panel = helix_view::graphics::Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+
+        let background = cx.editor.theme.get("ui.background");
+        let text_style = cx.editor.theme.get("ui.text");
+        let selected_style = cx.editor.theme.get("ui.text.focus");
+        let added_style = cx.editor.theme.get("diff.plus");
+        let removed_style = cx.editor.theme.get("diff.minus");
+
+        surface.clear_with(panel, background);
+        let block = Block::default().borders(Borders::ALL).title(
+            " Unsaved changes — s: save, d: discard, Esc: cancel ",
+        );
+        let inner = block.inner(panel);
+        block.render(panel, surface);
+
+        if self.entries.is_empty() {
+            surface.set_stringn(inner.x, inner.y, "No unsaved buffers", inner.width as usize, text_style);
+            return;
+        }
+
+        let list_height = (self.entries.len() as u16).min(inner.height.saturating_sub(2) / 2).max(1);
+        for (row, entry) in self.entries.iter().enumerate().take(list_height as usize) {
+            let y = inner.y + row as u16;
+            let marker = if row == self.selected { "> " } else { "  " };
+            let line = format!("{marker}{} (+{} -{})", entry.name, entry.added, entry.removed);
+            let style = if row == self.selected { selected_style } else { text_style };
+            surface.set_stringn(inner.x, y, &line, inner.width as usize, style);
+        }
+
+        if inner.height > list_height + 1 {
+            let diff_y = inner.y + list_height + 1;
+            let diff_height = inner.height - list_height - 1;
+            if let Some(entry) = self.entries.get(self.selected) {
+                if let Some(doc) = cx.editor.documents.get(&entry.doc_id) {
+                    for (row, (marker, text)) in
+                        diff_lines(doc).into_iter().enumerate().take(diff_height as usize)
+                    {
+                        let style = match marker {
+                            '+' => added_style,
+                            '-' => removed_style,
+                            _ => text_style,
+                        };
+                        let line = format!("{marker}{text}");
+                        surface.set_stringn(inner.x, diff_y + row as u16, &line, inner.width as usize, style);
+                    }
+                }
+            }
+        }
+    }
+
+    fn id(&self) -> Option<&'static str> {
+        Some(ID)
+    }
+}
+
+pub(crate) const ID: &str = "buffer-close-review";
@@ -0,0 +1,210 @@
+use std::path::PathBuf;
+
+use tui::{
+    buffer::Buffer as Surface,
+    widgets::{Block, Borders, Widget},
+};
+
+use helix_view::{editor::Action, graphics::Rect};
+
+use crate::{
+    compositor::{Component, Context, Event, EventResult},
+    key,
+    ui::file_picker,
+};
+
+enum Entry {
+    File(PathBuf),
+    Directory(PathBuf),
+}
+
+/// The screen shown in place of an empty scratch buffer when Helix is
+/// launched at an interactive terminal with no file arguments: a list of
+/// recently-accepted files and the directories they live in, sourced from
+/// the same [`helix_loader::frecency`] store the file picker itself uses to
+/// rank results, so there is no new persistence to maintain.
+///
+/// "Recent projects" here is deliberately nothing more than the
+/// deduplicated parent directories of those recent files -- there is no
+/// session or workspace concept anywhere else in the codebase to draw a
+/// richer list from, and inventing one just for this screen would be far
+/// more than a startup screen should carry.
+pub struct Dashboard {
+    entries: Vec<Entry>,
+    selected: usize,
+}
+
+impl Dashboard {
+    pub fn new() -> Self {
+        let mut files: Vec<(PathBuf, f64)> = helix_loader::frecency::scores()
+            .into_iter()
+            .filter(|(path, _)| path.is_file())
+            .collect();
+        files.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        files.truncate(10);
+
+        let mut directories = Vec::new();
+        for (path, _) in &files {
+            if let Some(parent) = path.parent() {
+                let parent = parent.to_path_buf();
+                if !directories.contains(&parent) {
+                    directories.push(parent);
+                }
+            }
+        }
+        directories.truncate(5);
+
+        let entries = files
+            .into_iter()
+            .map(|(path, _)| Entry::File(path))
+            .chain(directories.into_iter().map(Entry::Directory))
+            .collect();
+
+        Self {
+            entries,
+            selected: 0,
+        }
+    }
+
+    fn open_selected(&self, cx: &mut Context) -> EventResult {
+        match self.entries.get(self.selected) {
+            Some(Entry::File(path)) => {
+                if let Err(err) = cx.editor.open(path, Action::Replace) {
+                    cx.editor
+                        .set_error(format!("unable to open \"{}\": {err}", path.display()));
+                }
+                EventResult::Consumed(None)
+            }
+            Some(Entry::Directory(path)) => {
+                let picker = file_picker(path.clone(), &cx.editor.config());
+                EventResult::Consumed(Some(Box::new(|compositor, _| {
+                    compositor.push(Box::new(crate::ui::overlay::overlaid(picker)));
+                })))
+            }
+            None => EventResult::Ignored(None),
+        }
+    }
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for Dashboard {
+    fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
+        let key = match event {
+            Event::Key(event) => *event,
+            _ => return EventResult::Ignored(None),
+        };
+
+        match key {
+            key!(Up) | key!('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                EventResult::Consumed(None)
+            }
+            key!(Down) | key!('j') => {
+                if self.selected + 1 < self.entries.len() {
+                    self.selected += 1;
+                }
+                EventResult::Consumed(None)
+            }
+            key!(Enter) => self.open_selected(cx),
+            _ => EventResult::Ignored(None),
+        }
+    }
+
+    fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
+        let background = cx.editor.theme.get("ui.background");
+        let text_style = cx.editor.theme.get("ui.text");
+        let selected_style = cx.editor.theme.get("ui.text.focus");
+        let hint_style = cx.editor.theme.get("ui.virtual");
+
+        surface.clear_with(area, background);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Helix ");
+        let inner = block.inner(area);
+        block.render(area, surface);
+
+        let mut y = inner.y;
+        let mut row = 0;
+
+        let has_files = self.entries.iter().any(|e| matches!(e, Entry::File(_)));
+        let has_dirs = self
+            .entries
+            .iter()
+            .any(|e| matches!(e, Entry::Directory(_)));
+
+        if has_files && y < inner.bottom() {
+            surface.set_stringn(inner.x, y, "Recent files", inner.width as usize, hint_style);
+            y += 1;
+        }
+        for entry in &self.entries {
+            let Entry::File(path) = entry else { continue };
+            if y >= inner.bottom() {
+                break;
+            }
+            let style = if row == self.selected {
+                selected_style
+            } else {
+                text_style
+            };
+            surface.set_stringn(
+                inner.x,
+                y,
+                &path.display().to_string(),
+                inner.width as usize,
+                style,
+            );
+            y += 1;
+            row += 1;
+        }
+
+        if has_dirs && y < inner.bottom() {
+            y += 1;
+            surface.set_stringn(
+                inner.x,
+                y,
+                "Recent directories",
+                inner.width as usize,
+                hint_style,
+            );
+            y += 1;
+        }
+        for entry in &self.entries {
+            let Entry::Directory(path) = entry else {
+                continue;
+            };
+            if y >= inner.bottom() {
+                break;
+            }
+            let style = if row == self.selected {
+                selected_style
+            } else {
+                text_style
+            };
+            surface.set_stringn(
+                inner.x,
+                y,
+                &path.display().to_string(),
+                inner.width as usize,
+                style,
+            );
+            y += 1;
+            row += 1;
+        }
+
+        if inner.bottom().saturating_sub(y) >= 2 {
+            let hint_y = inner.bottom() - 1;
+            surface.set_stringn(
+                inner.x,
+                hint_y,
+                "↑/↓ or j/k: select   Enter: open   :open <path>: open a different file",
+                inner.width as usize,
+                hint_style,
+            );
+        }
+    }
+}
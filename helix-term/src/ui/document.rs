@@ -226,6 +226,25 @@ pub struct TextRenderer<'a> {
     pub draw_indent_guides: bool,
     pub col_offset: usize,
     pub viewport: Rect,
+    pub render_control_characters: bool,
+}
+
+/// Whether `g` is a single non-printable ASCII control character (excluding
+/// tab and newline, which have their own dedicated rendering).
+fn is_control_char(g: &str) -> bool {
+    let mut chars = g.chars();
+    matches!((chars.next(), chars.next()), (Some(ch), None) if ch.is_control() && ch != '\t' && ch != '\n')
+}
+
+/// Renders a control character using conventional caret notation, e.g. `^A`
+/// for `0x01` and `^?` for DEL (`0x7f`).
+fn control_char_repr(g: &str) -> String {
+    let code = g.chars().next().map_or(0, u32::from);
+    if code < 0x20 {
+        format!("^{}", (b'@' + code as u8) as char)
+    } else {
+        "^?".to_string()
+    }
 }
 
 impl<'a> TextRenderer<'a> {
@@ -291,9 +310,10 @@ pub fn new(
                     .unwrap_or_else(|| theme.get("ui.virtual.whitespace")),
             ),
             text_style,
-            draw_indent_guides: editor_config.indent_guides.render,
+            draw_indent_guides: editor_config.indent_guides.render && !editor_config.low_bandwidth,
             viewport,
             col_offset,
+            render_control_characters: editor_config.render_control_characters,
         }
     }
     /// Draws a single `grapheme` at the current render position with a specified `style`.
@@ -359,6 +379,7 @@ pub fn draw_grapheme(
         } else {
             &self.tab
         };
+        let control_repr;
         let grapheme = match grapheme {
             Grapheme::Tab { width } => {
                 let grapheme_tab_width = char_to_byte_idx(tab, width);
@@ -367,6 +388,10 @@ pub fn draw_grapheme(
             // TODO special rendering for other whitespaces?
             Grapheme::Other { ref g } if g == " " => space,
             Grapheme::Other { ref g } if g == "\u{00A0}" => nbsp,
+            Grapheme::Other { ref g } if self.render_control_characters && is_control_char(g) => {
+                control_repr = control_char_repr(g);
+                control_repr.as_str()
+            }
             Grapheme::Other { ref g } => g,
             Grapheme::Newline => &self.newline,
         };
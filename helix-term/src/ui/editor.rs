@@ -24,12 +24,12 @@
 };
 use helix_view::{
     document::{Mode, SavePoint, SCRATCH_BUFFER_NAME},
-    editor::{CompleteAction, CursorShapeConfig},
+    editor::{Action, CloseError, CompleteAction, CursorShapeConfig},
     graphics::{Color, CursorKind, Modifier, Rect, Style},
     info::Delay,
     input::{KeyEvent, MouseButton, MouseEvent, MouseEventKind},
     keyboard::{KeyCode, KeyModifiers},
-    view, Document, Editor, Theme, View,
+    view, Document, DocumentId, Editor, Theme, View,
 };
 use std::{mem::take, num::NonZeroUsize, path::PathBuf, rc::Rc, sync::Arc};
 
@@ -47,6 +47,10 @@ pub struct EditorView {
     spinners: ProgressSpinners,
     /// Tracks if the terminal window is focused by reaction to terminal focus events
     terminal_focused: bool,
+    /// Row and column ranges of each tab drawn by the last
+    /// [`Self::render_bufferline`] call, used to map a bufferline mouse
+    /// click back to a document.
+    bufferline_tabs: Vec<(DocumentId, u16, std::ops::Range<u16>)>,
 }
 
 #[derive(Debug, Clone)]
@@ -76,6 +80,7 @@ pub fn new(keymaps: Keymaps) -> Self {
             completion: None,
             spinners: ProgressSpinners::default(),
             terminal_focused: true,
+            bufferline_tabs: Vec::new(),
         }
     }
 
@@ -97,19 +102,20 @@ pub fn render_view(
         let theme = &editor.theme;
         let config = editor.config();
 
-        let should_render_rainbow_brackets = doc
-            .language_config()
-            .and_then(|lang_config| lang_config.rainbow_brackets)
-            .unwrap_or(config.rainbow_brackets);
+        let should_render_rainbow_brackets = !config.low_bandwidth
+            && doc
+                .language_config()
+                .and_then(|lang_config| lang_config.rainbow_brackets)
+                .unwrap_or(config.rainbow_brackets);
 
         let text_annotations = view.text_annotations(doc, Some(theme));
         let mut decorations = DecorationManager::default();
 
-        if is_focused && config.cursorline {
+        if is_focused && config.cursorline && !config.low_bandwidth {
             decorations.add_decoration(Self::cursorline(doc, view, theme));
         }
 
-        if is_focused && config.cursorcolumn {
+        if is_focused && config.cursorcolumn && !config.low_bandwidth {
             Self::highlight_cursorcolumn(doc, view, surface, theme, inner, &text_annotations);
         }
 
@@ -201,12 +207,16 @@ pub fn render_view(
                 primary_cursor,
             });
         }
-        if config.lsp.inline_diagnostics.enable(inner.width) {
+        let mut inline_diagnostics = config.lsp.inline_diagnostics.clone();
+        if let Some(enabled) = doc.inline_diagnostics {
+            inline_diagnostics.enabled = enabled;
+        }
+        if inline_diagnostics.enable(inner.width) {
             decorations.add_decoration(InlineDiagnostics::new(
                 doc.diagnostics(),
                 theme,
                 primary_cursor,
-                config.lsp.inline_diagnostics.clone(),
+                inline_diagnostics,
             ));
         };
 
@@ -252,8 +262,15 @@ pub fn render_view(
             .clip_top(view.area.height.saturating_sub(1))
             .clip_bottom(1); // -1 from bottom to remove commandline
 
-        let mut context =
-            statusline::RenderContext::new(editor, doc, view, is_focused, &self.spinners);
+        let mut context = statusline::RenderContext::new(
+            editor,
+            doc,
+            view,
+            is_focused,
+            &self.spinners,
+            self.keymaps.pending(),
+            self.keymaps.sticky().map(|node| node.name()),
+        );
 
         statusline::render(&mut context, statusline_area, surface);
     }
@@ -316,7 +333,7 @@ pub fn doc_syntax_highlights<'doc>(
         doc: &'doc Document,
         anchor: usize,
         height: u16,
-        _theme: &Theme,
+        theme: &Theme,
     ) -> Box<dyn Iterator<Item = HighlightEvent> + 'doc> {
         let text = doc.text().slice(..);
         let row = text.char_to_line(anchor.min(text.len_chars()));
@@ -353,13 +370,60 @@ pub fn doc_syntax_highlights<'doc>(
 
                 Box::new(iter)
             }
-            None => Box::new(
-                [HighlightEvent::Source {
-                    start: text.byte_to_char(range.start),
-                    end: text.byte_to_char(range.end),
-                }]
-                .into_iter(),
-            ),
+            None => {
+                // No tree-sitter grammar for this document (either none is
+                // configured, or it isn't built). Fall back to a lightweight
+                // heuristic highlighter over just the visible range, rather
+                // than leaving the text completely unstyled.
+                let source = text.byte_slice(range.clone()).to_string();
+                let comment_tokens = doc
+                    .language_config()
+                    .and_then(|config| config.comment_tokens.clone())
+                    .unwrap_or_default();
+                let is_log_file = doc
+                    .path()
+                    .and_then(|path| path.extension())
+                    .map_or(false, |ext| ext == "log");
+                let highlights = syntax::heuristic::HeuristicHighlights {
+                    comment: theme.find_scope_index_exact("comment").map(syntax::Highlight),
+                    string: theme.find_scope_index_exact("string").map(syntax::Highlight),
+                    number: theme
+                        .find_scope_index_exact("constant.numeric")
+                        .map(syntax::Highlight),
+                    log_levels: if is_log_file {
+                        syntax::heuristic::LogLevelHighlights {
+                            error: theme
+                                .find_scope_index_exact("diagnostic.error")
+                                .map(syntax::Highlight),
+                            warning: theme
+                                .find_scope_index_exact("diagnostic.warning")
+                                .map(syntax::Highlight),
+                            info: theme
+                                .find_scope_index_exact("diagnostic.info")
+                                .map(syntax::Highlight),
+                            debug: theme
+                                .find_scope_index_exact("diagnostic.hint")
+                                .map(syntax::Highlight),
+                        }
+                    } else {
+                        syntax::heuristic::LogLevelHighlights::default()
+                    },
+                };
+                let base = range.start;
+                let events =
+                    syntax::heuristic::highlight_events(&source, 0..source.len(), &comment_tokens, &highlights);
+
+                Box::new(events.into_iter().map(move |event| match event {
+                    HighlightEvent::Source { start, end } => HighlightEvent::Source {
+                        start: text.byte_to_char(ensure_grapheme_boundary_next_byte(
+                            text,
+                            base + start,
+                        )),
+                        end: text.byte_to_char(ensure_grapheme_boundary_next_byte(text, base + end)),
+                    },
+                    event => event,
+                }))
+            }
         }
     }
 
@@ -590,8 +654,54 @@ pub fn highlight_focused_view_elements(
         Vec::new()
     }
 
+    /// Draws the currently un-expired entries from [`Editor::notifications`]
+    /// as a stack of single-line toasts anchored to the top-right corner,
+    /// most recent on top. History beyond the toast window is only ever
+    /// reachable through `:notifications`, not drawn here.
+    pub fn render_notifications(editor: &Editor, viewport: Rect, surface: &mut Surface) {
+        use helix_view::editor::Severity;
+
+        let width = viewport.width.min(40).max(10);
+        for (i, notification) in editor.notifications.visible().enumerate() {
+            let y = viewport.y + i as u16;
+            if y >= viewport.y + viewport.height {
+                break;
+            }
+            let x = viewport.x + viewport.width.saturating_sub(width);
+
+            let style = match notification.severity {
+                Severity::Error => editor.theme.get("error"),
+                Severity::Warning => editor.theme.get("warning"),
+                Severity::Hint => editor.theme.get("hint"),
+                Severity::Info => editor.theme.get("info"),
+            };
+            surface.clear_with(Rect::new(x, y, width, 1), style);
+            surface.set_stringn(x, y, &notification.message, width as usize, style);
+        }
+    }
+
+    /// Order the bufferline should draw documents in: pinned buffers first
+    /// (see [`Document::pinned`]), each group keeping [`Editor::buffer_order`]'s
+    /// relative order. Falls back to `editor.documents()`'s order for any
+    /// open document somehow missing from `buffer_order`.
+    fn bufferline_order(editor: &Editor) -> Vec<DocumentId> {
+        let mut order: Vec<DocumentId> = editor
+            .buffer_order
+            .iter()
+            .copied()
+            .filter(|id| editor.documents.contains_key(id))
+            .collect();
+        for doc in editor.documents() {
+            if !order.contains(&doc.id()) {
+                order.push(doc.id());
+            }
+        }
+        order.sort_by_key(|id| !editor.documents[id].pinned);
+        order
+    }
+
     /// Render bufferline at the top
-    pub fn render_bufferline(editor: &Editor, viewport: Rect, surface: &mut Surface) {
+    pub fn render_bufferline(&mut self, editor: &Editor, viewport: Rect, surface: &mut Surface) {
         let scratch = PathBuf::from(SCRATCH_BUFFER_NAME); // default filename to use for scratch buffer
         surface.clear_with(
             viewport,
@@ -611,10 +721,15 @@ pub fn render_bufferline(editor: &Editor, viewport: Rect, surface: &mut Surface)
             .try_get("ui.bufferline")
             .unwrap_or_else(|| editor.theme.get("ui.statusline.inactive"));
 
+        let bufferline_pinned = editor.theme.try_get("ui.bufferline.pinned");
+
         let mut x = viewport.x;
         let current_doc = view!(editor).doc;
 
-        for doc in editor.documents() {
+        self.bufferline_tabs.clear();
+
+        for doc_id in Self::bufferline_order(editor) {
+            let doc = &editor.documents[&doc_id];
             let fname = doc
                 .path()
                 .unwrap_or(&scratch)
@@ -623,19 +738,26 @@ pub fn render_bufferline(editor: &Editor, viewport: Rect, surface: &mut Surface)
                 .to_str()
                 .unwrap_or_default();
 
-            let style = if current_doc == doc.id() {
+            let mut style = if current_doc == doc_id {
                 bufferline_active
             } else {
                 bufferline_inactive
             };
+            if doc.pinned {
+                if let Some(pinned_style) = bufferline_pinned {
+                    style = style.patch(pinned_style);
+                }
+            }
 
             let text = format!(" {}{} ", fname, if doc.is_modified() { "[+]" } else { "" });
             let used_width = viewport.x.saturating_sub(x);
             let rem_width = surface.area.width.saturating_sub(used_width);
 
+            let start = x;
             x = surface
                 .set_stringn(x, viewport.y, text, rem_width as usize, style)
                 .0;
+            self.bufferline_tabs.push((doc_id, viewport.y, start..x));
 
             if x >= surface.area.right() {
                 break;
@@ -1117,6 +1239,8 @@ pub fn clear_completion(&mut self, editor: &mut Editor) {
 
     pub fn handle_idle_timeout(&mut self, cx: &mut commands::Context) -> EventResult {
         commands::compute_inlay_hints_for_all_views(cx.editor, cx.jobs);
+        commands::compute_code_lens_for_all_views(cx.editor, cx.jobs);
+        commands::compute_document_colors_for_all_views(cx.editor, cx.jobs);
 
         if let Some(completion) = &mut self.completion {
             return if completion.ensure_item_resolved(cx) {
@@ -1149,6 +1273,28 @@ fn handle_mouse_event(
             ..
         } = *event;
 
+        if let Some(&(doc_id, _, _)) = self
+            .bufferline_tabs
+            .iter()
+            .find(|(_, tab_row, range)| *tab_row == row && range.contains(&column))
+        {
+            match kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    cxt.editor.switch(doc_id, Action::Replace);
+                    return EventResult::Consumed(None);
+                }
+                MouseEventKind::Up(MouseButton::Middle) => {
+                    if let Err(CloseError::BufferModified(name)) =
+                        cxt.editor.close_document(doc_id, false)
+                    {
+                        cxt.editor.set_error(format!("{}: unsaved buffer", name));
+                    }
+                    return EventResult::Consumed(None);
+                }
+                _ => {}
+            }
+        }
+
         let pos_and_view = |editor: &Editor, row, column, ignore_virtual_text| {
             editor.tree.views().find_map(|(view, _focus)| {
                 view.pos_at_screen_coords(
@@ -1515,7 +1661,7 @@ fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         cx.editor.resize(editor_area);
 
         if use_bufferline {
-            Self::render_bufferline(cx.editor, area.with_height(1), surface);
+            self.render_bufferline(cx.editor, area.with_height(1), surface);
         }
 
         for (view, is_focused) in cx.editor.tree.views() {
@@ -1594,6 +1740,8 @@ fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         if let Some(completion) = self.completion.as_mut() {
             completion.render(area, surface, cx);
         }
+
+        Self::render_notifications(cx.editor, area, surface);
     }
 
     fn cursor(&self, _area: Rect, editor: &Editor) -> (Option<Position>, CursorKind) {
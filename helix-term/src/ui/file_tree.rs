@@ -0,0 +1,396 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use tui::{
+    buffer::Buffer as Surface,
+    widgets::{Block, Borders, Widget},
+};
+
+use helix_view::{editor::Action, graphics::Rect, Editor};
+
+use crate::{
+    compositor::{Callback, Component, Context, Event, EventResult},
+    ctrl, key,
+    ui::{Prompt, PromptEvent},
+};
+
+struct Entry {
+    path: PathBuf,
+    depth: usize,
+    is_dir: bool,
+    expanded: bool,
+}
+
+/// A toggleable panel listing the files under a workspace root, with
+/// expand/collapse for directories, opening files (in place or in a split),
+/// basic create/rename/delete, and a `+`/`~` marker for untracked/modified
+/// files sourced from the same [`helix_view::editor::DiffProviderRegistry`]
+/// the gutter diff markers use.
+///
+/// This is *not* a docked panel in the sense of the editor yielding it
+/// columns: the compositor renders every layer into the same full terminal
+/// area (see `Compositor::render`), and `EditorView` derives its own
+/// viewport from that same area on every frame, so a layer above it has no
+/// way to make it smaller. Teaching `EditorView` about a docked sibling
+/// would be a much larger structural change than a single toggle command
+/// should carry, so instead this renders as a bordered strip along the left
+/// edge, on top of the editor, taking exclusive input focus while open.
+///
+/// `j`/`k` or the arrow keys move the selection, `l`/`Right` expands a
+/// directory, `h`/`Left` collapses one, `Enter` toggles a directory or opens
+/// a file in place, `v`/`s` open it in a vertical/horizontal split, `Ctrl-n`
+/// creates a new file in the selected directory (or next to the selected
+/// file), `r` renames it, `d` deletes it (moved to
+/// [`helix_loader::trash`] unless `trash-delete` is disabled), and `q`/`Esc`
+/// closes the panel.
+pub struct FileTree {
+    root: PathBuf,
+    entries: Vec<Entry>,
+    selected: usize,
+    width: u16,
+}
+
+impl FileTree {
+    pub fn new(root: PathBuf) -> Self {
+        let entries = Self::read_dir(&root, 0);
+        Self {
+            root,
+            entries,
+            selected: 0,
+            width: 32,
+        }
+    }
+
+    fn read_dir(dir: &Path, depth: usize) -> Vec<Entry> {
+        let mut entries: Vec<(PathBuf, bool)> = fs::read_dir(dir)
+            .map(|read_dir| {
+                read_dir
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| {
+                        let is_dir = entry.path().is_dir();
+                        (entry.path(), is_dir)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        // directories first, then alphabetically within each group
+        entries.sort_by(|(a_path, a_dir), (b_path, b_dir)| {
+            b_dir.cmp(a_dir).then_with(|| a_path.cmp(b_path))
+        });
+        entries
+            .into_iter()
+            .map(|(path, is_dir)| Entry {
+                path,
+                depth,
+                is_dir,
+                expanded: false,
+            })
+            .collect()
+    }
+
+    /// Re-reads the tree from disk, preserving which directories were
+    /// expanded and which entry was selected where possible.
+    fn refresh(&mut self) {
+        let selected_path = self.entries.get(self.selected).map(|entry| entry.path.clone());
+        let expanded: Vec<PathBuf> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.expanded)
+            .map(|entry| entry.path.clone())
+            .collect();
+
+        self.entries = Self::read_dir(&self.root, 0);
+        for path in expanded {
+            if let Some(idx) = self.entries.iter().position(|entry| entry.path == path) {
+                self.expand(idx);
+            }
+        }
+        if let Some(path) = selected_path {
+            if let Some(idx) = self.entries.iter().position(|entry| entry.path == path) {
+                self.selected = idx;
+            }
+        }
+        if self.selected >= self.entries.len() {
+            self.selected = self.entries.len().saturating_sub(1);
+        }
+    }
+
+    fn expand(&mut self, idx: usize) {
+        let (is_dir, expanded, depth, path) = {
+            let entry = &self.entries[idx];
+            (entry.is_dir, entry.expanded, entry.depth, entry.path.clone())
+        };
+        if !is_dir || expanded {
+            return;
+        }
+        self.entries[idx].expanded = true;
+        let children = Self::read_dir(&path, depth + 1);
+        self.entries.splice(idx + 1..idx + 1, children);
+    }
+
+    fn collapse(&mut self, idx: usize) {
+        let entry = &mut self.entries[idx];
+        if !entry.is_dir || !entry.expanded {
+            return;
+        }
+        entry.expanded = false;
+        let depth = entry.depth;
+        let end = self.entries[idx + 1..]
+            .iter()
+            .position(|entry| entry.depth <= depth)
+            .map(|offset| idx + 1 + offset)
+            .unwrap_or(self.entries.len());
+        self.entries.drain(idx + 1..end);
+    }
+
+    fn toggle(&mut self, idx: usize) {
+        if self.entries[idx].expanded {
+            self.collapse(idx);
+        } else {
+            self.expand(idx);
+        }
+    }
+
+    /// `+` for a file with no VCS diff base (untracked, or not inside any
+    /// repository), `~` for one that differs from its diff base, `None` for
+    /// directories, unmodified files and files that can't be read.
+    fn vcs_marker(entry: &Entry, editor: &Editor) -> Option<&'static str> {
+        if entry.is_dir {
+            return None;
+        }
+        match editor.diff_providers.get_diff_base(&entry.path) {
+            Some(base) => {
+                let current = fs::read(&entry.path).ok()?;
+                (current != base).then_some("~")
+            }
+            None => Some("+"),
+        }
+    }
+
+    fn close() -> Callback {
+        Box::new(|compositor, _| {
+            compositor.remove(ID);
+        })
+    }
+
+    fn open_selected(&self, action: Action, cx: &mut Context) -> EventResult {
+        let Some(entry) = self.entries.get(self.selected) else {
+            return EventResult::Consumed(None);
+        };
+        if entry.is_dir {
+            return EventResult::Consumed(None);
+        }
+        if let Err(err) = cx.editor.open(&entry.path, action) {
+            cx.editor
+                .set_error(format!("unable to open \"{}\": {err}", entry.path.display()));
+        }
+        EventResult::Consumed(Some(Self::close()))
+    }
+
+    fn prompt_create(&self) -> Callback {
+        let dir = match self.entries.get(self.selected) {
+            Some(entry) if entry.is_dir => entry.path.clone(),
+            Some(entry) => entry.path.parent().map_or_else(|| self.root.clone(), Path::to_path_buf),
+            None => self.root.clone(),
+        };
+        Box::new(move |compositor, _| {
+            let prompt = Prompt::new(
+                "new file: ".into(),
+                None,
+                crate::ui::completers::none,
+                move |cx: &mut Context, input: &str, event: PromptEvent| {
+                    if event != PromptEvent::Validate || input.is_empty() {
+                        return;
+                    }
+                    let path = dir.join(input);
+                    if let Some(parent) = path.parent() {
+                        let _ = fs::create_dir_all(parent);
+                    }
+                    if let Err(err) = fs::File::create(&path) {
+                        cx.editor
+                            .set_error(format!("unable to create \"{}\": {err}", path.display()));
+                        return;
+                    }
+                    let callback = crate::job::Callback::EditorCompositor(Box::new(
+                        |_editor, compositor| {
+                            if let Some(tree) = compositor.find_id::<FileTree>(ID) {
+                                tree.refresh();
+                            }
+                        },
+                    ));
+                    cx.jobs.callback(async move { Ok(callback) });
+                },
+            );
+            compositor.push(Box::new(prompt));
+        })
+    }
+
+    fn prompt_rename(&self) -> Option<Callback> {
+        let entry = self.entries.get(self.selected)?;
+        let path = entry.path.clone();
+        let name = entry.path.file_name()?.to_string_lossy().into_owned();
+        Some(Box::new(move |compositor, cx| {
+            let mut prompt = Prompt::new(
+                "rename to: ".into(),
+                None,
+                crate::ui::completers::none,
+                move |cx: &mut Context, input: &str, event: PromptEvent| {
+                    if event != PromptEvent::Validate || input.is_empty() {
+                        return;
+                    }
+                    let dest = path.with_file_name(input);
+                    if let Err(err) = fs::rename(&path, &dest) {
+                        cx.editor
+                            .set_error(format!("unable to rename \"{}\": {err}", path.display()));
+                        return;
+                    }
+                    let callback = crate::job::Callback::EditorCompositor(Box::new(
+                        |_editor, compositor| {
+                            if let Some(tree) = compositor.find_id::<FileTree>(ID) {
+                                tree.refresh();
+                            }
+                        },
+                    ));
+                    cx.jobs.callback(async move { Ok(callback) });
+                },
+            );
+            prompt.set_line(name, cx.editor);
+            compositor.push(Box::new(prompt));
+        }))
+    }
+}
+
+impl Component for FileTree {
+    fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
+        let key = match event {
+            Event::Key(event) => *event,
+            _ => return EventResult::Ignored(None),
+        };
+
+        match key {
+            key!(Esc) | key!('q') => EventResult::Consumed(Some(Self::close())),
+            key!(Up) | key!('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                EventResult::Consumed(None)
+            }
+            key!(Down) | key!('j') => {
+                if self.selected + 1 < self.entries.len() {
+                    self.selected += 1;
+                }
+                EventResult::Consumed(None)
+            }
+            key!(Right) | key!('l') => {
+                self.expand(self.selected);
+                EventResult::Consumed(None)
+            }
+            key!(Left) | key!('h') => {
+                self.collapse(self.selected);
+                EventResult::Consumed(None)
+            }
+            key!(Enter) => {
+                let is_dir = self.entries.get(self.selected).map_or(false, |entry| entry.is_dir);
+                if is_dir {
+                    self.toggle(self.selected);
+                    EventResult::Consumed(None)
+                } else {
+                    self.open_selected(Action::Replace, cx)
+                }
+            }
+            key!('v') => self.open_selected(Action::VerticalSplit, cx),
+            key!('s') => self.open_selected(Action::HorizontalSplit, cx),
+            key!('d') => {
+                if let Some(entry) = self.entries.get(self.selected) {
+                    let path = entry.path.clone();
+                    if cx.editor.config().trash_delete {
+                        match helix_loader::trash::move_to_trash(&path) {
+                            Ok(trashed_to) => {
+                                self.refresh();
+                                cx.editor.set_status(format!(
+                                    "moved \"{}\" to \"{}\"",
+                                    path.display(),
+                                    trashed_to.display()
+                                ));
+                            }
+                            Err(err) => cx.editor.set_error(format!(
+                                "unable to delete \"{}\": {err}",
+                                path.display()
+                            )),
+                        }
+                    } else {
+                        let result = if path.is_dir() {
+                            fs::remove_dir_all(&path)
+                        } else {
+                            fs::remove_file(&path)
+                        };
+                        match result {
+                            Ok(()) => self.refresh(),
+                            Err(err) => cx.editor.set_error(format!(
+                                "unable to delete \"{}\": {err}",
+                                path.display()
+                            )),
+                        }
+                    }
+                }
+                EventResult::Consumed(None)
+            }
+            ctrl!('n') => EventResult::Consumed(Some(self.prompt_create())),
+            key!('r') => EventResult::Consumed(self.prompt_rename()),
+            _ => EventResult::Consumed(None),
+        }
+    }
+
+    fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
+        let width = self.width.min(area.width);
+        let panel = Rect::new(area.x, area.y, width, area.height.saturating_sub(1));
+
+        let background = cx.editor.theme.get("ui.background");
+        let text_style = cx.editor.theme.get("ui.text");
+        let selected_style = cx.editor.theme.get("ui.text.focus");
+        let added_style = cx.editor.theme.get("diff.plus");
+        let modified_style = cx.editor.theme.get("diff.delta");
+
+        surface.clear_with(panel, background);
+        let block = Block::default().borders(Borders::ALL).title(" Explorer ");
+        let inner = block.inner(panel);
+        block.render(panel, surface);
+
+        for (row, entry) in self.entries.iter().enumerate().take(inner.height as usize) {
+            let y = inner.y + row as u16;
+            let indent = "  ".repeat(entry.depth);
+            let marker = if entry.is_dir {
+                if entry.expanded { "\u{25be} " } else { "\u{25b8} " }
+            } else {
+                "  "
+            };
+            let name = entry
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let suffix = match Self::vcs_marker(entry, cx.editor) {
+                Some("~") => " ~",
+                Some(_) => " +",
+                None => "",
+            };
+            let line = format!("{indent}{marker}{name}{suffix}");
+            let style = if row == self.selected {
+                selected_style
+            } else if suffix == " ~" {
+                modified_style
+            } else if suffix == " +" {
+                added_style
+            } else {
+                text_style
+            };
+            surface.set_stringn(inner.x, y, &line, inner.width as usize, style);
+        }
+    }
+
+    fn id(&self) -> Option<&'static str> {
+        Some(ID)
+    }
+}
+
+pub(crate) const ID: &str = "file-tree";
@@ -7,7 +7,10 @@
 use helix_core::fuzzy::MATCHER;
 use nucleo::pattern::{Atom, AtomKind, CaseMatching};
 use nucleo::{Config, Utf32Str};
-use tui::{buffer::Buffer as Surface, widgets::Table};
+use tui::{
+    buffer::Buffer as Surface,
+    widgets::{Scrollbar, Table, Widget},
+};
 
 pub use tui::widgets::{Cell, Row};
 
@@ -340,10 +343,6 @@ fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
 
         let win_height = area.height as usize;
 
-        const fn div_ceil(a: usize, b: usize) -> usize {
-            (a + b - 1) / b
-        }
-
         let rows = options
             .iter()
             .map(|option| option.format(&self.editor_data));
@@ -376,28 +375,17 @@ const fn div_ceil(a: usize, b: usize) -> usize {
             right.set_style(selected);
         }
 
-        let fits = len <= win_height;
-
         let scroll_style = theme.get("ui.menu.scroll");
-        if !fits {
-            let scroll_height = div_ceil(win_height.pow(2), len).min(win_height);
-            let scroll_line = (win_height - scroll_height) * scroll
-                / std::cmp::max(1, len.saturating_sub(win_height));
-
-            let mut cell;
-            for i in 0..win_height {
-                cell = &mut surface[(area.right() - 1, area.top() + i as u16)];
-
-                cell.set_symbol("▐"); // right half block
-
-                if scroll_line <= i && i < scroll_line + scroll_height {
-                    // Draw scroll thumb
-                    cell.set_fg(scroll_style.fg.unwrap_or(helix_view::theme::Color::Reset));
-                } else {
-                    // Draw scroll track
-                    cell.set_fg(scroll_style.bg.unwrap_or(helix_view::theme::Color::Reset));
-                }
-            }
-        }
+        Scrollbar::new(len, win_height, scroll)
+            .thumb_style(helix_view::graphics::Style::default().fg(
+                scroll_style.fg.unwrap_or(helix_view::theme::Color::Reset),
+            ))
+            .track_style(helix_view::graphics::Style::default().fg(
+                scroll_style.bg.unwrap_or(helix_view::theme::Color::Reset),
+            ))
+            .render(
+                Rect::new(area.right() - 1, area.top(), 1, win_height as u16),
+                surface,
+            );
     }
 }
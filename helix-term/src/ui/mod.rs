@@ -1,6 +1,9 @@
+pub mod buffer_close_review;
 mod completion;
+mod dashboard;
 mod document;
 pub(crate) mod editor;
+pub mod file_tree;
 mod info;
 pub mod lsp;
 mod markdown;
@@ -11,27 +14,34 @@
 mod prompt;
 mod spinner;
 mod statusline;
+pub mod terminal;
 mod text;
 mod text_decorations;
 
 use crate::compositor::{Component, Compositor};
 use crate::filter_picker_entry;
 use crate::job::{self, Callback};
+use crate::{ctrl, key};
+pub use buffer_close_review::{BufferCloseReview, PendingQuit};
 pub use completion::{Completion, CompletionItem};
+pub use dashboard::Dashboard;
 pub use editor::EditorView;
+pub use file_tree::FileTree;
 pub use markdown::Markdown;
 pub use menu::Menu;
-pub use picker::{Column as PickerColumn, DynamicPicker, FileLocation, Picker};
+pub use picker::{Column as PickerColumn, DynamicPicker, FileLocation, Injector, Picker};
 pub use popup::Popup;
-pub use prompt::{Prompt, PromptEvent};
+pub use prompt::{CompletionDirection, Prompt, PromptEvent};
 pub use spinner::{ProgressSpinners, Spinner};
+pub use terminal::Terminal;
 pub use text::Text;
 
 use helix_core::regex::Regex;
 use helix_core::regex::RegexBuilder;
+use helix_view::editor::Action;
 use helix_view::Editor;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub fn prompt(
     cx: &mut crate::commands::Context,
@@ -156,28 +166,46 @@ pub fn regex_prompt(
     cx.push_layer(Box::new(prompt));
 }
 
-type FilePicker = Picker<PathBuf, PathBuf>;
+/// Data made available to the file picker's columns: the crawl root (paths
+/// are displayed relative to it) and whether the icon column, if present,
+/// should use Nerd Font glyphs rather than plain text.
+pub struct FilePickerData {
+    pub root: PathBuf,
+    pub icons_nerd_font: bool,
+}
 
-pub fn file_picker(root: PathBuf, config: &helix_view::editor::Config) -> FilePicker {
+type FilePicker = Picker<PathBuf, FilePickerData>;
+
+/// Crawls `root` for files matching `config.file_picker`'s filters and feeds
+/// them into `injector`, ranking the files that fit in an initial 30ms batch
+/// by frecency and streaming the rest in from a background thread if the
+/// tree is too large to finish crawling in that window. Used both to
+/// populate a fresh [`file_picker`] and, via [`Picker::with_restart`], to
+/// re-run the crawl when a closed picker is reopened through `last_picker`.
+fn spawn_file_picker_crawl(
+    root: PathBuf,
+    config: &helix_view::editor::FilePickerConfig,
+    injector: Injector<PathBuf, FilePickerData>,
+) {
     use ignore::{types::TypesBuilder, WalkBuilder};
     use std::time::Instant;
 
     let now = Instant::now();
 
-    let dedup_symlinks = config.file_picker.deduplicate_links;
+    let dedup_symlinks = config.deduplicate_links;
     let absolute_root = root.canonicalize().unwrap_or_else(|_| root.clone());
 
     let mut walk_builder = WalkBuilder::new(&root);
     walk_builder
-        .hidden(config.file_picker.hidden)
-        .parents(config.file_picker.parents)
-        .ignore(config.file_picker.ignore)
-        .follow_links(config.file_picker.follow_symlinks)
-        .git_ignore(config.file_picker.git_ignore)
-        .git_global(config.file_picker.git_global)
-        .git_exclude(config.file_picker.git_exclude)
+        .hidden(config.hidden)
+        .parents(config.parents)
+        .ignore(config.ignore)
+        .follow_links(config.follow_symlinks)
+        .git_ignore(config.git_ignore)
+        .git_global(config.git_global)
+        .git_exclude(config.git_exclude)
         .sort_by_file_name(|name1, name2| name1.cmp(name2))
-        .max_depth(config.file_picker.max_depth)
+        .max_depth(config.max_depth)
         .filter_entry(move |entry| filter_picker_entry(entry, &absolute_root, dedup_symlinks));
 
     // We want to exclude files that the editor can't handle yet
@@ -202,20 +230,75 @@ pub fn file_picker(root: PathBuf, config: &helix_view::editor::Config) -> FilePi
     });
     log::debug!("file_picker init {:?}", Instant::now().duration_since(now));
 
-    let columns = vec![PickerColumn::new(
+    let timeout = std::time::Instant::now() + std::time::Duration::from_millis(30);
+
+    // Gather the files that fit in the initial (non-streamed) batch and rank
+    // them by frecency so frequently/recently opened files bubble to the top
+    // when the picker's query is still empty. Files that only show up via the
+    // background stream (very large trees) keep the walker's own order --
+    // ranking those too would mean holding the whole tree in memory first,
+    // defeating the point of streaming.
+    let frecency = helix_loader::frecency::scores();
+    let mut hit_timeout = false;
+    let mut initial_batch = Vec::new();
+    for file in &mut files {
+        initial_batch.push(file);
+        if std::time::Instant::now() >= timeout {
+            hit_timeout = true;
+            break;
+        }
+    }
+    initial_batch.sort_by(|a, b| {
+        let score_a = frecency.get(a).copied().unwrap_or(0.0);
+        let score_b = frecency.get(b).copied().unwrap_or(0.0);
+        score_b.total_cmp(&score_a)
+    });
+    for file in initial_batch {
+        if injector.push(file).is_err() {
+            break;
+        }
+    }
+    if hit_timeout {
+        std::thread::spawn(move || {
+            for file in files {
+                if injector.push(file).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+pub fn file_picker(root: PathBuf, config: &helix_view::editor::Config) -> FilePicker {
+    let icons_enabled = config.picker.icons;
+    let mut columns = Vec::new();
+    if icons_enabled {
+        columns.push(PickerColumn::new(
+            "icon",
+            |item: &PathBuf, data: &FilePickerData| {
+                helix_view::icons::icon_for(item, false, data.icons_nerd_font).into()
+            },
+        ));
+    }
+    columns.push(PickerColumn::new(
         "path",
-        |item: &PathBuf, root: &PathBuf| {
-            item.strip_prefix(root)
+        |item: &PathBuf, data: &FilePickerData| {
+            item.strip_prefix(&data.root)
                 .unwrap_or(item)
                 .to_string_lossy()
                 .into()
         },
-    )];
+    ));
+    let default_column = usize::from(icons_enabled);
+
     let picker = Picker::new(
         columns,
-        0,
+        default_column,
         Vec::new(),
-        root,
+        FilePickerData {
+            root: root.clone(),
+            icons_nerd_font: config.picker.icons_nerd_font,
+        },
         move |cx, path: &PathBuf, action| {
             if let Err(e) = cx.editor.open(path, action) {
                 let err = if let Some(err) = e.source() {
@@ -227,30 +310,168 @@ pub fn file_picker(root: PathBuf, config: &helix_view::editor::Config) -> FilePi
             }
         },
     )
-    .with_preview(|_editor, path| Some((path.clone().into(), None)));
-    let injector = picker.injector();
-    let timeout = std::time::Instant::now() + std::time::Duration::from_millis(30);
+    .with_preview(|_editor, path| Some((path.clone().into(), None)))
+    .with_preview_cache_capacity(config.file_picker.preview_cache_size);
+    // Not `.with_frecency_id("file")`: the "path" column displays paths
+    // relative to `root` for readability, but `frecency::record_access` (and
+    // every other call site) keys on the absolute path, so the two wouldn't
+    // agree on what to boost. The crawl above already ranks the query-less
+    // list by frecency (`spawn_file_picker_crawl`); mid-query blending with
+    // the match score is left to pickers, like the command palette, whose
+    // display text is its own stable key.
+
+    spawn_file_picker_crawl(root.clone(), &config.file_picker, picker.injector());
+
+    picker.with_restart(move |editor, injector| {
+        spawn_file_picker_crawl(root.clone(), &editor.config().file_picker, injector.clone());
+    })
+}
 
-    let mut hit_timeout = false;
-    for file in &mut files {
-        if injector.push(file).is_err() {
-            break;
-        }
-        if std::time::Instant::now() >= timeout {
-            hit_timeout = true;
-            break;
-        }
+/// A single entry rendered by [`directory_picker`], either a real file
+/// system entry or the synthesized `..` entry used to move up a directory.
+#[derive(Debug, Clone)]
+pub struct DirectoryEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+    pub size: Option<u64>,
+}
+
+fn read_directory_entries(dir: &Path) -> Vec<DirectoryEntry> {
+    let mut entries: Vec<DirectoryEntry> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let metadata = entry.metadata().ok()?;
+            Some(DirectoryEntry {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                path: entry.path(),
+                is_dir: metadata.is_dir(),
+                size: (!metadata.is_dir()).then_some(metadata.len()),
+            })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.name.cmp(&b.name),
+    });
+
+    // Always expose a way back up so `Backspace` (which acts on the
+    // currently selected row) still works in an empty directory.
+    if let Some(parent) = dir.parent() {
+        entries.insert(
+            0,
+            DirectoryEntry {
+                name: "..".to_string(),
+                path: parent.to_path_buf(),
+                is_dir: true,
+                size: None,
+            },
+        );
     }
-    if hit_timeout {
-        std::thread::spawn(move || {
-            for file in files {
-                if injector.push(file).is_err() {
-                    break;
-                }
-            }
-        });
+
+    entries
+}
+
+fn format_entry_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
     }
-    picker
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+type DirectoryPicker = Picker<DirectoryEntry, ()>;
+
+/// A picker over the entries of `dir`, styled as a lightweight file browser:
+/// `Enter` opens files and descends into subdirectories, `Backspace` goes up
+/// to the parent directory, and `Ctrl-n` prompts for a name and opens a new
+/// buffer for that file in the current directory; nothing is written to disk
+/// until the buffer is saved.
+pub fn directory_picker(dir: PathBuf) -> DirectoryPicker {
+    let entries = read_directory_entries(&dir);
+
+    let columns = vec![
+        PickerColumn::new("name", |entry: &DirectoryEntry, _: &()| {
+            if entry.is_dir {
+                format!("{}/", entry.name).into()
+            } else {
+                entry.name.clone().into()
+            }
+        }),
+        PickerColumn::new("type", |entry: &DirectoryEntry, _: &()| {
+            (if entry.is_dir { "dir" } else { "file" }).into()
+        }),
+        PickerColumn::new("size", |entry: &DirectoryEntry, _: &()| {
+            entry.size.map(format_entry_size).unwrap_or_default().into()
+        }),
+    ];
+
+    let up_dir = dir.clone();
+    let new_file_dir = dir;
+
+    Picker::new(
+        columns,
+        0,
+        entries,
+        (),
+        |cx, entry: &DirectoryEntry, action| {
+            if entry.is_dir {
+                let next_dir = entry.path.clone();
+                let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+                    compositor.replace_or_push(
+                        picker::ID,
+                        overlay::overlaid(directory_picker(next_dir)),
+                    );
+                }));
+                cx.jobs.callback(async move { Ok(callback) });
+            } else if let Err(err) = cx.editor.open(&entry.path, action) {
+                cx.editor
+                    .set_error(format!("unable to open \"{}\": {err}", entry.path.display()));
+            }
+        },
+    )
+    .with_action(key!(Backspace), "go up a directory", move |cx, _entry| {
+        let Some(parent) = up_dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+            compositor.replace_or_push(picker::ID, overlay::overlaid(directory_picker(parent)));
+        }));
+        cx.jobs.callback(async move { Ok(callback) });
+    })
+    .with_action(ctrl!('n'), "create a new file", move |cx, _entry| {
+        let dir = new_file_dir.clone();
+        let callback = Callback::EditorCompositor(Box::new(move |_editor, compositor| {
+            let prompt = Prompt::new(
+                "new file:".into(),
+                None,
+                completers::none,
+                move |cx: &mut crate::compositor::Context, input: &str, event: PromptEvent| {
+                    if event != PromptEvent::Validate || input.is_empty() {
+                        return;
+                    }
+                    let path = dir.join(input);
+                    if let Err(err) = cx.editor.open(&path, Action::Replace) {
+                        cx.editor
+                            .set_error(format!("unable to create \"{}\": {err}", path.display()));
+                    }
+                },
+            );
+            compositor.push(Box::new(prompt));
+        }));
+        cx.jobs.callback(async move { Ok(callback) });
+    })
 }
 
 pub mod completers {
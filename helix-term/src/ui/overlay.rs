@@ -73,4 +73,8 @@ fn cursor(&self, area: Rect, ctx: &Editor) -> (Option<Position>, CursorKind) {
     fn id(&self) -> Option<&'static str> {
         self.content.id()
     }
+
+    fn on_reopen(&mut self, editor: &mut Editor) {
+        self.content.on_reopen(editor)
+    }
 }
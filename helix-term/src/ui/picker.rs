@@ -2,7 +2,7 @@
     alt,
     compositor::{self, Component, Compositor, Context, Event, EventResult},
     ctrl,
-    job::{dispatch_blocking, Callback},
+    job::{dispatch_blocking, Callback, Jobs},
     key, shift,
     ui::{
         self,
@@ -12,22 +12,24 @@
     },
 };
 use futures_util::{future::BoxFuture, FutureExt};
+use helix_core::fuzzy::{fuzzy_matching_config, FuzzyCaseMatching};
 use helix_event::AsyncHook;
 use nucleo::pattern::CaseMatching;
-use nucleo::{Config, Nucleo, Utf32String};
+use nucleo::{Nucleo, Utf32String};
 use tokio::time::Instant;
 use tui::{
     buffer::Buffer as Surface,
+    graphics_protocol::{self, GraphicsProtocol},
     layout::Constraint,
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+    widgets::{Block, BorderType, Borders, Cell, Row, Scrollbar, Table},
 };
 
 use tui::widgets::Widget;
 
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     io::Read,
     path::PathBuf,
     sync::{
@@ -37,15 +39,24 @@
     time::Duration,
 };
 
-use crate::ui::{Prompt, PromptEvent};
+use crate::ui::{CompletionDirection, Prompt, PromptEvent};
+
+/// Register used to persist submitted picker queries across picker
+/// invocations so `ctrl-p`/`ctrl-n` can recall them when the prompt is
+/// empty. Uses a control character so it can't collide with (or be
+/// selected by) any user-facing named register.
+const QUERY_HISTORY_REGISTER: char = '\u{1}';
 use helix_core::{
     char_idx_at_visual_offset, fuzzy::MATCHER, movement::Direction,
     text_annotations::TextAnnotations, unicode::segmentation::UnicodeSegmentation, Position,
     Syntax,
 };
 use helix_view::{
+    document,
     editor::Action,
     graphics::{CursorKind, Margin, Modifier, Rect},
+    input::KeyEvent,
+    quickfix::QuickfixEntry,
     theme::Style,
     view::ViewPosition,
     Document, DocumentId, Editor,
@@ -57,6 +68,11 @@
 pub const MIN_AREA_WIDTH_FOR_PREVIEW: u16 = 72;
 /// Biggest file size to preview in bytes
 pub const MAX_FILE_SIZE_FOR_PREVIEW: u64 = 10 * 1024 * 1024;
+/// Number of leading bytes read from a binary file to render as a hex dump.
+pub const HEX_PREVIEW_LEN: usize = 4 * 1024;
+/// Extensions previewed as images (via [`tui::graphics_protocol`]) rather
+/// than as text or a hex dump.
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "webp"];
 
 #[derive(PartialEq, Eq, Hash)]
 pub enum PathOrId {
@@ -86,6 +102,59 @@ fn from(v: DocumentId) -> Self {
     }
 }
 
+/// Case-matching behavior applied to the picker's query, cycled at runtime
+/// with `Alt-c`. This only switches case sensitivity; how each atom of the
+/// query matches (fuzzy, or the `'`/`^`/`$`/`!` forms nucleo's pattern
+/// syntax provides) is still decided by the query text itself.
+///
+/// The initial mode a picker opens in is seeded from
+/// `editor.fuzzy-matching.case-matching` (see `From<FuzzyCaseMatching>`
+/// below); `Alt-c` still cycles through all three regardless of that
+/// default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchMode {
+    Smart,
+    Insensitive,
+    Sensitive,
+}
+
+impl MatchMode {
+    fn next(self) -> Self {
+        match self {
+            MatchMode::Smart => MatchMode::Insensitive,
+            MatchMode::Insensitive => MatchMode::Sensitive,
+            MatchMode::Sensitive => MatchMode::Smart,
+        }
+    }
+
+    fn case_matching(self) -> CaseMatching {
+        match self {
+            MatchMode::Smart => CaseMatching::Smart,
+            MatchMode::Insensitive => CaseMatching::Ignore,
+            MatchMode::Sensitive => CaseMatching::Respect,
+        }
+    }
+
+    /// Short label shown next to the match count when not the default.
+    fn label(self) -> Option<&'static str> {
+        match self {
+            MatchMode::Smart => None,
+            MatchMode::Insensitive => Some("ignore-case"),
+            MatchMode::Sensitive => Some("match-case"),
+        }
+    }
+}
+
+impl From<FuzzyCaseMatching> for MatchMode {
+    fn from(case_matching: FuzzyCaseMatching) -> Self {
+        match case_matching {
+            FuzzyCaseMatching::Smart => MatchMode::Smart,
+            FuzzyCaseMatching::Ignore => MatchMode::Insensitive,
+            FuzzyCaseMatching::Respect => MatchMode::Sensitive,
+        }
+    }
+}
+
 type FileCallback<T> = Box<dyn Fn(&Editor, &T) -> Option<FileLocation>>;
 
 /// File path and range of lines (used to align and highlight lines)
@@ -93,7 +162,33 @@ fn from(v: DocumentId) -> Self {
 
 pub enum CachedPreview {
     Document(Box<Document>),
-    Binary,
+    /// The leading `HEX_PREVIEW_LEN` bytes of a file whose content isn't text.
+    Binary(Vec<u8>),
+    /// The raw, still-encoded contents of an image file, along with the
+    /// graphics protocol detected for the current terminal. `None` means no
+    /// supported protocol was detected, in which case the preview falls
+    /// back to a metadata summary instead of the image itself.
+    Image(Vec<u8>, Option<GraphicsProtocol>),
+    LargeFile,
+    NotFound,
+    /// A placeholder inserted by `get_preview` while the file is read on a
+    /// background task, so slow disks/NFS don't block rendering. Replaced
+    /// in the cache once the task's `EditorCompositor` callback runs.
+    Loading,
+}
+
+/// The `Send`-safe result of reading a preview's file on a background task.
+/// Unlike [`CachedPreview`], this never holds a [`Document`], since `Document`
+/// isn't `Send` (it holds `Rc` fields). The `Text` case is turned into a
+/// `CachedPreview::Document` once it reaches the main thread.
+enum RawPreview {
+    Text {
+        rope: helix_core::Rope,
+        encoding: &'static helix_core::encoding::Encoding,
+        has_bom: bool,
+    },
+    Binary(Vec<u8>),
+    Image(Vec<u8>),
     LargeFile,
     NotFound,
 }
@@ -120,14 +215,85 @@ fn placeholder(&self) -> &str {
             Self::EditorDocument(_) => "<Invalid file location>",
             Self::Cached(preview) => match preview {
                 CachedPreview::Document(_) => "<Invalid file location>",
-                CachedPreview::Binary => "<Binary file>",
+                CachedPreview::Binary(_) => "<Binary file>",
+                CachedPreview::Image(..) => "<Image file>",
                 CachedPreview::LargeFile => "<File too large to preview>",
                 CachedPreview::NotFound => "<File not found>",
+                CachedPreview::Loading => "<Loading…>",
             },
         }
     }
 }
 
+/// The number of preview entries kept by a picker's [`LruCache`] when the
+/// caller doesn't override it with [`Picker::with_preview_cache_capacity`].
+const DEFAULT_PREVIEW_CACHE_CAPACITY: usize = 100;
+
+/// A `HashMap` bounded to `capacity` entries, evicting the least-recently
+/// used entry (by `get`/`get_mut`/`insert`) once full. Used for
+/// [`Picker::preview_cache`] so long picker sessions over large repos don't
+/// hold every previewed document in memory forever.
+struct LruCache<K, V> {
+    map: HashMap<K, V>,
+    // Most-recently-used key is at the back.
+    order: VecDeque<K>,
+    capacity: usize,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + std::hash::Hash + Clone,
+{
+    fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.order.len() > self.capacity {
+            if let Some(key) = self.order.pop_front() {
+                self.map.remove(&key);
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos).expect("index in bounds");
+            self.order.push_back(key);
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.touch(key);
+        self.map.get(key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.touch(key);
+        self.map.get_mut(key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> &V {
+        if !self.map.contains_key(&key) {
+            if self.order.len() >= self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.map.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        } else {
+            self.touch(&key);
+        }
+        self.map.insert(key.clone(), value);
+        self.map.get(&key).expect("just inserted")
+    }
+}
+
 fn inject_nucleo_item<T, D>(
     injector: &nucleo::Injector<T>,
     columns: &[Column<T, D>],
@@ -247,21 +413,57 @@ pub struct Picker<T: 'static + Send + Sync, D: 'static> {
     callback_fn: PickerCallback<T>,
 
     pub truncate_start: bool,
-    /// Caches paths to documents
-    preview_cache: HashMap<PathBuf, CachedPreview>,
-    read_buffer: Vec<u8>,
+    /// Caches paths to documents, bounded to avoid unbounded growth over
+    /// long picker sessions (e.g. browsing many previews in global search).
+    preview_cache: LruCache<PathBuf, CachedPreview>,
     /// Given an item in the picker, return the file path and line number to display.
     file_fn: Option<FileCallback<T>>,
 
+    /// Additional per-item actions beyond the built-in open/split callbacks,
+    /// triggered by a dedicated key and invoked with the selected item.
+    custom_actions: Vec<(KeyEvent, &'static str, CustomPickerAction<T>)>,
+
+    /// Column matched items are currently sorted by, if any. `None` means
+    /// the picker uses nucleo's own fuzzy-match order.
+    sort_column: Option<usize>,
+    sort_ascending: bool,
+    /// Maps a display cursor position to the index nucleo knows the item by.
+    /// Empty when `sort_column` is `None`, in which case the cursor *is*
+    /// nucleo's index.
+    sort_order: Vec<u32>,
+    /// Set whenever the sort column, direction, or match set changes so the
+    /// next render recomputes `sort_order`.
+    sort_order_dirty: bool,
+
+    /// Identifies this picker to [`helix_loader::frecency`], e.g. `"file"` or
+    /// `"command"`. `None` (the default) means accepted items aren't
+    /// recorded and matches aren't re-ranked by frecency, which is the right
+    /// choice for pickers whose items aren't stable across invocations (line
+    /// numbers, diagnostics, ...). Set with [`Picker::with_frecency_id`].
+    frecency_id: Option<&'static str>,
+
+    /// Case-matching behavior for the query, cycled with `Alt-c`.
+    match_mode: MatchMode,
+
+    /// Re-populates the picker from scratch, e.g. re-running an FS crawl.
+    /// Invoked by [`Component::on_reopen`] when the picker is reactivated
+    /// after being closed and stashed as `last_picker`, since closing a
+    /// streaming picker bumps `version` and that stops any in-flight
+    /// injector from pushing further items -- without this the reopened
+    /// picker would just be frozen with whatever it had collected so far.
+    restart_fn: Option<Box<dyn FnMut(&mut Editor, &Injector<T, D>)>>,
+
     pub tmp_running: bool,
 }
 
+type CustomPickerAction<T> = Box<dyn Fn(&mut Context, &T)>;
+
 impl<T: 'static + Send + Sync, D: 'static + Send + Sync> Picker<T, D> {
     pub fn stream(columns: Vec<Column<T, D>>, editor_data: D) -> (Nucleo<T>, Injector<T, D>) {
         let matcher_columns = columns.iter().filter(|col| col.filter).count() as u32;
         assert!(matcher_columns > 0);
         let matcher = Nucleo::new(
-            Config::DEFAULT,
+            fuzzy_matching_config().nucleo_config(false),
             Arc::new(helix_event::request_redraw),
             None,
             matcher_columns,
@@ -286,7 +488,7 @@ pub fn new(
         let matcher_columns = columns.iter().filter(|col| col.filter).count() as u32;
         assert!(matcher_columns > 0);
         let matcher = Nucleo::new(
-            Config::DEFAULT,
+            fuzzy_matching_config().nucleo_config(false),
             Arc::new(helix_event::request_redraw),
             None,
             matcher_columns,
@@ -333,7 +535,7 @@ fn with(
 
         let prompt = Prompt::new(
             "".into(),
-            None,
+            Some(QUERY_HISTORY_REGISTER),
             ui::completers::none,
             |_editor: &mut Context, _pattern: &str, _event: PromptEvent| {},
         );
@@ -359,13 +561,40 @@ fn with(
             callback_fn: Box::new(callback_fn),
             completion_height: 0,
             widths,
-            preview_cache: HashMap::new(),
-            read_buffer: Vec::with_capacity(1024),
+            preview_cache: LruCache::new(DEFAULT_PREVIEW_CACHE_CAPACITY),
             file_fn: None,
+            custom_actions: Vec::new(),
+            sort_column: None,
+            sort_ascending: true,
+            sort_order: Vec::new(),
+            sort_order_dirty: false,
+            frecency_id: None,
+            match_mode: MatchMode::from(fuzzy_matching_config().case_matching),
+            restart_fn: None,
             tmp_running: false,
         }
     }
 
+    /// Registers a callback that repopulates the picker's items from
+    /// scratch. Called automatically when the picker is reopened via
+    /// `last_picker` -- see [`Self::restart_fn`].
+    pub fn with_restart(mut self, restart: impl FnMut(&mut Editor, &Injector<T, D>) + 'static) -> Self {
+        self.restart_fn = Some(Box::new(restart));
+        self
+    }
+
+    /// Register an additional action bound to `key`, invoked with the
+    /// currently selected item. Shown in the picker's help footer.
+    pub fn with_action(
+        mut self,
+        key: KeyEvent,
+        name: &'static str,
+        action: impl Fn(&mut Context, &T) + 'static,
+    ) -> Self {
+        self.custom_actions.push((key, name, Box::new(action)));
+        self
+    }
+
     pub fn injector(&self) -> Injector<T, D> {
         Injector {
             dst: self.matcher.injector(),
@@ -381,6 +610,18 @@ pub fn truncate_start(mut self, truncate_start: bool) -> Self {
         self
     }
 
+    /// Opts this picker into [`helix_loader::frecency`] tracking: items
+    /// accepted (opened, executed, ...) from it are recorded under `id` and
+    /// boost matching items of future invocations of pickers sharing the
+    /// same `id`, weighted by `editor.picker.frecency-weight`. `id` should
+    /// be stable across picker invocations for the same kind of picker
+    /// (e.g. `"file"`, `"command"`) since it's how past accepts are looked
+    /// back up.
+    pub fn with_frecency_id(mut self, id: &'static str) -> Self {
+        self.frecency_id = Some(id);
+        self
+    }
+
     pub fn with_preview(
         mut self,
         preview_fn: impl Fn(&Editor, &T) -> Option<FileLocation> + 'static,
@@ -388,7 +629,15 @@ pub fn with_preview(
         self.file_fn = Some(Box::new(preview_fn));
         // assumption: if we have a preview we are matching paths... If this is ever
         // not true this could be a separate builder function
-        self.matcher.update_config(Config::DEFAULT.match_paths());
+        self.matcher
+            .update_config(fuzzy_matching_config().nucleo_config(true));
+        self
+    }
+
+    /// Overrides the number of entries kept in the preview cache (default
+    /// [`DEFAULT_PREVIEW_CACHE_CAPACITY`]).
+    pub fn with_preview_cache_capacity(mut self, capacity: usize) -> Self {
+        self.preview_cache.set_capacity(capacity);
         self
     }
 
@@ -441,12 +690,147 @@ pub fn to_end(&mut self) {
     }
 
     pub fn selection(&self) -> Option<&T> {
+        let index = self.resolve_index(self.cursor)?;
         self.matcher
             .snapshot()
-            .get_matched_item(self.cursor)
+            .get_matched_item(index)
             .map(|item| item.data)
     }
 
+    /// Builds a [`QuickfixEntry`] for every currently matched item that
+    /// resolves to a file location through this picker's [`Picker::with_preview`]
+    /// callback. Returns an empty list for pickers with no such callback.
+    fn to_quickfix_entries(&self, editor: &Editor) -> Vec<QuickfixEntry> {
+        let Some(file_fn) = self.file_fn.as_ref() else {
+            return Vec::new();
+        };
+
+        let snapshot = self.matcher.snapshot();
+        (0..snapshot.matched_item_count())
+            .filter_map(|i| snapshot.get_matched_item(i))
+            .filter_map(|item| {
+                let (path_or_id, range) = file_fn(editor, item.data)?;
+                let path = match path_or_id {
+                    PathOrId::Path(path) => path,
+                    PathOrId::Id(id) => editor.documents.get(&id)?.path()?.clone(),
+                };
+                Some(QuickfixEntry {
+                    path,
+                    line: range.map_or(0, |(start, _)| start),
+                })
+            })
+            .collect()
+    }
+
+    /// Cycles the picker between sorting matched items by nucleo's fuzzy
+    /// score (the default) and sorting by each column in turn, ascending
+    /// then descending, before returning to the default.
+    pub fn cycle_sort_column(&mut self) {
+        self.sort_column = match self.sort_column {
+            None => {
+                self.sort_ascending = true;
+                Some(0)
+            }
+            Some(column) if self.sort_ascending => {
+                self.sort_ascending = false;
+                Some(column)
+            }
+            Some(column) if column + 1 < self.columns.len() => {
+                self.sort_ascending = true;
+                Some(column + 1)
+            }
+            Some(_) => None,
+        };
+        self.sort_order_dirty = true;
+        self.cursor = 0;
+    }
+
+    /// Maps a display cursor position to the index nucleo knows the
+    /// corresponding item by, taking the current sort into account.
+    fn resolve_index(&self, cursor: u32) -> Option<u32> {
+        if self.sort_order.is_empty() {
+            Some(cursor)
+        } else {
+            self.sort_order.get(cursor as usize).copied()
+        }
+    }
+
+    /// Recomputes `sort_order` from the current matched items if a sort
+    /// column is set, or frecency ranking is active for this picker, and
+    /// the match set (or sort) has changed since the last render.
+    fn refresh_sort_order(&mut self, frecency_weight: u8) {
+        let frecency_active = self.frecency_id.is_some() && frecency_weight > 0;
+        if self.sort_column.is_none() && !frecency_active {
+            self.sort_order.clear();
+            self.sort_order_dirty = false;
+            return;
+        }
+        if !self.sort_order_dirty {
+            return;
+        }
+
+        let snapshot = self.matcher.snapshot();
+        let mut order: Vec<u32> = (0..snapshot.matched_item_count()).collect();
+        if let Some(column) = self.sort_column {
+            let column = &self.columns[column];
+            order.sort_by(|&a, &b| {
+                let item_a = snapshot.get_matched_item(a).expect("index in bounds");
+                let item_b = snapshot.get_matched_item(b).expect("index in bounds");
+                column
+                    .format_text(item_a.data, &self.editor_data)
+                    .cmp(&column.format_text(item_b.data, &self.editor_data))
+            });
+            if !self.sort_ascending {
+                order.reverse();
+            }
+        } else {
+            // Blend nucleo's fuzzy-match rank with frecency. Nucleo doesn't
+            // expose the raw match score through `Snapshot`, so an item's
+            // position in the incoming (best-match-first) order is used as
+            // a proxy: first place scores 1.0, last place scores near 0.0.
+            let picker_id = self.frecency_id.expect("checked above");
+            let frecency = helix_loader::frecency::scores_for(picker_id);
+            let max_frecency = frecency.values().copied().fold(0.0_f64, f64::max);
+            let weight = frecency_weight as f64 / 100.0;
+            let column = &self.columns[self.primary_column];
+            let match_count = order.len().max(1) as f64;
+
+            let mut scored: Vec<(u32, f64)> = order
+                .iter()
+                .enumerate()
+                .map(|(rank, &index)| {
+                    let item = snapshot.get_matched_item(index).expect("index in bounds");
+                    let match_score = 1.0 - rank as f64 / match_count;
+                    let frecency_score = if max_frecency > 0.0 {
+                        let key = column.format_text(item.data, &self.editor_data);
+                        frecency.get(key.as_ref()).copied().unwrap_or(0.0) / max_frecency
+                    } else {
+                        0.0
+                    };
+                    (index, (1.0 - weight) * match_score + weight * frecency_score)
+                })
+                .collect();
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+            for (slot, (index, _)) in order.iter_mut().zip(scored) {
+                *slot = index;
+            }
+        }
+        self.sort_order = order;
+        self.sort_order_dirty = false;
+    }
+
+    /// Records that `option` was just accepted (opened, executed, ...) from
+    /// this picker, if it's opted into frecency tracking.
+    fn record_frecency_accept(&self, option: &T) {
+        let Some(picker_id) = self.frecency_id else {
+            return;
+        };
+        let key = self.columns[self.primary_column].format_text(option, &self.editor_data);
+        if let Err(err) = helix_loader::frecency::record_accept(picker_id, &key) {
+            log::warn!("failed to record frecency for {picker_id} picker: {err}");
+        }
+    }
+
     fn primary_query(&self) -> &str {
         self.query
             .get(self.column_names[self.primary_column])
@@ -460,36 +844,82 @@ pub fn toggle_preview(&mut self) {
 
     fn prompt_handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
         if let EventResult::Consumed(_) = self.prompt.handle_event(event, cx) {
-            // TODO: better track how the pattern has changed
-            let line = self.prompt.line();
-            let new_query = parse_query(&self.column_names, self.primary_column, line);
-            if new_query != self.query {
-                for (i, column) in self
-                    .columns
-                    .iter()
-                    .filter(|column| column.filter)
-                    .enumerate()
-                {
-                    let pattern = new_query
-                        .get(column.name)
-                        .map(|pattern| pattern.as_str())
-                        .unwrap_or_default();
-                    let append = self
-                        .query
-                        .get(column.name)
-                        .map(|old_pattern| {
-                            pattern.starts_with(old_pattern) && !old_pattern.ends_with('\\')
-                        })
-                        .unwrap_or(false);
+            self.update_query();
+        }
+        EventResult::Consumed(None)
+    }
 
-                    self.matcher
-                        .pattern
-                        .reparse(i, pattern, CaseMatching::Smart, append);
-                }
-                self.query = new_query;
+    /// Records the current prompt line in the query history register, unless
+    /// it's empty or identical to the most recently stored entry.
+    fn save_query_to_history(&mut self, cx: &mut Context) {
+        let line = self.prompt.line();
+        if line.is_empty() {
+            return;
+        }
+        let last_item = cx
+            .editor
+            .registers
+            .first(QUERY_HISTORY_REGISTER, cx.editor)
+            .map(|entry| entry.to_string());
+        if last_item.as_deref() != Some(line.as_str()) {
+            if let Err(err) = cx
+                .editor
+                .registers
+                .push(QUERY_HISTORY_REGISTER, line.clone())
+            {
+                cx.editor.set_error(err.to_string());
             }
         }
-        EventResult::Consumed(None)
+    }
+
+    /// Re-parses the prompt's current line into the per-column query and, if
+    /// it changed, feeds the updated patterns to the matcher.
+    fn update_query(&mut self) {
+        // TODO: better track how the pattern has changed
+        let line = self.prompt.line();
+        let new_query = parse_query(&self.column_names, self.primary_column, line);
+        if new_query != self.query {
+            for (i, column) in self
+                .columns
+                .iter()
+                .filter(|column| column.filter)
+                .enumerate()
+            {
+                let pattern = new_query
+                    .get(column.name)
+                    .map(|pattern| pattern.as_str())
+                    .unwrap_or_default();
+                let append = self
+                    .query
+                    .get(column.name)
+                    .map(|old_pattern| {
+                        pattern.starts_with(old_pattern) && !old_pattern.ends_with('\\')
+                    })
+                    .unwrap_or(false);
+
+                self.matcher
+                    .pattern
+                    .reparse(i, pattern, self.match_mode.case_matching(), append);
+            }
+            self.query = new_query;
+        }
+    }
+
+    /// Advances to the next [`MatchMode`] and re-parses every column's
+    /// pattern under it, since the pattern text itself hasn't changed but
+    /// nucleo needs to rescore with the new case-matching behavior.
+    fn cycle_match_mode(&mut self) {
+        self.match_mode = self.match_mode.next();
+        for (i, column) in self.columns.iter().filter(|column| column.filter).enumerate() {
+            let pattern = self
+                .query
+                .get(column.name)
+                .map(|pattern| pattern.as_str())
+                .unwrap_or_default();
+            self.matcher
+                .pattern
+                .reparse(i, pattern, self.match_mode.case_matching(), false);
+        }
     }
 
     fn current_file(&self, editor: &Editor) -> Option<FileLocation> {
@@ -500,10 +930,17 @@ fn current_file(&self, editor: &Editor) -> Option<FileLocation> {
 
     /// Get (cached) preview for a given path. If a document corresponding
     /// to the path is already open in the editor, it is used instead.
+    ///
+    /// Reading a file's contents can block on slow disks/NFS, so on a cache
+    /// miss this inserts [`CachedPreview::Loading`] and reads the file on a
+    /// background task instead of blocking the render path; the task's
+    /// `EditorCompositor` callback swaps the real preview into the cache
+    /// once the read finishes.
     fn get_preview<'picker, 'editor>(
         &'picker mut self,
         path_or_id: PathOrId,
         editor: &'editor Editor,
+        jobs: &mut Jobs,
     ) -> Preview<'picker, 'editor> {
         match path_or_id {
             PathOrId::Path(path) => {
@@ -512,33 +949,108 @@ fn get_preview<'picker, 'editor>(
                     return Preview::EditorDocument(doc);
                 }
 
-                if self.preview_cache.contains_key(path) {
-                    return Preview::Cached(&self.preview_cache[path]);
+                if let Some(preview) = self.preview_cache.get(path) {
+                    return Preview::Cached(preview);
                 }
 
-                let data = std::fs::File::open(path).and_then(|file| {
-                    let metadata = file.metadata()?;
-                    // Read up to 1kb to detect the content type
-                    let n = file.take(1024).read_to_end(&mut self.read_buffer)?;
-                    let content_type = content_inspector::inspect(&self.read_buffer[..n]);
-                    self.read_buffer.clear();
-                    Ok((metadata, content_type))
-                });
-                let preview = data
-                    .map(
-                        |(metadata, content_type)| match (metadata.len(), content_type) {
-                            (_, content_inspector::ContentType::BINARY) => CachedPreview::Binary,
-                            (size, _) if size > MAX_FILE_SIZE_FOR_PREVIEW => {
-                                CachedPreview::LargeFile
+                let is_image = path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map_or(false, |ext| {
+                        IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str())
+                    });
+
+                let cache_key = path.to_owned();
+                let path = path.to_owned();
+                let config = editor.config.clone();
+                let job = tokio::task::spawn_blocking(move || {
+                    // `Document` holds `Rc` fields (e.g. inlay hint annotations),
+                    // so it isn't `Send` and can't be built on this background
+                    // task and shipped to the callback below. Do the (blocking)
+                    // file I/O and decoding here, using the same `Rope` decoding
+                    // that `Document::open` itself uses, and defer constructing
+                    // the `Document` to the callback running on the main thread.
+                    let raw = if is_image {
+                        std::fs::File::open(&path)
+                            .and_then(|file| {
+                                let mut bytes = Vec::new();
+                                file.take(MAX_FILE_SIZE_FOR_PREVIEW).read_to_end(&mut bytes)?;
+                                Ok(bytes)
+                            })
+                            .map(RawPreview::Image)
+                            .unwrap_or(RawPreview::NotFound)
+                    } else {
+                        let mut read_buffer = Vec::with_capacity(HEX_PREVIEW_LEN);
+                        let data = std::fs::File::open(&path).and_then(|file| {
+                            let metadata = file.metadata()?;
+                            // Read up to HEX_PREVIEW_LEN to detect the content type
+                            // and, if the file turns out to be binary, to render as
+                            // a hex dump.
+                            let n = file
+                                .take(HEX_PREVIEW_LEN as u64)
+                                .read_to_end(&mut read_buffer)?;
+                            let content_type = content_inspector::inspect(&read_buffer[..n]);
+                            Ok((metadata, content_type, n))
+                        });
+                        data.map(
+                            |(metadata, content_type, n)| match (metadata.len(), content_type) {
+                                (_, content_inspector::ContentType::BINARY) => {
+                                    RawPreview::Binary(read_buffer[..n].to_vec())
+                                }
+                                (size, _) if size > MAX_FILE_SIZE_FOR_PREVIEW => {
+                                    RawPreview::LargeFile
+                                }
+                                _ => std::fs::File::open(&path)
+                                    .map_err(anyhow::Error::from)
+                                    .and_then(|mut file| document::from_reader(&mut file, None))
+                                    .map(|(rope, encoding, has_bom)| RawPreview::Text {
+                                        rope,
+                                        encoding,
+                                        has_bom,
+                                    })
+                                    .unwrap_or(RawPreview::NotFound),
+                            },
+                        )
+                        .unwrap_or(RawPreview::NotFound)
+                    };
+
+                    let callback = move |_editor: &mut Editor, compositor: &mut Compositor| {
+                        let preview = match raw {
+                            RawPreview::Image(bytes) => {
+                                CachedPreview::Image(bytes, graphics_protocol::detect())
                             }
-                            _ => Document::open(path, None, None, editor.config.clone())
-                                .map(|doc| CachedPreview::Document(Box::new(doc)))
-                                .unwrap_or(CachedPreview::NotFound),
-                        },
-                    )
-                    .unwrap_or(CachedPreview::NotFound);
-                self.preview_cache.insert(path.to_owned(), preview);
-                Preview::Cached(&self.preview_cache[path])
+                            RawPreview::Binary(bytes) => CachedPreview::Binary(bytes),
+                            RawPreview::LargeFile => CachedPreview::LargeFile,
+                            RawPreview::NotFound => CachedPreview::NotFound,
+                            RawPreview::Text {
+                                rope,
+                                encoding,
+                                has_bom,
+                            } => {
+                                let mut doc = Document::from(rope, Some((encoding, has_bom)), config);
+                                doc.set_path(Some(&path));
+                                doc.detect_indent_and_line_ending();
+                                CachedPreview::Document(Box::new(doc))
+                            }
+                        };
+
+                        let picker = match compositor.find::<Overlay<Self>>() {
+                            Some(Overlay { content, .. }) => Some(content),
+                            None => compositor
+                                .find::<Overlay<DynamicPicker<T, D>>>()
+                                .map(|overlay| &mut overlay.content.file_picker),
+                        };
+                        let Some(picker) = picker else {
+                            log::info!("picker closed before preview finished loading");
+                            return;
+                        };
+                        picker.preview_cache.insert(path, preview);
+                    };
+                    Callback::EditorCompositor(Box::new(callback))
+                });
+                jobs.callback(job.map(|res| res.map_err(anyhow::Error::from)));
+
+                Preview::Cached(self.preview_cache.insert(cache_key, CachedPreview::Loading))
             }
             PathOrId::Id(id) => {
                 let doc = editor.documents.get(&id).unwrap();
@@ -617,6 +1129,10 @@ fn handle_idle_timeout(&mut self, cx: &mut Context) -> EventResult {
 
     fn render_picker(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         let status = self.matcher.tick(10);
+        if status.changed {
+            self.sort_order_dirty = true;
+        }
+        self.refresh_sort_order(cx.editor.config().picker.frecency_weight);
         let snapshot = self.matcher.snapshot();
         if status.changed {
             self.cursor = self
@@ -648,12 +1164,25 @@ fn render_picker(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context)
         self.prompt.render(area, surface, cx);
 
         let count = format!(
-            "{}{}/{}",
+            "{}{}{}{}/{}",
             if status.running || self.tmp_running {
                 "(running) "
             } else {
                 ""
             },
+            self.match_mode
+                .label()
+                .map(|label| format!("[{label}] "))
+                .unwrap_or_default(),
+            self.sort_column
+                .map(|column| {
+                    format!(
+                        "[sort: {} {}] ",
+                        self.columns[column].name,
+                        if self.sort_ascending { "↑" } else { "↓" }
+                    )
+                })
+                .unwrap_or_default(),
             snapshot.matched_item_count(),
             snapshot.item_count(),
         );
@@ -685,12 +1214,17 @@ fn render_picker(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context)
             .min(snapshot.matched_item_count());
         let mut indices = Vec::new();
         let mut matcher = MATCHER.lock();
-        matcher.config = Config::DEFAULT;
-        if self.file_fn.is_some() {
-            matcher.config.set_match_paths()
-        }
+        matcher.config = fuzzy_matching_config().nucleo_config(self.file_fn.is_some());
 
-        let options = snapshot.matched_items(offset..end).map(|item| {
+        let row_indices: Vec<u32> = if self.sort_order.is_empty() {
+            (offset..end).collect()
+        } else {
+            self.sort_order[offset as usize..end as usize].to_vec()
+        };
+        let options = row_indices
+            .into_iter()
+            .filter_map(|index| snapshot.get_matched_item(index))
+            .map(|item| {
             let mut widths = self.widths.iter_mut();
             let mut matcher_index = 0;
 
@@ -805,6 +1339,19 @@ fn render_picker(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context)
             },
             self.truncate_start,
         );
+
+        let scroll_style = cx.editor.theme.get("ui.menu.scroll");
+        Scrollbar::new(
+            snapshot.matched_item_count() as usize,
+            rows as usize,
+            offset as usize,
+        )
+        .thumb_style(Style::default().fg(scroll_style.fg.unwrap_or(helix_view::theme::Color::Reset)))
+        .track_style(Style::default().fg(scroll_style.bg.unwrap_or(helix_view::theme::Color::Reset)))
+        .render(
+            Rect::new(inner.right().saturating_sub(1), inner.y, 1, inner.height),
+            surface,
+        );
     }
 
     fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
@@ -825,7 +1372,15 @@ fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context
         block.render(area, surface);
 
         if let Some((path, range)) = self.current_file(cx.editor) {
-            let preview = self.get_preview(path, cx.editor);
+            let preview = self.get_preview(path, cx.editor, cx.jobs);
+            if let Preview::Cached(CachedPreview::Binary(bytes)) = &preview {
+                render_hex_preview(bytes, inner, surface, text);
+                return;
+            }
+            if let Preview::Cached(CachedPreview::Image(bytes, protocol)) = &preview {
+                render_image_preview(bytes, *protocol, inner, surface, text);
+                return;
+            }
             let doc = match preview.document() {
                 Some(doc)
                     if range.map_or(true, |(start, end)| {
@@ -915,11 +1470,131 @@ fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context
                 &cx.editor.theme,
                 decorations,
             );
+
+            let scroll_style = cx.editor.theme.get("ui.menu.scroll");
+            let current_line = doc.text().char_to_line(offset.anchor);
+            Scrollbar::new(doc.text().len_lines(), inner.height as usize, current_line)
+                .thumb_style(Style::default().fg(scroll_style.fg.unwrap_or(helix_view::theme::Color::Reset)))
+                .track_style(Style::default().fg(scroll_style.bg.unwrap_or(helix_view::theme::Color::Reset)))
+                .render(
+                    Rect::new(area.right().saturating_sub(1), inner.y, 1, inner.height),
+                    surface,
+                );
+        }
+    }
+}
+
+/// Renders a read-only hex dump of `bytes`, one `BYTES_PER_LINE`-byte chunk
+/// per row, so binary files can still be identified from the preview pane.
+fn render_hex_preview(bytes: &[u8], area: Rect, surface: &mut Surface, style: Style) {
+    const BYTES_PER_LINE: usize = 16;
+
+    for (i, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+        if i as u16 >= area.height {
+            break;
+        }
+
+        let mut hex = String::with_capacity(BYTES_PER_LINE * 3 + 1);
+        for (j, byte) in chunk.iter().enumerate() {
+            if j == BYTES_PER_LINE / 2 {
+                hex.push(' ');
+            }
+            hex.push_str(&format!("{byte:02x} "));
+        }
+
+        let ascii: String = chunk
+            .iter()
+            .map(|byte| {
+                if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        let line = format!(
+            "{:08x}  {hex:<width$} |{ascii}|",
+            i * BYTES_PER_LINE,
+            width = BYTES_PER_LINE * 3 + 1,
+        );
+        surface.set_stringn(area.x, area.y + i as u16, &line, area.width as usize, style);
+    }
+}
+
+/// Renders `bytes` (the still-encoded contents of an image file) inline via
+/// `protocol`. Image protocols draw directly to the terminal at a fixed
+/// cursor position rather than through the regular cell grid, so unlike
+/// [`render_hex_preview`] this bypasses `surface` and writes straight to
+/// stdout. Falls back to a small metadata summary when `protocol` is `None`,
+/// i.e. no supported graphics protocol was detected for the terminal.
+fn render_image_preview(
+    bytes: &[u8],
+    protocol: Option<GraphicsProtocol>,
+    area: Rect,
+    surface: &mut Surface,
+    style: Style,
+) {
+    let escape = protocol.and_then(|protocol| graphics_protocol::encode(protocol, bytes));
+    let Some(escape) = escape else {
+        let text = format!("<Image file, {} KiB>", (bytes.len() + 1023) / 1024);
+        let x = area.x + area.width.saturating_sub(text.len() as u16) / 2;
+        let y = area.y + area.height / 2;
+        surface.set_stringn(x, y, &text, area.width as usize, style);
+        return;
+    };
+
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    // Move the cursor to the preview area's top-left corner (1-indexed) to
+    // anchor the image, then park it back at the origin so it doesn't
+    // interfere with the terminal-wide diff the backend writes next frame.
+    let _ = write!(stdout, "\x1b[{};{}H{escape}\x1b[1;1H", area.y + 1, area.x + 1);
+    let _ = stdout.flush();
+}
+
+impl<T: 'static + Send + Sync + Clone, D: 'static + Send + Sync> Picker<T, D> {
+    /// Removes any currently matched item for which `predicate` returns
+    /// `true`, e.g. after a picker action deletes the resource it
+    /// represents. Nucleo has no primitive for removing a single injected
+    /// item, so this re-populates the matcher with the retained items —
+    /// still much cheaper than tearing down and rebuilding the picker
+    /// component itself.
+    pub fn remove_matching_items(&mut self, predicate: impl Fn(&T) -> bool) {
+        let retained: Vec<T> = {
+            let snapshot = self.matcher.snapshot();
+            (0..snapshot.matched_item_count())
+                .filter_map(|i| snapshot.get_matched_item(i))
+                .filter(|item| !predicate(item.data))
+                .map(|item| item.data.clone())
+                .collect()
+        };
+
+        self.matcher.restart(false);
+        let injector = self.matcher.injector();
+        for item in retained {
+            inject_nucleo_item(&injector, &self.columns, item, &self.editor_data);
         }
+
+        self.cursor = self.cursor.min(
+            self.matcher
+                .snapshot()
+                .matched_item_count()
+                .saturating_sub(1),
+        );
+        self.version.fetch_add(1, atomic::Ordering::Relaxed);
     }
 }
 
 impl<I: 'static + Send + Sync, D: 'static + Send + Sync> Component for Picker<I, D> {
+    fn on_reopen(&mut self, editor: &mut Editor) {
+        if let Some(mut restart) = self.restart_fn.take() {
+            let injector = self.injector();
+            restart(editor, &injector);
+            self.restart_fn = Some(restart);
+        }
+    }
+
     fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         // +---------+ +---------+
         // |prompt   | |preview  |
@@ -928,20 +1603,33 @@ fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         // |         | |         |
         // +---------+ +---------+
 
-        let render_preview = self.show_preview && area.width > MIN_AREA_WIDTH_FOR_PREVIEW;
-
-        let picker_width = if render_preview {
-            area.width / 2
-        } else {
-            area.width
-        };
+        let picker_config = cx.editor.config().picker.clone();
+        let render_preview = self.show_preview
+            && picker_config.preview_position != helix_view::editor::PreviewPosition::Hidden
+            && area.width > MIN_AREA_WIDTH_FOR_PREVIEW;
 
-        let picker_area = area.with_width(picker_width);
-        self.render_picker(picker_area, surface, cx);
+        if !render_preview {
+            self.render_picker(area, surface, cx);
+            return;
+        }
 
-        if render_preview {
-            let preview_area = area.clip_left(picker_width);
-            self.render_preview(preview_area, surface, cx);
+        let ratio = u16::from(picker_config.preview_ratio.min(100));
+        match picker_config.preview_position {
+            helix_view::editor::PreviewPosition::Below => {
+                let picker_height = area.height * ratio / 100;
+                let picker_area = area.with_height(picker_height);
+                self.render_picker(picker_area, surface, cx);
+                let preview_area = area.clip_top(picker_height);
+                self.render_preview(preview_area, surface, cx);
+            }
+            helix_view::editor::PreviewPosition::Right => {
+                let picker_width = area.width * ratio / 100;
+                let picker_area = area.with_width(picker_width);
+                self.render_picker(picker_area, surface, cx);
+                let preview_area = area.clip_left(picker_width);
+                self.render_preview(preview_area, surface, cx);
+            }
+            helix_view::editor::PreviewPosition::Hidden => unreachable!(),
         }
     }
 
@@ -968,10 +1656,9 @@ fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> EventResult {
                     compositor.pop();
                 })
             } else {
-                // stop streaming in new items in the background, really we should
-                // be restarting the stream somehow once the picker gets
-                // reopened instead (like for an FS crawl) that would also remove the
-                // need for the special case above but that is pretty tricky
+                // stop streaming in new items in the background; if the picker
+                // gets reopened via `last_picker`, `Component::on_reopen` uses
+                // `restart_fn` (when set) to kick the stream off again
                 picker.version.fetch_add(1, atomic::Ordering::Relaxed);
                 Box::new(|compositor: &mut Compositor, _ctx| {
                     // remove the layer
@@ -985,10 +1672,26 @@ fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> EventResult {
         ctx.editor.reset_idle_timer();
 
         match key_event {
-            shift!(Tab) | key!(Up) | ctrl!('p') => {
+            shift!(Tab) | key!(Up) => {
+                self.move_by(1, Direction::Backward);
+            }
+            ctrl!('p') if self.prompt.line().is_empty() => {
+                self.prompt
+                    .change_history(ctx, QUERY_HISTORY_REGISTER, CompletionDirection::Backward);
+                self.update_query();
+            }
+            ctrl!('p') => {
                 self.move_by(1, Direction::Backward);
             }
-            key!(Tab) | key!(Down) | ctrl!('n') => {
+            key!(Tab) | key!(Down) => {
+                self.move_by(1, Direction::Forward);
+            }
+            ctrl!('n') if self.prompt.line().is_empty() => {
+                self.prompt
+                    .change_history(ctx, QUERY_HISTORY_REGISTER, CompletionDirection::Forward);
+                self.update_query();
+            }
+            ctrl!('n') => {
                 self.move_by(1, Direction::Forward);
             }
             key!(PageDown) | ctrl!('d') => {
@@ -1005,24 +1708,32 @@ fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> EventResult {
             }
             key!(Esc) | ctrl!('c') => return close_fn(self),
             alt!(Enter) => {
+                self.save_query_to_history(ctx);
                 if let Some(option) = self.selection() {
+                    self.record_frecency_accept(option);
                     (self.callback_fn)(ctx, option, Action::Load);
                 }
             }
             key!(Enter) => {
+                self.save_query_to_history(ctx);
                 if let Some(option) = self.selection() {
+                    self.record_frecency_accept(option);
                     (self.callback_fn)(ctx, option, Action::Replace);
                 }
                 return close_fn(self);
             }
             ctrl!('s') => {
+                self.save_query_to_history(ctx);
                 if let Some(option) = self.selection() {
+                    self.record_frecency_accept(option);
                     (self.callback_fn)(ctx, option, Action::HorizontalSplit);
                 }
                 return close_fn(self);
             }
             ctrl!('v') => {
+                self.save_query_to_history(ctx);
                 if let Some(option) = self.selection() {
+                    self.record_frecency_accept(option);
                     (self.callback_fn)(ctx, option, Action::VerticalSplit);
                 }
                 return close_fn(self);
@@ -1030,8 +1741,53 @@ fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> EventResult {
             ctrl!('t') => {
                 self.toggle_preview();
             }
+            ctrl!('r') if self.columns.len() > 1 => {
+                self.cycle_sort_column();
+            }
+            alt!('c') => {
+                self.cycle_match_mode();
+            }
+            ctrl!('q') => {
+                let entries = self.to_quickfix_entries(ctx.editor);
+                if entries.is_empty() {
+                    ctx.editor
+                        .set_error("No file locations in this picker to add to the quickfix list");
+                } else {
+                    let len = entries.len();
+                    ctx.editor.quickfix.set(entries);
+                    ctx.editor
+                        .set_status(format!("Added {len} entries to the quickfix list"));
+                }
+            }
+            ctrl!('o') if self.file_fn.is_some() => {
+                let cap = ctx.editor.config().picker.batch_open_limit;
+                let snapshot = self.matcher.snapshot();
+                let count = snapshot.matched_item_count().min(cap);
+                for i in 0..count {
+                    if let Some(item) = snapshot.get_matched_item(i) {
+                        (self.callback_fn)(ctx, item.data, Action::Load);
+                    }
+                }
+                if count == 0 {
+                    ctx.editor.set_error("No matches to open");
+                } else {
+                    ctx.editor
+                        .set_status(format!("Opened {count} matches as background buffers"));
+                }
+                return close_fn(self);
+            }
             _ => {
-                self.prompt_handle_event(event, ctx);
+                if let Some((_, _, action)) = self
+                    .custom_actions
+                    .iter()
+                    .find(|(key, _, _)| *key == key_event)
+                {
+                    if let Some(option) = self.selection() {
+                        action(ctx, option);
+                    }
+                } else {
+                    self.prompt_handle_event(event, ctx);
+                }
             }
         }
 
@@ -1067,6 +1823,16 @@ fn drop(&mut self) {
 
 type PickerCallback<T> = Box<dyn Fn(&mut Context, &T, Action)>;
 
+/// Splits a picker prompt line into a pattern per column, keyed by column
+/// name (`%field:pattern`) with the primary column receiving any text
+/// outside a `%field:` block.
+///
+/// A pattern (primary or per-field) that starts with `!`, e.g.
+/// `%path:!test`, is passed through unchanged to nucleo's atom parser,
+/// which treats a leading `!` as negation: rows whose column *does*
+/// contain the rest of the pattern are excluded rather than matched. This
+/// function only has to avoid treating `!` as special during tokenizing,
+/// since the matching layer already implements the exclusion.
 fn parse_query(
     column_names: &[&'static str],
     primary_column: usize,
@@ -1171,6 +1937,25 @@ pub fn new(file_picker: Picker<T, D>, query_callback: DynQueryCallback<T, D>) ->
 }
 
 impl<T: Send + Sync + 'static, D: Send + Sync + 'static> Component for DynamicPicker<T, D> {
+    fn on_reopen(&mut self, editor: &mut Editor) {
+        // Closing bumped `version`, which stops any request that was still
+        // in flight; re-run `query_callback` for the current query so a
+        // reopened live-grep/workspace-symbols picker isn't left showing a
+        // stale, possibly-empty result set. Done directly here rather than
+        // through `DynamicPickerHook`, since the hook only re-fires when the
+        // query text *changes* and it hasn't here.
+        self.file_picker.version.fetch_add(1, atomic::Ordering::Relaxed);
+        self.file_picker.matcher.restart(false);
+        self.file_picker.tmp_running = true;
+        let injector = self.file_picker.injector();
+        let get_options = (self.query_callback)(self.query.clone(), editor, &injector);
+        tokio::spawn(async move {
+            if let Err(err) = get_options.await {
+                log::error!("Failed to do dynamic request: {err}");
+            }
+        });
+    }
+
     fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
         self.file_picker.render(area, surface, cx);
     }
@@ -1339,5 +2124,14 @@ fn parse_query_test() {
                 "field1" => "a\"b".to_string(),
             )
         );
+
+        // A leading `!` in a field's pattern is left untouched for the
+        // matcher to interpret as a negated (excluding) atom.
+        assert_eq!(
+            parse_query(columns, primary_column, "%field1:!test"),
+            hashmap!(
+                "field1" => "!test".to_string(),
+            )
+        );
     }
 }
@@ -92,12 +92,12 @@ pub fn ignore_escape_key(mut self, ignore: bool) -> Self {
     /// Calculate the position where the popup should be rendered and return the coordinates of the
     /// top left corner.
     pub fn get_rel_position(&mut self, viewport: Rect, editor: &Editor) -> (u16, u16) {
+        let (width, height) = self.size;
+
         let position = self
             .position
             .get_or_insert_with(|| editor.cursor().0.unwrap_or_default());
 
-        let (width, height) = self.size;
-
         // if there's a orientation preference, use that
         // if we're on the top part of the screen, do below
         // if we're on the bottom part, do above
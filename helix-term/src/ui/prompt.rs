@@ -1,5 +1,5 @@
 use crate::compositor::{Component, Compositor, Context, Event, EventResult};
-use crate::{alt, ctrl, key, shift, ui};
+use crate::{alt, ctrl, job, key, shift, ui};
 use helix_core::syntax;
 use helix_view::input::KeyEvent;
 use helix_view::keyboard::KeyCode;
@@ -9,9 +9,11 @@
 use tui::widgets::{Block, Borders, Widget};
 
 use helix_core::{
-    unicode::segmentation::GraphemeCursor, unicode::width::UnicodeWidthStr, Position,
+    unicode::segmentation::GraphemeCursor, unicode::width::UnicodeWidthStr, Position, Selection,
+    Transaction,
 };
 use helix_view::{
+    editor::Action,
     graphics::{CursorKind, Margin, Rect},
     Editor,
 };
@@ -35,6 +37,11 @@ pub struct Prompt {
     pub doc_fn: DocFn,
     next_char_handler: Option<PromptCharHandler>,
     language: Option<(&'static str, Arc<syntax::Loader>)>,
+    /// Whether `Shift-Enter` inserts a newline instead of doing nothing, and
+    /// [`Self::render_prompt`]/[`Self::cursor`] account for the line wrapping
+    /// that results. Off by default since most prompts (`:`, search, etc.)
+    /// are conceptually single-line.
+    multiline: bool,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -87,9 +94,17 @@ pub fn new(
             doc_fn: Box::new(|_| None),
             next_char_handler: None,
             language: None,
+            multiline: false,
         }
     }
 
+    /// Allows `Shift-Enter` to insert a newline, for prompts that take a
+    /// body rather than a single line, e.g. a commit message.
+    pub fn with_multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
     pub fn with_line(mut self, line: String, editor: &Editor) -> Self {
         self.set_line(line, editor);
         self
@@ -111,6 +126,46 @@ pub fn line(&self) -> &String {
         &self.line
     }
 
+    /// Opens the current line in a full, editable scratch buffer (a
+    /// "command-line window", after Vim's `:h cmdline-window`) so long
+    /// commands, search regexes or global-search queries can be composed
+    /// with the ordinary editing keys instead of the single-line prompt.
+    /// Pressing `Enter` feeds the buffer's content back into this prompt as
+    /// if it had been typed there; `:cmdwin-cancel` discards it instead.
+    fn open_command_line_window(&self, cx: &mut Context) {
+        let line = self.line.clone();
+        let callback = async move {
+            let call: job::Callback = job::Callback::EditorCompositor(Box::new(
+                move |editor: &mut Editor, _compositor: &mut Compositor| {
+                    let doc_id = editor.new_file(Action::HorizontalSplit);
+                    editor.command_line_window = Some(doc_id);
+
+                    let (view, doc) = current!(editor);
+                    doc.ensure_view_init(view.id);
+                    let transaction =
+                        Transaction::insert(doc.text(), doc.selection(view.id), line.into())
+                            .with_selection(Selection::point(0));
+                    doc.apply(&transaction, view.id);
+                    doc.append_changes_to_history(view);
+
+                    editor.set_status(
+                        "Command-line window: press Enter to accept, or :cmdwin-cancel to discard",
+                    );
+                },
+            ));
+            Ok(call)
+        };
+        cx.jobs.callback(callback);
+    }
+
+    /// Replaces the prompt's line with `line` and validates it immediately,
+    /// as if the user had typed it and pressed `Enter`. Used to feed back
+    /// the content of a command-line window (see [`Self::open_command_line_window`]).
+    pub fn submit(&mut self, line: String, cx: &mut Context) {
+        self.set_line(line, cx.editor);
+        (self.callback_fn)(cx, &self.line, PromptEvent::Validate);
+    }
+
     pub fn recalculate_completion(&mut self, editor: &Editor) {
         self.exit_selection();
         self.completion = (self.completion_fn)(editor, &self.line);
@@ -467,7 +522,14 @@ pub fn render_prompt(&self, area: Rect, surface: &mut Surface, cx: &mut Context)
             text.render(inner, surface, cx);
         }
 
-        let line = area.height - 1;
+        // Multiline prompts grow upward from the bottom row, one row per
+        // line already typed.
+        let line_count = if self.multiline {
+            self.line.matches('\n').count() as u16 + 1
+        } else {
+            1
+        };
+        let line = area.height.saturating_sub(line_count);
         surface.clear_with(area.clip_top(line), background);
         // render buffer text
         surface.set_string(area.x, area.y + line, &self.prompt, prompt_color);
@@ -491,6 +553,10 @@ pub fn render_prompt(&self, area: Rect, surface: &mut Surface, cx: &mut Context)
             )
             .into();
             text.render(line_area, surface, cx);
+        } else if self.multiline {
+            for (i, line) in self.line.split('\n').enumerate() {
+                surface.set_string(line_area.x, line_area.y + i as u16, line, prompt_color);
+            }
         } else {
             surface.set_string(line_area.x, line_area.y, self.line.clone(), prompt_color);
         }
@@ -568,6 +634,10 @@ fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
                     (self.callback_fn)(cx, &self.line, PromptEvent::Update);
                 }
             }
+            shift!(Enter) if self.multiline => {
+                self.insert_char('\n', cx);
+                (self.callback_fn)(cx, &self.line, PromptEvent::Update);
+            }
             key!(Enter) => {
                 if self.selection.is_some() && self.line.ends_with(std::path::MAIN_SEPARATOR) {
                     self.recalculate_completion(cx.editor);
@@ -624,6 +694,10 @@ fn handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
                 (self.callback_fn)(cx, &self.line, PromptEvent::Update)
             }
             ctrl!('q') => self.exit_selection(),
+            ctrl!('o') => {
+                self.open_command_line_window(cx);
+                return EventResult::Consumed(None);
+            }
             ctrl!('r') => {
                 self.completion = cx
                     .editor
@@ -663,6 +737,29 @@ fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
     }
 
     fn cursor(&self, area: Rect, _editor: &Editor) -> (Option<Position>, CursorKind) {
+        if self.multiline {
+            let cursor_line_idx = self.line[..self.cursor].matches('\n').count();
+            let line_start = self.line[..self.cursor]
+                .rfind('\n')
+                .map_or(0, |pos| pos + 1);
+            let line_count = self.line.matches('\n').count() + 1;
+            let row = area.height as usize - line_count + cursor_line_idx;
+            let indent = if cursor_line_idx == 0 {
+                self.prompt.len()
+            } else {
+                0
+            };
+            return (
+                Some(Position::new(
+                    area.y as usize + row,
+                    area.x as usize
+                        + indent
+                        + UnicodeWidthStr::width(&self.line[line_start..self.cursor]),
+                )),
+                CursorKind::Block,
+            );
+        }
+
         let line = area.height as usize - 1;
         (
             Some(Position::new(
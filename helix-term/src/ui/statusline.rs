@@ -1,6 +1,9 @@
+use std::path::PathBuf;
+
 use helix_core::{coords_at_pos, encoding, Position};
 use helix_lsp::lsp::DiagnosticSeverity;
 use helix_view::document::DEFAULT_LANGUAGE_NAME;
+use helix_view::input::KeyEvent;
 use helix_view::{
     document::{Mode, SCRATCH_BUFFER_NAME},
     graphics::Rect,
@@ -21,6 +24,8 @@ pub struct RenderContext<'a> {
     pub focused: bool,
     pub spinners: &'a ProgressSpinners,
     pub parts: RenderBuffer<'a>,
+    pub pending_keys: &'a [KeyEvent],
+    pub sticky_name: Option<&'a str>,
 }
 
 impl<'a> RenderContext<'a> {
@@ -30,6 +35,8 @@ pub fn new(
         view: &'a View,
         focused: bool,
         spinners: &'a ProgressSpinners,
+        pending_keys: &'a [KeyEvent],
+        sticky_name: Option<&'a str>,
     ) -> Self {
         RenderContext {
             editor,
@@ -38,6 +45,8 @@ pub fn new(
             focused,
             spinners,
             parts: RenderBuffer::default(),
+            pending_keys,
+            sticky_name,
         }
     }
 }
@@ -68,15 +77,39 @@ pub fn render(context: &mut RenderContext, viewport: Rect, surface: &mut Surface
         append(&mut context.parts.right, text, &base_style, style)
     };
 
-    // Left side of the status line.
-
     let config = context.editor.config();
 
-    let element_ids = &config.statusline.left;
-    element_ids
-        .iter()
-        .map(|element_id| get_render_function(*element_id))
-        .for_each(|render| render(context, write_left));
+    if let Some(template) = config.statusline.format.clone() {
+        let mut sections = template.splitn(3, '|').map(parse_template);
+        let left = sections.next().unwrap_or_default();
+        let center = sections.next().unwrap_or_default();
+        let right = sections.next().unwrap_or_default();
+
+        render_template(context, &left, write_left);
+        render_template(context, &center, write_center);
+        render_template(context, &right, write_right);
+    } else {
+        // Left side of the status line.
+        let element_ids = &config.statusline.left;
+        element_ids
+            .iter()
+            .map(|element_id| get_render_function(*element_id))
+            .for_each(|render| render(context, write_left));
+
+        // Right side of the status line.
+        let element_ids = &config.statusline.right;
+        element_ids
+            .iter()
+            .map(|element_id| get_render_function(*element_id))
+            .for_each(|render| render(context, write_right));
+
+        // Center of the status line.
+        let element_ids = &config.statusline.center;
+        element_ids
+            .iter()
+            .map(|element_id| get_render_function(*element_id))
+            .for_each(|render| render(context, write_center));
+    }
 
     surface.set_spans(
         viewport.x,
@@ -85,14 +118,6 @@ pub fn render(context: &mut RenderContext, viewport: Rect, surface: &mut Surface
         context.parts.left.width() as u16,
     );
 
-    // Right side of the status line.
-
-    let element_ids = &config.statusline.right;
-    element_ids
-        .iter()
-        .map(|element_id| get_render_function(*element_id))
-        .for_each(|render| render(context, write_right));
-
     surface.set_spans(
         viewport.x
             + viewport
@@ -103,14 +128,6 @@ pub fn render(context: &mut RenderContext, viewport: Rect, surface: &mut Surface
         context.parts.right.width() as u16,
     );
 
-    // Center of the status line.
-
-    let element_ids = &config.statusline.center;
-    element_ids
-        .iter()
-        .map(|element_id| get_render_function(*element_id))
-        .for_each(|render| render(context, write_center));
-
     // Width of the empty space between the left and center area and between the center and right area.
     let spacing = 1u16;
 
@@ -133,6 +150,108 @@ fn append(buffer: &mut Spans, text: String, base_style: &Style, style: Option<St
     ));
 }
 
+/// A piece of a [`StatusLineConfig::format`] template: either literal text
+/// to render verbatim, or a built-in element with an optional `:modifier`.
+enum TemplateSegment {
+    Literal(String),
+    Element(StatusLineElementID, Option<String>),
+}
+
+/// Resolves a `{...}` placeholder name to an element using the same
+/// kebab-case names as the `left`/`center`/`right` config lists.
+fn element_from_name(name: &str) -> Option<StatusLineElementID> {
+    serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+}
+
+/// Parses a `StatusLineConfig::format` section into literal and element
+/// segments. Unknown `{placeholder}`s are kept as literal text so typos show
+/// up in the rendered statusline instead of silently vanishing.
+fn parse_template(template: &str) -> Vec<TemplateSegment> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '{' {
+            literal.push(ch);
+            continue;
+        }
+
+        let mut name = String::new();
+        let mut closed = false;
+        for ch in chars.by_ref() {
+            if ch == '}' {
+                closed = true;
+                break;
+            }
+            name.push(ch);
+        }
+
+        if !closed {
+            literal.push('{');
+            literal.push_str(&name);
+            continue;
+        }
+
+        let (id_name, modifier) = match name.split_once(':') {
+            Some((id_name, modifier)) => (id_name, Some(modifier.to_string())),
+            None => (name.as_str(), None),
+        };
+
+        match element_from_name(id_name) {
+            Some(id) => {
+                if !literal.is_empty() {
+                    segments.push(TemplateSegment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(TemplateSegment::Element(id, modifier));
+            }
+            None => {
+                literal.push('{');
+                literal.push_str(&name);
+                literal.push('}');
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(TemplateSegment::Literal(literal));
+    }
+
+    segments
+}
+
+fn render_template<F>(context: &mut RenderContext, segments: &[TemplateSegment], write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    for segment in segments {
+        match segment {
+            TemplateSegment::Literal(text) => write(context, text.clone(), None),
+            TemplateSegment::Element(id, modifier) => {
+                // `:trunc` on the file name shows the basename instead.
+                let id = match (*id, modifier.as_deref()) {
+                    (StatusLineElementID::FileName, Some("trunc")) => {
+                        StatusLineElementID::FileBaseName
+                    }
+                    _ => *id,
+                };
+                let color = modifier
+                    .as_deref()
+                    .filter(|&m| m != "trunc")
+                    .and_then(|scope| context.editor.theme.try_get(scope));
+                let render = get_render_function(id);
+                if let Some(color) = color {
+                    render(context, |context: &mut RenderContext, text, style| {
+                        write(context, text, style.or(Some(color)))
+                    });
+                } else {
+                    render(context, write);
+                }
+            }
+        }
+    }
+}
+
 fn get_render_function<F>(element_id: StatusLineElementID) -> impl Fn(&mut RenderContext, F)
 where
     F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
@@ -162,6 +281,11 @@ fn get_render_function<F>(element_id: StatusLineElementID) -> impl Fn(&mut Rende
         helix_view::editor::StatusLineElement::Spacer => render_spacer,
         helix_view::editor::StatusLineElement::VersionControl => render_version_control,
         helix_view::editor::StatusLineElement::Register => render_register,
+        helix_view::editor::StatusLineElement::PendingKeys => render_pending_keys,
+        helix_view::editor::StatusLineElement::PinIndicator => render_pin_indicator,
+        helix_view::editor::StatusLineElement::StructurePath => render_structure_path,
+        helix_view::editor::StatusLineElement::FileIndentStyle => render_file_indent_style,
+        helix_view::editor::StatusLineElement::AutoFormatIndicator => render_auto_format_indicator,
     }
 }
 
@@ -413,6 +537,19 @@ fn render_file_type<F>(context: &mut RenderContext, write: F)
     write(context, format!(" {} ", file_type), None);
 }
 
+fn render_file_indent_style<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    use helix_core::indent::IndentStyle;
+    let indent = match context.doc.indent_style {
+        IndentStyle::Tabs => "tabs".to_string(),
+        IndentStyle::Spaces(width) => format!("spaces:{width}"),
+    };
+
+    write(context, format!(" {} ", indent), None);
+}
+
 fn render_file_name<F>(context: &mut RenderContext, write: F)
 where
     F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
@@ -443,6 +580,30 @@ fn render_file_modification_indicator<F>(context: &mut RenderContext, write: F)
     write(context, title, None);
 }
 
+fn render_pin_indicator<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    let title = if context.view.pinned { "[pin]" } else { "" }.to_string();
+    write(context, title, None);
+}
+
+fn render_structure_path<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    let pos = context
+        .doc
+        .selection(context.view.id)
+        .primary()
+        .cursor(context.doc.text().slice(..));
+    let title = match context.doc.structure_path(pos) {
+        Some(path) => format!(" {} ", path),
+        None => String::new(),
+    };
+    write(context, title, None);
+}
+
 fn render_read_only_indicator<F>(context: &mut RenderContext, write: F)
 where
     F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
@@ -456,6 +617,20 @@ fn render_read_only_indicator<F>(context: &mut RenderContext, write: F)
     write(context, title, None);
 }
 
+fn render_auto_format_indicator<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    let config = context.editor.config();
+    let title = if config.auto_format && config.auto_format_excluded(context.doc.path().map(PathBuf::as_path)) {
+        " [auto-format off] "
+    } else {
+        ""
+    }
+    .to_string();
+    write(context, title, None);
+}
+
 fn render_file_base_name<F>(context: &mut RenderContext, write: F)
 where
     F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
@@ -513,3 +688,32 @@ fn render_register<F>(context: &mut RenderContext, write: F)
         write(context, format!(" reg={} ", reg), None)
     }
 }
+
+/// Shows the in-progress count and keys of a pending command, along with the
+/// name of the active sticky keymap node, e.g. `3g` after typing `3g` in
+/// normal mode, or `Goto` while a sticky "Goto mode" node is active. A count
+/// typed on its own, with no key pending yet, is not shown.
+fn render_pending_keys<F>(context: &mut RenderContext, write: F)
+where
+    F: Fn(&mut RenderContext, String, Option<Style>) + Copy,
+{
+    if context.pending_keys.is_empty() && context.sticky_name.is_none() {
+        return;
+    }
+
+    let mut text = String::new();
+    if let Some(count) = context.editor.count {
+        text.push_str(&count.to_string());
+    }
+    for key in context.pending_keys {
+        text.push_str(&key.to_string());
+    }
+    if let Some(name) = context.sticky_name {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(name);
+    }
+
+    write(context, format!(" {} ", text), None);
+}
@@ -0,0 +1,227 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, ChildStdin};
+use tokio::sync::Mutex as AsyncMutex;
+
+use tui::{
+    buffer::Buffer as Surface,
+    widgets::{Block, Borders, Widget},
+};
+
+use helix_view::graphics::Rect;
+
+use crate::compositor::{Component, Context, Event, EventResult};
+use crate::{ctrl, key};
+
+// Cap buffered scrollback so a chatty process can't grow this unbounded.
+const MAX_LINES: usize = 5000;
+
+#[derive(Default)]
+struct Output {
+    lines: VecDeque<String>,
+    /// Bytes received since the last newline.
+    partial: String,
+}
+
+impl Output {
+    fn push(&mut self, chunk: &[u8]) {
+        self.partial.push_str(&String::from_utf8_lossy(chunk));
+        while let Some(pos) = self.partial.find('\n') {
+            let line = self.partial.drain(..=pos).collect::<String>();
+            self.lines
+                .push_back(line.trim_end_matches('\n').trim_end_matches('\r').to_string());
+            while self.lines.len() > MAX_LINES {
+                self.lines.pop_front();
+            }
+        }
+    }
+}
+
+/// A toggleable panel along the bottom of the screen running an
+/// interactive shell, spawned once via [`Terminal::new`] and kept alive
+/// (stdin open, output streaming in) for as long as the panel exists.
+///
+/// While focused, typed characters build up a line that is sent to the
+/// shell's stdin on `Enter`; `Ctrl-t` blurs focus back to the editor
+/// without closing the panel, and `Ctrl-t` again (since the panel still
+/// sits on top of the layer stack and sees the key first either way)
+/// refocuses it. `Ctrl-q` kills the shell and closes the panel.
+/// `:terminal-send-selection` writes the current primary selection to the
+/// shell's stdin regardless of focus.
+///
+/// Output is kept as plain lines rather than interpreted by a VT100
+/// state machine, so this is a scrollback log with a line-buffered input,
+/// not a full terminal emulator: full-screen programs (pagers, `htop`,
+/// editors) will print garbled escape sequences instead of redrawing in
+/// place. That's the tradeoff for not pulling in a PTY/VT100 crate for a
+/// single panel.
+pub struct Terminal {
+    child: Child,
+    stdin: Arc<AsyncMutex<ChildStdin>>,
+    output: Arc<Mutex<Output>>,
+    input: String,
+    scroll: usize,
+    focused: bool,
+}
+
+impl Terminal {
+    pub fn new(shell: &[String], cwd: PathBuf) -> std::io::Result<Self> {
+        use std::process::Stdio;
+        use tokio::process::Command;
+
+        let mut command = Command::new(&shell[0]);
+        command
+            .current_dir(cwd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        let mut child = command.spawn()?;
+        let stdin = child.stdin.take().expect("stdin requested");
+        let stdout = child.stdout.take().expect("stdout requested");
+        let stderr = child.stderr.take().expect("stderr requested");
+
+        let output = Arc::new(Mutex::new(Output::default()));
+        spawn_reader(stdout, Arc::clone(&output));
+        spawn_reader(stderr, Arc::clone(&output));
+
+        Ok(Self {
+            child,
+            stdin: Arc::new(AsyncMutex::new(stdin)),
+            output,
+            input: String::new(),
+            scroll: 0,
+            focused: true,
+        })
+    }
+
+    /// Writes `text` followed by a newline to the shell's stdin, as if it
+    /// had been typed and submitted.
+    pub fn send_line(&self, text: &str) {
+        let stdin = Arc::clone(&self.stdin);
+        let mut data = text.as_bytes().to_vec();
+        data.push(b'\n');
+        tokio::spawn(async move {
+            let mut stdin = stdin.lock().await;
+            let _ = stdin.write_all(&data).await;
+            let _ = stdin.flush().await;
+        });
+    }
+
+    fn submit_input(&mut self) {
+        let line = std::mem::take(&mut self.input);
+        self.send_line(&line);
+    }
+
+    fn close() -> crate::compositor::Callback {
+        Box::new(|compositor, _| {
+            compositor.remove(ID);
+        })
+    }
+}
+
+fn spawn_reader(mut reader: impl tokio::io::AsyncRead + Unpin + Send + 'static, output: Arc<Mutex<Output>>) {
+    tokio::spawn(async move {
+        let mut buf = [0u8; 4096];
+        loop {
+            match reader.read(&mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    output.lock().unwrap().push(&buf[..n]);
+                    helix_event::request_redraw();
+                }
+            }
+        }
+    });
+}
+
+impl Component for Terminal {
+    fn handle_event(&mut self, event: &Event, _cx: &mut Context) -> EventResult {
+        let key = match event {
+            Event::Key(event) => *event,
+            _ => return EventResult::Ignored(None),
+        };
+
+        if key == ctrl!('t') {
+            self.focused = !self.focused;
+            return EventResult::Consumed(None);
+        }
+
+        if !self.focused {
+            return EventResult::Ignored(None);
+        }
+
+        match key {
+            ctrl!('q') => {
+                let _ = self.child.start_kill();
+                return EventResult::Consumed(Some(Self::close()));
+            }
+            key!(Enter) => self.submit_input(),
+            key!(Backspace) => {
+                self.input.pop();
+            }
+            key!(PageUp) => self.scroll = self.scroll.saturating_add(10),
+            key!(PageDown) => self.scroll = self.scroll.saturating_sub(10),
+            helix_view::input::KeyEvent {
+                code: helix_view::keyboard::KeyCode::Char(c),
+                modifiers: _,
+            } => self.input.push(c),
+            _ => (),
+        }
+
+        EventResult::Consumed(None)
+    }
+
+    fn render(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context) {
+        let height = (area.height / 3).max(5).min(area.height);
+        let panel = Rect::new(area.x, area.y + area.height - height, area.width, height);
+
+        let background = cx.editor.theme.get("ui.background");
+        let text_style = cx.editor.theme.get("ui.text");
+        let prompt_style = if self.focused {
+            cx.editor.theme.get("ui.text.focus")
+        } else {
+            text_style
+        };
+
+        surface.clear_with(panel, background);
+        let title = if self.focused { " Terminal " } else { " Terminal (Ctrl-t to focus) " };
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let inner = block.inner(panel);
+        block.render(panel, surface);
+
+        let output = self.output.lock().unwrap();
+        let mut lines: Vec<&str> = output.lines.iter().map(String::as_str).collect();
+        if !output.partial.is_empty() {
+            lines.push(output.partial.as_str());
+        }
+
+        let output_rows = inner.height.saturating_sub(1) as usize;
+        let start = lines.len().saturating_sub(output_rows + self.scroll);
+        let end = lines.len().saturating_sub(self.scroll.min(lines.len()));
+        for (row, line) in lines[start..end].iter().enumerate() {
+            let y = inner.y + row as u16;
+            surface.set_stringn(inner.x, y, line, inner.width as usize, text_style);
+        }
+        drop(output);
+
+        let prompt_line = format!("$ {}", self.input);
+        surface.set_stringn(
+            inner.x,
+            inner.y + inner.height.saturating_sub(1),
+            &prompt_line,
+            inner.width as usize,
+            prompt_style,
+        );
+    }
+
+    fn id(&self) -> Option<&'static str> {
+        Some(ID)
+    }
+}
+
+pub(crate) const ID: &str = "terminal";
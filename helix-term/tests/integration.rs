@@ -22,4 +22,5 @@ async fn hello_world() -> anyhow::Result<()> {
     mod movement;
     mod prompt;
     mod splits;
+    mod ui;
 }
@@ -344,6 +344,22 @@ pub async fn run_event_loop_until_idle(app: &mut Application) {
     app.event_loop_until_idle(&mut rx_stream).await;
 }
 
+/// Feeds `keys` to `app`, lets it idle, then snapshots the rendered screen
+/// as text via [`Application::render_to_string`]. Useful for asserting on
+/// pickers, prompts and popups, which `test_key_sequence`'s document-only
+/// assertions can't see.
+pub async fn send_keys_and_render(app: &mut Application, keys: &str) -> anyhow::Result<String> {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut rx_stream = UnboundedReceiverStream::new(rx);
+
+    for key_event in parse_macro(keys)?.into_iter() {
+        tx.send(Ok(Event::Key(KeyEvent::from(key_event))))?;
+    }
+    app.event_loop_until_idle(&mut rx_stream).await;
+
+    Ok(app.render_to_string().await)
+}
+
 pub fn assert_file_has_content(file: &mut File, content: &str) -> anyhow::Result<()> {
     file.flush()?;
     file.sync_all()?;
@@ -0,0 +1,18 @@
+use super::*;
+
+/// Exercises `Application::render_to_string`, the harness used to assert on
+/// rendered pickers/prompts/popups instead of only document contents.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_render_to_string_shows_command_prompt() -> anyhow::Result<()> {
+    let mut app = AppBuilder::new().build()?;
+
+    let screen = send_keys_and_render(&mut app, ":theme").await?;
+    assert!(
+        screen.lines().any(|line| line.trim_start().starts_with(":theme")),
+        "expected the command prompt to be rendered, got:\n{screen}"
+    );
+
+    test_key_sequence(&mut app, Some("<esc>:q!<ret>"), None, true).await?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Detection and encoding for terminal graphics protocols (Kitty, iTerm2 and
+//! Sixel), used by UI components that want to render raster images inline
+//! instead of falling back to a text placeholder.
+
+use base64::Engine as _;
+
+/// A terminal graphics protocol capable of displaying raster images inline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    /// The [Kitty graphics protocol](https://sw.kovidgoyal.net/kitty/graphics-protocol/).
+    Kitty,
+    /// iTerm2's [inline images protocol](https://iterm2.com/documentation-images.html),
+    /// also implemented by WezTerm.
+    Iterm2,
+    /// [Sixel](https://en.wikipedia.org/wiki/Sixel) graphics. `detect` can
+    /// report this, but [`encode`] can't produce it yet: unlike the other
+    /// two protocols, Sixel doesn't accept an encoded image file directly —
+    /// it needs the image decoded into a raw pixel grid first, and this
+    /// crate has no image codec to do that with.
+    Sixel,
+}
+
+/// Detects which graphics protocol, if any, the terminal we're running in
+/// supports, based on environment variables set by known terminal emulators.
+/// There's no escape sequence that reliably queries this across terminals,
+/// so like [`crate::backend::crossterm`]'s truecolor/underline detection,
+/// this is necessarily a best-effort heuristic.
+pub fn detect() -> Option<GraphicsProtocol> {
+    if std::env::var_os("KITTY_WINDOW_ID").is_some() {
+        return Some(GraphicsProtocol::Kitty);
+    }
+    if matches!(std::env::var("TERM").as_deref(), Ok(term) if term.contains("kitty")) {
+        return Some(GraphicsProtocol::Kitty);
+    }
+    match std::env::var("TERM_PROGRAM").as_deref() {
+        Ok("iTerm.app") | Ok("WezTerm") => return Some(GraphicsProtocol::Iterm2),
+        _ => {}
+    }
+    if std::env::var_os("MLTERM").is_some() {
+        return Some(GraphicsProtocol::Sixel);
+    }
+    None
+}
+
+/// Encodes `bytes` (the raw, still-encoded contents of an image file, e.g. a
+/// PNG) as an escape sequence that displays it inline when written to the
+/// terminal. Returns `None` for protocols this function can't encode for
+/// (currently just [`GraphicsProtocol::Sixel`]).
+pub fn encode(protocol: GraphicsProtocol, bytes: &[u8]) -> Option<String> {
+    let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+    match protocol {
+        // `f=100` tells kitty to auto-detect the image format; `a=T`
+        // transmits and displays it immediately at the cursor position.
+        GraphicsProtocol::Kitty => Some(format!("\x1b_Gf=100,a=T;{data}\x1b\\")),
+        GraphicsProtocol::Iterm2 => {
+            Some(format!("\x1b]1337;File=inline=1;size={}:{data}\x07", bytes.len()))
+        }
+        GraphicsProtocol::Sixel => None,
+    }
+}
@@ -8,16 +8,19 @@
 // //! - [`List`]
 // //! - [`Table`]
 //! - [`Paragraph`]
+//! - [`Scrollbar`]
 
 mod block;
 // mod list;
 mod paragraph;
 mod reflow;
+mod scrollbar;
 mod table;
 
 pub use self::block::{Block, BorderType};
 // pub use self::list::{List, ListItem, ListState};
 pub use self::paragraph::{Paragraph, Wrap};
+pub use self::scrollbar::Scrollbar;
 pub use self::table::{Cell, Row, Table, TableState};
 
 use crate::buffer::Buffer;
@@ -0,0 +1,67 @@
+use crate::{buffer::Buffer, widgets::Widget};
+use helix_view::graphics::{Rect, Style};
+
+/// A single-column vertical scrollbar: a track of `▐` characters with a
+/// thumb sized and positioned to reflect how much of `content_length` is
+/// visible, and where, in a viewport of `viewport_length` rows starting at
+/// `position`.
+///
+/// Renders nothing if the content already fits in the viewport.
+pub struct Scrollbar {
+    content_length: usize,
+    viewport_length: usize,
+    position: usize,
+    thumb_style: Style,
+    track_style: Style,
+}
+
+impl Scrollbar {
+    pub fn new(content_length: usize, viewport_length: usize, position: usize) -> Self {
+        Self {
+            content_length,
+            viewport_length,
+            position,
+            thumb_style: Style::default(),
+            track_style: Style::default(),
+        }
+    }
+
+    pub fn thumb_style(mut self, style: Style) -> Self {
+        self.thumb_style = style;
+        self
+    }
+
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+}
+
+const fn div_ceil(a: usize, b: usize) -> usize {
+    (a + b - 1) / b
+}
+
+impl Widget for Scrollbar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || self.content_length <= self.viewport_length {
+            return;
+        }
+
+        let track_height = area.height as usize;
+        let thumb_height = div_ceil(track_height.pow(2), self.content_length)
+            .min(track_height)
+            .max(1);
+        let thumb_start = (track_height - thumb_height) * self.position
+            / std::cmp::max(1, self.content_length.saturating_sub(self.viewport_length));
+
+        for i in 0..track_height {
+            let cell = &mut buf[(area.x, area.y + i as u16)];
+            cell.set_symbol("▐"); // right half block
+            if thumb_start <= i && i < thumb_start + thumb_height {
+                cell.set_style(self.thumb_style);
+            } else {
+                cell.set_style(self.track_style);
+            }
+        }
+    }
+}
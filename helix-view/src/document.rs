@@ -7,7 +7,7 @@
 use helix_core::doc_formatter::TextFormat;
 use helix_core::encoding::Encoding;
 use helix_core::syntax::{Highlight, LanguageServerFeature};
-use helix_core::text_annotations::InlineAnnotation;
+use helix_core::text_annotations::{InlineAnnotation, Overlay};
 use helix_core::Range;
 use helix_lsp::lsp;
 use helix_lsp::util::{generate_transaction_from_edits, lsp_pos_to_pos};
@@ -34,7 +34,8 @@
     indent::{auto_detect_indent_style, IndentStyle},
     line_ending::auto_detect_line_ending,
     syntax::{self, LanguageConfiguration},
-    ChangeSet, Diagnostic, LineEnding, Rope, RopeBuilder, Selection, Syntax, Transaction,
+    sync_log::TransactionLog as SyncLog,
+    ChangeSet, Diagnostic, LineEnding, Rope, RopeBuilder, Selection, Syntax, Tendril, Transaction,
 };
 
 use crate::editor::Config;
@@ -50,6 +51,11 @@
 
 pub const SCRATCH_BUFFER_NAME: &str = "[scratch]";
 
+/// Display name for a pathless document created from stdin, so it reads
+/// distinctly from a blank [`SCRATCH_BUFFER_NAME`] buffer. See
+/// [`Document::display_name`].
+pub const STDIN_BUFFER_NAME: &str = "[stdin]";
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Mode {
     Normal = 0,
@@ -118,6 +124,24 @@ pub struct SavePoint {
     revert: Mutex<Transaction>,
 }
 
+/// A named point in a document's undo history, created with
+/// [`Document::create_checkpoint`] (directly via `:checkpoint`, or
+/// automatically before a risky bulk operation such as `:format` or
+/// `:global-replace`'s "replace all").
+///
+/// Unlike [`SavePoint`], a checkpoint doesn't hold its own revert
+/// transaction: it just remembers a revision number in the document's
+/// [`History`], which [`Document::restore_checkpoint`] jumps back to using
+/// the same undo-tree traversal as `u`/`U`. Revisions are never dropped from
+/// the tree, so a checkpoint stays restorable no matter how much undo/redo
+/// or further editing happens after it was created.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    pub name: String,
+    pub revision: usize,
+    pub created_at: SystemTime,
+}
+
 pub struct Document {
     pub(crate) id: DocumentId,
     text: Rope,
@@ -131,6 +155,59 @@ pub struct Document {
     /// update from the LSP
     pub inlay_hints_oudated: bool,
 
+    /// Code lenses last fetched via `textDocument/codeLens`, alongside the
+    /// id of the language server that returned them (needed to dispatch
+    /// `:code-lens-execute`'s `workspace/executeCommand` to the right
+    /// server). Rendered as a `"code-lens"` virtual text layer by
+    /// [`Self::set_code_lens`]; cleared on reload or when no server
+    /// supports the feature.
+    pub(crate) code_lens: Vec<lsp::CodeLens>,
+    pub(crate) code_lens_language_server_id: Option<usize>,
+
+    /// Color literals last fetched via `textDocument/documentColor`,
+    /// alongside the id of the language server that returned them. Rendered
+    /// as a `"document-color"` virtual text layer by
+    /// [`Self::set_document_colors`].
+    pub(crate) document_colors: Vec<lsp::ColorInformation>,
+    pub(crate) document_colors_language_server_id: Option<usize>,
+
+    /// Named virtual-text layers contributed by features other than inlay
+    /// hints (blame, debug values, plugins, ...), keyed by a stable owner
+    /// identifier so unrelated features can't clobber each other's
+    /// annotations. See [`VirtualTextLayer`].
+    pub(crate) virtual_text: HashMap<&'static str, VirtualTextLayer>,
+
+    /// Set by `:csv-align` while column alignment is active for this
+    /// document. Holds the field delimiter; the padding itself is kept in
+    /// `virtual_text` under the `"csv-align"` owner and recomputed by
+    /// [`Self::refresh_csv_align`] whenever the buffer changes.
+    pub csv_delimiter: Option<char>,
+
+    /// Set by `:redact` while secret masking is active for this document.
+    /// The overlays themselves are kept in `overlay_text` under the
+    /// `"redact"` owner and recomputed by [`Self::refresh_redact`] whenever
+    /// the buffer changes.
+    pub redact_enabled: bool,
+
+    /// Set by `:ansi-view` while ANSI SGR color interpretation is active
+    /// for this document. The escape-hiding and recoloring overlays
+    /// themselves are kept in `overlay_text` under the `"ansi-escape"`
+    /// owner and one owner per color scope (e.g. `"ansi.red"`), recomputed
+    /// by [`Self::refresh_ansi_view`] whenever the buffer changes.
+    pub ansi_view_enabled: bool,
+
+    /// Buffer-local marks set with `:mark-set` (lowercase names). Unlike
+    /// [`crate::marks::GlobalMarkStore`], these live only as long as the
+    /// document does and are never persisted to disk. Stored as
+    /// `(line, column)` rather than a [`crate::Selection`] so they match the
+    /// same "doesn't track later edits" trade-off as global marks.
+    pub marks: HashMap<char, (usize, usize)>,
+
+    /// Named grapheme-overlay layers contributed by features that need to
+    /// replace rendered text rather than just add to it (e.g. `:redact`).
+    /// See [`OverlayLayer`].
+    pub(crate) overlay_text: HashMap<&'static str, OverlayLayer>,
+
     path: Option<PathBuf>,
     encoding: &'static encoding::Encoding,
     has_bom: bool,
@@ -160,6 +237,19 @@ pub struct Document {
 
     savepoints: Vec<Weak<SavePoint>>,
 
+    /// Named undo checkpoints, most recently created last. See
+    /// [`Self::create_checkpoint`].
+    checkpoints: Vec<Checkpoint>,
+
+    /// Append-only log of every transaction committed to this document,
+    /// independent of `history` (which undo/redo rewinds). See
+    /// [`helix_core::sync_log`].
+    pub sync_log: SyncLog,
+
+    /// Overrides [`Self::display_name`] for a pathless document, e.g.
+    /// [`STDIN_BUFFER_NAME`] for a document read from stdin.
+    pub(crate) scratch_buffer_name: Option<&'static str>,
+
     // Last time we wrote to the file. This will carry the time the file was last opened if there
     // were no saves.
     last_saved_time: SystemTime,
@@ -179,6 +269,14 @@ pub struct Document {
 
     pub readonly: bool,
     pub copilot_state: Arc<Mutex<CopilotState>>,
+
+    /// Whether this buffer is pinned to the left of the bufferline. See
+    /// [`Editor::move_buffer`](crate::editor::Editor::move_buffer).
+    pub pinned: bool,
+
+    /// Per-document override of `editor.lsp.inline-diagnostics.enabled`, set
+    /// by `:toggle-inline-diagnostics`. `None` defers to the global config.
+    pub inline_diagnostics: Option<bool>,
 }
 
 #[derive(Clone)]
@@ -288,6 +386,44 @@ pub fn get_transaction(&self, doc: &Rope) -> Option<Transaction> {
             self.offset_encoding?,
         ))
     }
+
+    /// Returns a transaction that inserts just the first word of the ghost
+    /// text at the cursor, e.g. for an `accept-word` keybinding, rather than
+    /// the whole suggestion. Unlike [`Self::get_transaction`], this ignores
+    /// the LSP-provided replacement range and always inserts at `pos`, since
+    /// a partial accept has no well-defined range of its own.
+    pub fn get_word_transaction(&self, doc: &Rope) -> Option<Transaction> {
+        let Completion { text, pos, .. } = self.get_completion()?;
+        let end = ghost_text_word_boundary(text);
+        Some(Transaction::insert(
+            doc,
+            &Selection::point(*pos),
+            Tendril::from(&text[..end]),
+        ))
+    }
+
+    /// Returns a transaction that inserts the ghost text up to and including
+    /// its first line break, or all of it if it's a single line. See
+    /// [`Self::get_word_transaction`] for why this always inserts at `pos`
+    /// rather than reusing the LSP-provided range.
+    pub fn get_line_transaction(&self, doc: &Rope) -> Option<Transaction> {
+        let Completion { text, pos, .. } = self.get_completion()?;
+        let end = text.find('\n').map_or(text.len(), |i| i + 1);
+        Some(Transaction::insert(
+            doc,
+            &Selection::point(*pos),
+            Tendril::from(&text[..end]),
+        ))
+    }
+}
+
+/// The end of the first word in `text`, skipping any leading whitespace.
+/// Returns `text.len()` if it contains no further whitespace.
+fn ghost_text_word_boundary(text: &str) -> usize {
+    let non_ws = text.find(|ch: char| !ch.is_whitespace()).unwrap_or(text.len());
+    text[non_ws..]
+        .find(char::is_whitespace)
+        .map_or(text.len(), |i| non_ws + i)
 }
 
 #[derive(Clone)]
@@ -350,6 +486,59 @@ pub fn empty_with_id(id: DocumentInlayHintsId) -> Self {
     }
 }
 
+/// A single named layer of virtual text, e.g. a blame annotation or a debug
+/// adapter's inline values.
+///
+/// Layers are combined by [`crate::View::text_annotations`] alongside the
+/// built-in inlay-hint and diagnostic layers, ordered by `priority` (lowest
+/// first, so higher-priority layers win when annotations at the same
+/// position overlap). Registering a layer under an `owner` that already has
+/// one replaces it, so a feature only ever clobbers its own previous state,
+/// never another feature's.
+#[derive(Debug, Clone)]
+pub struct VirtualTextLayer {
+    pub priority: i32,
+    pub annotations: Rc<[InlineAnnotation]>,
+    /// Theme scope (e.g. `"ui.virtual.inlay-hint"`) used to style this
+    /// layer, resolved to a `Highlight` when the annotations are rendered.
+    pub highlight_scope: Option<String>,
+}
+
+impl VirtualTextLayer {
+    pub fn new(
+        priority: i32,
+        annotations: impl Into<Rc<[InlineAnnotation]>>,
+        highlight_scope: Option<String>,
+    ) -> Self {
+        Self {
+            priority,
+            annotations: annotations.into(),
+            highlight_scope,
+        }
+    }
+}
+
+/// A single named layer of grapheme overlays, e.g. redacted secrets.
+/// Combined by [`crate::View::text_annotations`] the same way as
+/// [`VirtualTextLayer`], but each entry *replaces* a grapheme's rendered
+/// form rather than inserting extra text.
+#[derive(Debug, Clone)]
+pub struct OverlayLayer {
+    pub overlays: Rc<[Overlay]>,
+    /// Theme scope used to style this layer, resolved to a `Highlight` when
+    /// the overlays are rendered.
+    pub highlight_scope: Option<String>,
+}
+
+impl OverlayLayer {
+    pub fn new(overlays: impl Into<Rc<[Overlay]>>, highlight_scope: Option<String>) -> Self {
+        Self {
+            overlays: overlays.into(),
+            highlight_scope,
+        }
+    }
+}
+
 /// Associated with a [`Document`] and [`ViewId`], uniquely identifies the state of inlay hints for
 /// for that document and view: if this changed since the last save, the inlay hints for the view
 /// should be recomputed.
@@ -766,6 +955,16 @@ pub fn from(
             selections: HashMap::default(),
             inlay_hints: HashMap::default(),
             inlay_hints_oudated: false,
+            code_lens: Vec::new(),
+            code_lens_language_server_id: None,
+            document_colors: Vec::new(),
+            document_colors_language_server_id: None,
+            virtual_text: HashMap::default(),
+            csv_delimiter: None,
+            redact_enabled: false,
+            ansi_view_enabled: false,
+            marks: HashMap::default(),
+            overlay_text: HashMap::default(),
             indent_style: DEFAULT_INDENT,
             line_ending,
             restore_cursor: false,
@@ -777,6 +976,9 @@ pub fn from(
             version: 0,
             history: Cell::new(History::default()),
             savepoints: Vec::new(),
+            checkpoints: Vec::new(),
+            sync_log: SyncLog::default(),
+            scratch_buffer_name: None,
             last_saved_time: SystemTime::now(),
             last_saved_revision: 0,
             modified_since_accessed: false,
@@ -787,6 +989,8 @@ pub fn from(
             focused_at: std::time::Instant::now(),
             readonly: false,
             copilot_state: Arc::new(Mutex::new(CopilotState::new(copilot_auto))),
+            pinned: false,
+            inline_diagnostics: None,
         }
     }
 
@@ -1045,25 +1249,33 @@ pub fn detect_language(&mut self, config_loader: Arc<syntax::Loader>) {
         );
     }
 
-    /// Detect the programming language based on the file type.
+    /// Detect the programming language based on the file type, falling back
+    /// to a shebang line in the buffer's content if the file name doesn't
+    /// match (or there is no file name at all, as for a document read from
+    /// stdin).
     pub fn detect_language_config(
         &self,
         config_loader: &syntax::Loader,
     ) -> Option<Arc<helix_core::syntax::LanguageConfiguration>> {
-        config_loader
-            .language_config_for_file_name(self.path.as_ref()?)
+        self.path
+            .as_ref()
+            .and_then(|path| config_loader.language_config_for_file_name(path))
             .or_else(|| config_loader.language_config_for_shebang(self.text().slice(..)))
     }
 
-    /// Detect the indentation used in the file, or otherwise defaults to the language indentation
-    /// configured in `languages.toml`, with a fallback to tabs if it isn't specified. Line ending
-    /// is likewise auto-detected, and will remain unchanged if no line endings were detected.
+    /// Uses the language indentation configured in `languages.toml` if set, otherwise
+    /// falls back to heuristically detecting the indentation from the buffer's content,
+    /// and falls back to tabs if neither yields a confident result. There is no
+    /// editorconfig support to consult here, just the language config and the
+    /// heuristic. Line ending is likewise auto-detected, and will remain unchanged if
+    /// no line endings were detected.
     pub fn detect_indent_and_line_ending(&mut self) {
-        self.indent_style = auto_detect_indent_style(&self.text).unwrap_or_else(|| {
-            self.language_config()
-                .and_then(|config| config.indent.as_ref())
-                .map_or(DEFAULT_INDENT, |config| IndentStyle::from_str(&config.unit))
-        });
+        self.indent_style = self
+            .language_config()
+            .and_then(|config| config.indent.as_ref())
+            .map(|config| IndentStyle::from_str(&config.unit))
+            .or_else(|| auto_detect_indent_style(&self.text))
+            .unwrap_or(DEFAULT_INDENT);
         if let Some(line_ending) = auto_detect_line_ending(&self.text) {
             self.line_ending = line_ending;
         }
@@ -1184,6 +1396,8 @@ pub fn set_language(
             self.syntax = None;
             self.language = None;
         };
+
+        self.refresh_line_length_diagnostics();
     }
 
     /// Set the programming language for the file if you know the name (scope) but don't have the
@@ -1386,6 +1600,8 @@ fn apply_impl(
             };
 
             self.inlay_hints_oudated = true;
+            self.reset_code_lens();
+            self.reset_document_colors();
             for text_annotation in self.inlay_hints.values_mut() {
                 let DocumentInlayHints {
                     id: _,
@@ -1458,6 +1674,65 @@ pub fn send_copilot_completion(&self, view_id: ViewId) {
         }
     }
 
+    /// Requests inline completions from the first configured language
+    /// server that supports `textDocument/inlineCompletion`, rendering the
+    /// result through the same ghost-text pipeline as
+    /// [`Self::send_copilot_completion`]. Gated on both the server
+    /// capability and `lsp.display-inline-completions`, mirroring how
+    /// inlay hints are gated on `lsp.display-inlay-hints`. If a Copilot
+    /// server is also active for this document, whichever completion
+    /// arrives last wins, since both populate the same `copilot_state`.
+    pub fn send_lsp_inline_completion(&self, view_id: ViewId, config: &Config) {
+        if !config.lsp.display_inline_completions {
+            return;
+        }
+
+        let Some(language_server) = self
+            .language_servers_with_feature(LanguageServerFeature::InlineCompletion)
+            .next()
+        else {
+            return;
+        };
+
+        let position = self.position(view_id, language_server.offset_encoding());
+        let offset_encoding = language_server.offset_encoding();
+        let Some(future) = language_server.text_document_inline_completion(self.identifier(), position)
+        else {
+            return;
+        };
+
+        let doc_text = self.text().clone();
+        let copilot_state = self.copilot_state.clone();
+
+        tokio::spawn(async move {
+            let Ok(response) = future.await else {
+                return;
+            };
+            let Ok(Some(response)) =
+                serde_json::from_value::<Option<lsp::InlineCompletionResponse>>(response)
+            else {
+                return;
+            };
+            let items = match response {
+                lsp::InlineCompletionResponse::Array(items) => items,
+                lsp::InlineCompletionResponse::List(list) => list.items,
+            };
+            let Some(item) = items.into_iter().next() else {
+                return;
+            };
+
+            let completion = copilot_types::Completion::from_inline_completion_item(item, position);
+            let mut state = copilot_state.lock();
+            state.populate(
+                copilot_types::CompletionResponse {
+                    completions: vec![completion],
+                },
+                &doc_text,
+                offset_encoding,
+            );
+        });
+    }
+
     fn copilot_document(
         &self,
         view_id: ViewId,
@@ -1627,6 +1902,54 @@ pub fn later(&mut self, view: &mut View, uk: UndoKind) -> bool {
         self.earlier_later_impl(view, uk, false)
     }
 
+    /// Records a named checkpoint at the document's current revision, so it
+    /// can be returned to later with [`Self::restore_checkpoint`] regardless
+    /// of how much undo/redo or further editing happens in between.
+    pub fn create_checkpoint(&mut self, name: String) -> &Checkpoint {
+        let history = self.history.take();
+        let revision = history.current_revision();
+        self.history.set(history);
+
+        self.checkpoints.push(Checkpoint {
+            name,
+            revision,
+            created_at: SystemTime::now(),
+        });
+        self.checkpoints.last().unwrap()
+    }
+
+    /// Named checkpoints recorded for this document, oldest first.
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Restores the document to the revision recorded by the most recently
+    /// created checkpoint named `name`. Returns whether a checkpoint with
+    /// that name was found.
+    pub fn restore_checkpoint(&mut self, view: &mut View, name: &str) -> bool {
+        let Some(checkpoint) = self.checkpoints.iter().rev().find(|c| c.name == name) else {
+            return false;
+        };
+        let revision = checkpoint.revision;
+
+        let mut history = self.history.take();
+        let txns = history.jump_to(revision);
+        self.history.set(history);
+
+        let mut success = false;
+        for txn in &txns {
+            if self.apply_impl(txn, view.id, true) {
+                success = true;
+            }
+        }
+        if success {
+            // reset changeset to fix len
+            self.changes = ChangeSet::new(self.text().slice(..));
+            view.sync_changes(self);
+        }
+        success
+    }
+
     /// Commit pending changes to history
     pub fn append_changes_to_history(&mut self, view: &mut View) {
         if self.changes.is_empty() {
@@ -1647,6 +1970,8 @@ pub fn append_changes_to_history(&mut self, view: &mut View) {
         history.commit_revision(&transaction, &old_state);
         self.history.set(history);
 
+        self.sync_log.record(&transaction);
+
         // Update jumplist entries in the view.
         view.apply(&transaction, self);
     }
@@ -1811,6 +2136,23 @@ pub fn syntax(&self) -> Option<&Syntax> {
         self.syntax.as_ref()
     }
 
+    /// The breadcrumb path to `pos` through the document's syntax tree, for
+    /// structured data formats (e.g. `spec.containers[0].image`). See
+    /// [`helix_core::syntax::structure_path::structure_path`] for which
+    /// languages are supported.
+    pub fn structure_path(&self, pos: usize) -> Option<String> {
+        let syntax = self.syntax()?;
+        let language_id = self.language_name()?;
+        let text = self.text().slice(..);
+        let byte_pos = text.char_to_byte(pos);
+        helix_core::syntax::structure_path::structure_path(
+            syntax.tree().root_node(),
+            text,
+            byte_pos,
+            language_id,
+        )
+    }
+
     /// The width that the tab character is rendered at
     pub fn tab_width(&self) -> usize {
         self.language_config()
@@ -1862,7 +2204,7 @@ pub fn relative_path(&self) -> Option<PathBuf> {
     pub fn display_name(&self) -> Cow<'static, str> {
         self.relative_path()
             .map(|path| path.to_string_lossy().to_string().into())
-            .unwrap_or_else(|| SCRATCH_BUFFER_NAME.into())
+            .unwrap_or_else(|| self.scratch_buffer_name.unwrap_or(SCRATCH_BUFFER_NAME).into())
     }
 
     // transact(Fn) ?
@@ -1899,8 +2241,10 @@ pub fn diagnostics(&self) -> &[Diagnostic] {
 
     pub fn shown_diagnostics(&self) -> impl Iterator<Item = &Diagnostic> + DoubleEndedIterator {
         self.diagnostics.iter().filter(|d| {
-            self.language_servers_with_feature(LanguageServerFeature::Diagnostics)
-                .any(|ls| ls.id() == d.language_server_id)
+            d.language_server_id == helix_core::line_length::BUILTIN_LANGUAGE_SERVER_ID
+                || self
+                    .language_servers_with_feature(LanguageServerFeature::Diagnostics)
+                    .any(|ls| ls.id() == d.language_server_id)
         })
     }
 
@@ -1940,6 +2284,40 @@ pub fn clear_diagnostics(&mut self, language_server_id: usize) {
             .retain(|d| d.language_server_id != language_server_id);
     }
 
+    fn line_length_diagnostic_enabled(&self) -> bool {
+        self.language_config()
+            .and_then(|config| config.line_length_diagnostic)
+            .unwrap_or(self.config.load().line_length_diagnostic.enable)
+    }
+
+    /// Recomputes the soft line-length budget diagnostics (see
+    /// `editor.line-length-diagnostic`) from the current buffer contents.
+    /// Called after edits, the same way `refresh_redact`/`refresh_csv_align`
+    /// keep their own derived state in sync.
+    pub fn refresh_line_length_diagnostics(&mut self) {
+        if !self.line_length_diagnostic_enabled() {
+            self.clear_diagnostics(helix_core::line_length::BUILTIN_LANGUAGE_SERVER_ID);
+            return;
+        }
+
+        let config = self.config.load();
+        let text_width = self
+            .language_config()
+            .and_then(|config| config.text_width)
+            .unwrap_or(config.text_width);
+        let severity = config.line_length_diagnostic.severity;
+        let diagnostics = helix_core::line_length::line_length_diagnostics(
+            self.text().slice(..),
+            text_width,
+            severity,
+        );
+        self.replace_diagnostics(
+            diagnostics,
+            &[],
+            helix_core::line_length::BUILTIN_LANGUAGE_SERVER_ID,
+        );
+    }
+
     /// Get the document's auto pairs. If the document has a recognized
     /// language config with auto pairs configured, returns that;
     /// otherwise, falls back to the global auto pairs config. If the global
@@ -1982,6 +2360,26 @@ pub fn text_format(&self, mut viewport_width: u16, theme: Option<&Theme>) -> Tex
         if soft_wrap_at_text_width {
             viewport_width = text_width as u16;
         }
+        // `text-width-percentage` expresses the wrap column relative to the
+        // viewport instead of a fixed `text-width`, so it's recomputed here
+        // from the current `viewport_width` on every call (i.e. every
+        // resize) rather than being resolved once like `text_width` above.
+        let text_width_percentage = self
+            .language_config()
+            .and_then(|config| {
+                config
+                    .soft_wrap
+                    .as_ref()
+                    .and_then(|soft_wrap| soft_wrap.text_width_percentage)
+            })
+            .or(config.soft_wrap.text_width_percentage);
+        if let Some(percentage) = text_width_percentage {
+            let percentage_width =
+                (viewport_width as usize * percentage.min(100) as usize / 100).max(1) as u16;
+            if percentage_width < viewport_width {
+                viewport_width = percentage_width;
+            }
+        }
         let config = self.config.load();
         let editor_soft_wrap = &config.soft_wrap;
         let language_soft_wrap = self
@@ -2017,7 +2415,7 @@ pub fn text_format(&self, mut viewport_width: u16, theme: Option<&Theme>) -> Tex
             wrap_indicator_highlight: theme
                 .and_then(|theme| theme.find_scope_index("ui.virtual.wrap"))
                 .map(Highlight),
-            soft_wrap_at_text_width,
+            soft_wrap_at_text_width: soft_wrap_at_text_width || text_width_percentage.is_some(),
         }
     }
 
@@ -2036,8 +2434,256 @@ pub fn inlay_hints(&self, view_id: ViewId) -> Option<&DocumentInlayHints> {
     pub fn reset_all_inlay_hints(&mut self) {
         self.inlay_hints = Default::default();
     }
+
+    /// Stores freshly fetched `textDocument/codeLens` results and rebuilds
+    /// the `"code-lens"` virtual-text layer from them, one annotation per
+    /// lens placed at the start of its range's first line (e.g. `3
+    /// references | Run test`, matching how editors like VS Code stack
+    /// lenses above the line they annotate).
+    pub fn set_code_lens(
+        &mut self,
+        language_server_id: usize,
+        code_lens: Vec<lsp::CodeLens>,
+        offset_encoding: OffsetEncoding,
+    ) {
+        let mut annotations = Vec::with_capacity(code_lens.len());
+        let text = self.text();
+        for lens in &code_lens {
+            let Some(char_idx) = lsp_pos_to_pos(text, lens.range.start, offset_encoding) else {
+                continue;
+            };
+            let line = text.char_to_line(char_idx);
+            let line_start = text.line_to_char(line);
+            let title = lens
+                .command
+                .as_ref()
+                .map_or("<unresolved code lens>", |command| command.title.as_str());
+            annotations.push(InlineAnnotation::new(line_start, format!("{title} ")));
+        }
+
+        self.code_lens_language_server_id = Some(language_server_id);
+        self.code_lens = code_lens;
+        self.set_virtual_text(
+            "code-lens",
+            VirtualTextLayer::new(0, annotations, Some("ui.virtual.inlay-hint".to_string())),
+        );
+    }
+
+    /// Drops any fetched code lenses and their virtual-text layer, e.g.
+    /// because the buffer changed and they're now stale.
+    pub fn reset_code_lens(&mut self) {
+        self.code_lens.clear();
+        self.code_lens_language_server_id = None;
+        self.remove_virtual_text("code-lens");
+    }
+
+    /// Returns the language server id and lens whose range contains `line`,
+    /// if any, for `:code-lens-execute` to run under the cursor.
+    pub fn code_lens_at_line(&self, line: usize) -> Option<(usize, &lsp::CodeLens)> {
+        let language_server_id = self.code_lens_language_server_id?;
+        self.code_lens
+            .iter()
+            .find(|lens| {
+                (lens.range.start.line as usize..=lens.range.end.line as usize).contains(&line)
+            })
+            .map(|lens| (language_server_id, lens))
+    }
+
+    /// Stores freshly fetched `textDocument/documentColor` results and
+    /// rebuilds the `"document-color"` virtual-text layer from them.
+    ///
+    /// Terminal cells are styled from a fixed theme scope rather than the
+    /// literal's own RGB value: doing otherwise would mean allocating an ad
+    /// hoc [`helix_core::syntax::Highlight`] per distinct color, which the
+    /// text-annotation rendering path isn't set up for. The resolved hex
+    /// value is still shown as text so the swatch stays useful without it.
+    pub fn set_document_colors(
+        &mut self,
+        language_server_id: usize,
+        colors: Vec<lsp::ColorInformation>,
+        offset_encoding: OffsetEncoding,
+    ) {
+        let mut annotations = Vec::with_capacity(colors.len());
+        let text = self.text();
+        for color in &colors {
+            let Some(char_idx) = lsp_pos_to_pos(text, color.range.start, offset_encoding) else {
+                continue;
+            };
+            let lsp::Color { red, green, blue, .. } = color.color;
+            let label = format!(
+                "■ #{:02x}{:02x}{:02x} ",
+                (red * 255.0).round() as u8,
+                (green * 255.0).round() as u8,
+                (blue * 255.0).round() as u8,
+            );
+            annotations.push(InlineAnnotation::new(char_idx, label));
+        }
+
+        self.document_colors_language_server_id = Some(language_server_id);
+        self.document_colors = colors;
+        self.set_virtual_text(
+            "document-color",
+            VirtualTextLayer::new(0, annotations, Some("ui.virtual.inlay-hint".to_string())),
+        );
+    }
+
+    /// Drops any fetched document colors and their virtual-text layer, e.g.
+    /// because the buffer changed and they're now stale.
+    pub fn reset_document_colors(&mut self) {
+        self.document_colors.clear();
+        self.document_colors_language_server_id = None;
+        self.remove_virtual_text("document-color");
+    }
+
+    /// Returns the language server id and color literal whose range
+    /// contains `char_idx`, if any, for `:document-color-presentation`.
+    pub fn document_color_at(&self, char_idx: usize, offset_encoding: OffsetEncoding) -> Option<(usize, &lsp::ColorInformation)> {
+        let language_server_id = self.document_colors_language_server_id?;
+        let text = self.text();
+        self.document_colors
+            .iter()
+            .find(|color| {
+                let Some(start) = lsp_pos_to_pos(text, color.range.start, offset_encoding) else {
+                    return false;
+                };
+                let Some(end) = lsp_pos_to_pos(text, color.range.end, offset_encoding) else {
+                    return false;
+                };
+                (start..=end).contains(&char_idx)
+            })
+            .map(|color| (language_server_id, color))
+    }
+
+    /// Registers (or replaces) a named virtual-text layer for this document.
+    /// `owner` should be a stable identifier for the contributing feature
+    /// (e.g. `"blame"`, `"dap-values"`) so unrelated features never clobber
+    /// each other's annotations, only their own previous state.
+    pub fn set_virtual_text(&mut self, owner: &'static str, layer: VirtualTextLayer) {
+        self.virtual_text.insert(owner, layer);
+    }
+
+    /// Removes the named virtual-text layer, if any.
+    pub fn remove_virtual_text(&mut self, owner: &'static str) {
+        self.virtual_text.remove(owner);
+    }
+
+    /// Iterates over the currently registered virtual-text layers.
+    pub fn virtual_text_layers(&self) -> impl Iterator<Item = (&'static str, &VirtualTextLayer)> {
+        self.virtual_text.iter().map(|(&owner, layer)| (owner, layer))
+    }
+
+    /// Enables or disables `:csv-align` column alignment for this document
+    /// and recomputes it immediately. Pass `None` to disable.
+    pub fn set_csv_delimiter(&mut self, delimiter: Option<char>) {
+        self.csv_delimiter = delimiter;
+        self.refresh_csv_align();
+    }
+
+    /// Recomputes the `"csv-align"` virtual-text layer from the current
+    /// buffer contents. Called after edits so alignment stays in sync as
+    /// fields grow or shrink.
+    pub fn refresh_csv_align(&mut self) {
+        match self.csv_delimiter {
+            Some(delimiter) => {
+                let annotations = helix_core::csv::column_annotations(self.text().slice(..), delimiter);
+                self.set_virtual_text(
+                    "csv-align",
+                    VirtualTextLayer::new(0, annotations, None),
+                );
+            }
+            None => self.remove_virtual_text("csv-align"),
+        }
+    }
+
+    /// Registers (or replaces) a named overlay layer for this document. See
+    /// [`OverlayLayer`] and [`Self::set_virtual_text`].
+    pub fn set_overlay_text(&mut self, owner: &'static str, layer: OverlayLayer) {
+        self.overlay_text.insert(owner, layer);
+    }
+
+    /// Removes the named overlay layer, if any.
+    pub fn remove_overlay_text(&mut self, owner: &'static str) {
+        self.overlay_text.remove(owner);
+    }
+
+    /// Iterates over the currently registered overlay layers.
+    pub fn overlay_text_layers(&self) -> impl Iterator<Item = (&'static str, &OverlayLayer)> {
+        self.overlay_text.iter().map(|(&owner, layer)| (owner, layer))
+    }
+
+    /// Enables or disables `:redact` secret masking for this document and
+    /// recomputes it immediately. Pass `false` to disable.
+    pub fn set_redact_enabled(&mut self, enabled: bool) {
+        self.redact_enabled = enabled;
+        self.refresh_redact();
+    }
+
+    /// Recomputes the `"redact"` overlay layer from the current buffer
+    /// contents. Called after edits so newly-typed secrets get masked too.
+    pub fn refresh_redact(&mut self) {
+        if self.redact_enabled {
+            let overlays =
+                helix_core::redact::redaction_overlays(self.text().slice(..), helix_core::redact::DEFAULT_PATTERNS);
+            self.set_overlay_text("redact", OverlayLayer::new(overlays, Some("ui.virtual.redact".to_string())));
+        } else {
+            self.remove_overlay_text("redact");
+        }
+    }
+
+    /// Enables or disables `:ansi-view` ANSI color interpretation for this
+    /// document and recomputes it immediately. Pass `false` to disable.
+    pub fn set_ansi_view_enabled(&mut self, enabled: bool) {
+        self.ansi_view_enabled = enabled;
+        self.refresh_ansi_view();
+    }
+
+    /// Recomputes the `"ansi-escape"` and per-color overlay layers from the
+    /// current buffer contents. Called after edits so newly-appended
+    /// colored output (e.g. a streaming build log) stays interpreted.
+    pub fn refresh_ansi_view(&mut self) {
+        if self.ansi_view_enabled {
+            let (escapes, spans) = helix_core::ansi::ansi_overlays(self.text().slice(..));
+            self.set_overlay_text("ansi-escape", OverlayLayer::new(escapes, None));
+            for owner in ANSI_OVERLAY_SCOPES.iter().copied() {
+                self.remove_overlay_text(owner);
+            }
+            for span in spans {
+                self.set_overlay_text(
+                    span.scope,
+                    OverlayLayer::new(span.overlays, Some(span.scope.to_string())),
+                );
+            }
+        } else {
+            self.remove_overlay_text("ansi-escape");
+            for owner in ANSI_OVERLAY_SCOPES.iter().copied() {
+                self.remove_overlay_text(owner);
+            }
+        }
+    }
 }
 
+/// The fixed set of overlay owners (one per interpreted ANSI color scope)
+/// that [`Document::refresh_ansi_view`] may register, so it can clear all
+/// of them on every recompute without tracking which ones were last used.
+const ANSI_OVERLAY_SCOPES: &[&str] = &[
+    "ansi.black",
+    "ansi.red",
+    "ansi.green",
+    "ansi.yellow",
+    "ansi.blue",
+    "ansi.magenta",
+    "ansi.cyan",
+    "ansi.white",
+    "ansi.bright-black",
+    "ansi.bright-red",
+    "ansi.bright-green",
+    "ansi.bright-yellow",
+    "ansi.bright-blue",
+    "ansi.bright-magenta",
+    "ansi.bright-cyan",
+    "ansi.bright-white",
+];
+
 #[derive(Clone, Debug)]
 pub enum FormatterError {
     SpawningFailed {
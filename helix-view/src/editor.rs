@@ -2,10 +2,13 @@
     align_view,
     annotations::diagnostics::InlineDiagnosticsConfig,
     document::{DocumentSavedEventFuture, DocumentSavedEventResult, Mode, SavePoint},
+    fs_watcher,
     graphics::{CursorKind, Rect},
     handlers::Handlers,
     info::Info,
     input::KeyEvent,
+    language_overrides, marks, notes, notification,
+    quickfix::QuickfixList,
     register::Registers,
     theme::{self, Theme},
     tree::{self, Tree},
@@ -44,8 +47,9 @@
 pub use helix_core::diagnostic::Severity;
 use helix_core::{
     auto_pairs::AutoPairs,
+    movement::Direction,
     syntax::{self, AutoPairConfig, SoftWrap},
-    Change, LineEnding, NATIVE_LINE_ENDING,
+    Change, LineEnding, Transaction, NATIVE_LINE_ENDING,
 };
 use helix_core::{Position, Selection};
 use helix_dap as dap;
@@ -194,6 +198,10 @@ pub struct FilePickerConfig {
     /// WalkBuilder options
     /// Maximum Depth to recurse directories in file picker and global search. Defaults to `None`.
     pub max_depth: Option<usize>,
+    /// Maximum number of file previews the picker keeps cached at once,
+    /// evicting the least-recently viewed entry once the cap is reached.
+    /// Defaults to 100.
+    pub preview_cache_size: usize,
 }
 
 impl Default for FilePickerConfig {
@@ -208,6 +216,59 @@ fn default() -> Self {
             git_global: true,
             git_exclude: true,
             max_depth: None,
+            preview_cache_size: 100,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PreviewPosition {
+    /// Preview to the right of the picker
+    Right,
+    /// Preview below the picker
+    Below,
+    /// Never show the preview
+    Hidden,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default, deny_unknown_fields)]
+pub struct PickerConfig {
+    /// Where to render the preview pane, or whether to hide it. Defaults to `right`.
+    pub preview_position: PreviewPosition,
+    /// Percentage of the picker's area given to the picker list itself, with
+    /// the rest going to the preview pane. Ignored when `preview-position`
+    /// is `below` or `hidden`. Defaults to 50.
+    pub preview_ratio: u8,
+    /// Show a file-type icon column before the path in file, buffer and
+    /// symbol pickers. Defaults to `false`, since it requires a terminal
+    /// font patched with Nerd Font glyphs (see `icons-nerd-font`).
+    pub icons: bool,
+    /// Whether the icon column (when `icons` is enabled) renders Nerd Font
+    /// glyphs or a plain-text placeholder. Defaults to `true`.
+    pub icons_nerd_font: bool,
+    /// Weight (0-100) given to frecency -- how frequently/recently an item
+    /// has been accepted from a picker that opts into tracking (see
+    /// `Picker::with_frecency_id`) -- when ranking matched items, blended
+    /// with the fuzzy match score. `0` disables frecency ranking entirely.
+    /// Defaults to `25`.
+    pub frecency_weight: u8,
+    /// Maximum number of currently matched items that `Ctrl-o` will open at
+    /// once as background buffers, for pickers backed by a file location
+    /// (see `Picker::with_preview`). Defaults to `20`.
+    pub batch_open_limit: u32,
+}
+
+impl Default for PickerConfig {
+    fn default() -> Self {
+        Self {
+            preview_position: PreviewPosition::Right,
+            preview_ratio: 50,
+            icons: false,
+            icons_nerd_font: true,
+            frecency_weight: 25,
+            batch_open_limit: 20,
         }
     }
 }
@@ -237,10 +298,20 @@ pub struct Config {
     /// etc. Optionally, this can be a list of 2-tuples to specify a
     /// global list of characters to pair. Defaults to true.
     pub auto_pairs: AutoPairConfig,
+    /// Wrap a non-empty selection in the relevant pair instead of replacing
+    /// it when typing an opening character from `auto-pairs`. Applies to all
+    /// configured pairs; `auto-pairs`'s schema has no room for a per-pair
+    /// flag without a breaking change. Defaults to true.
+    pub auto_pairs_surround_selections: bool,
     /// Automatic auto-completion, automatically pop up without user trigger. Defaults to true.
     pub auto_completion: bool,
     /// Automatic formatting on save. Defaults to true.
     pub auto_format: bool,
+    /// Glob patterns matched against a document's path for which `auto-format`
+    /// is skipped on save even though it's otherwise enabled, e.g. vendored or
+    /// generated code the user doesn't want reformatted. Matched relative to
+    /// the workspace root the same way ignore-file globs are. Defaults to empty.
+    pub auto_format_exclude: Vec<String>,
     /// Automatic save on focus lost. Defaults to false.
     pub auto_save: bool,
     /// Set a global text_width
@@ -274,6 +345,8 @@ pub struct Config {
     )]
     pub auto_info_delay: Duration,
     pub file_picker: FilePickerConfig,
+    /// Configuration of picker layout, e.g. the preview pane's position and size.
+    pub picker: PickerConfig,
     /// Configuration of the statusline elements
     pub statusline: StatusLineConfig,
     /// Shape for cursor in each mode
@@ -308,6 +381,55 @@ pub struct Config {
     pub smart_tab: Option<SmartTabConfig>,
     /// Whether to render rainbow highlights. Defaults to `false`.
     pub rainbow_brackets: bool,
+    /// Render non-printable ASCII control characters (other than tab and
+    /// newline, which are handled by `whitespace`) as `^X` mnemonics instead
+    /// of passing them through to the terminal. Defaults to `true`.
+    pub render_control_characters: bool,
+    /// What to do when pasting a register with a different number of values
+    /// than there are selections. Defaults to `repeat`.
+    pub paste_selection_mismatch: PasteSelectionMismatch,
+    /// Soft line-length budget, reported as diagnostics rather than only a
+    /// `rulers` line. Disabled by default; can also be turned on per
+    /// language via `[[language]] line-length-diagnostic.enable = true`.
+    pub line_length_diagnostic: LineLengthDiagnosticConfig,
+    /// Configuration for the polling file watcher backing `:log-follow`.
+    /// See [`helix_view::fs_watcher`].
+    pub file_watcher: FileWatcherConfig,
+    /// Degrades rendering for slow connections (e.g. over SSH): disables
+    /// `cursorline`, `cursorcolumn`, `indent-guides` and rainbow bracket
+    /// highlighting regardless of their own settings, and coalesces redraws
+    /// less eagerly (see `Editor::redraw_debounce`). There's no terminal
+    /// round-trip latency probe in this codebase, so unlike the rest of
+    /// this list, auto-detection isn't implemented — this is config-only.
+    /// Defaults to `false`.
+    pub low_bandwidth: bool,
+    /// Tunables for the nucleo fuzzy matcher, applied consistently across
+    /// pickers, completion filtering and prompt completers. Published into
+    /// [`helix_core::fuzzy::FUZZY_MATCHING_CONFIG`] by [`Editor::new`] and
+    /// [`Editor::refresh_config`] so code that can't reach `Editor::config()`
+    /// (the matcher helpers in `helix-core::fuzzy`, the picker render path)
+    /// still sees an up-to-date value.
+    pub fuzzy_matching: helix_core::fuzzy::FuzzyMatchingConfig,
+    /// Move files and directories deleted through the file-tree explorer,
+    /// `:remove`/`:delete-file` or an LSP workspace edit into
+    /// [`helix_loader::trash`] instead of removing them outright. Set to
+    /// `false` to delete permanently. Defaults to `true`.
+    pub trash_delete: bool,
+}
+
+/// Controls how [a paste](crate::view) fills in extra selections when the
+/// register being pasted holds a different number of values than there are
+/// selections.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PasteSelectionMismatch {
+    /// Repeat the last value for any selections beyond the register's values.
+    Repeat,
+    /// Cycle through the register's values, wrapping back to the first one.
+    Cycle,
+    /// Join all of the register's values together and paste the joined text
+    /// into every selection.
+    JoinAll,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Eq, PartialOrd, Ord)]
@@ -392,6 +514,8 @@ pub struct LspConfig {
     pub display_signature_help_docs: bool,
     /// Display inlay hints
     pub display_inlay_hints: bool,
+    /// Show inline completions from the language server as ghost text
+    pub display_inline_completions: bool,
     /// Whether to enable snippet support
     pub snippets: bool,
     /// Whether to include declaration in the goto reference query
@@ -411,6 +535,7 @@ fn default() -> Self {
             auto_signature_help: true,
             display_signature_help_docs: true,
             display_inlay_hints: false,
+            display_inline_completions: true,
             snippets: true,
             goto_reference_include_declaration: true,
             inline_diagnostics: InlineDiagnosticsConfig::default(),
@@ -437,6 +562,18 @@ pub struct StatusLineConfig {
     pub right: Vec<StatusLineElement>,
     pub separator: String,
     pub mode: ModeConfig,
+    /// Overrides `left`/`center`/`right` with a template string composed of
+    /// `{element-name}` placeholders (the same kebab-case names used in
+    /// `left`/`center`/`right`), literal text and `|` separators dividing
+    /// the left, center and right sections, e.g.
+    /// `"{mode} {file-name} | {diagnostics} | {position} {file-encoding}"`.
+    /// A placeholder can carry a `:modifier` suffix, e.g. `{file-name:trunc}`
+    /// to show the basename only, or `{diagnostics:warning}` to render that
+    /// segment in the given theme scope's color. Segments that render no
+    /// text (e.g. `{diagnostics}` with nothing to report) are skipped, same
+    /// as in the `left`/`center`/`right` lists. `None` (the default) keeps
+    /// using `left`/`center`/`right`.
+    pub format: Option<String>,
 }
 
 impl Default for StatusLineConfig {
@@ -450,17 +587,20 @@ fn default() -> Self {
                 E::FileName,
                 E::ReadOnlyIndicator,
                 E::FileModificationIndicator,
+                E::PinIndicator,
             ],
             center: vec![],
             right: vec![
                 E::Diagnostics,
                 E::Selections,
                 E::Register,
+                E::PendingKeys,
                 E::Position,
                 E::FileEncoding,
             ],
             separator: String::from("│"),
             mode: ModeConfig::default(),
+            format: None,
         }
     }
 }
@@ -545,6 +685,26 @@ pub enum StatusLineElement {
 
     /// Indicator for selected register
     Register,
+
+    /// The pending count, register and keys of an in-progress command, plus
+    /// the name of the active sticky keymap node, if any
+    PendingKeys,
+
+    /// An indicator that shows `"[pin]"` when the view is pinned
+    PinIndicator,
+
+    /// A breadcrumb path to the cursor through the current structured data
+    /// file (JSON, YAML, TOML), e.g. `spec.template.containers[0].image`.
+    /// Empty outside of those languages or when the cursor isn't inside a
+    /// keyed/indexed node.
+    StructurePath,
+
+    /// The document's indentation style, e.g. `spaces:4` or `tabs`.
+    FileIndentStyle,
+
+    /// An indicator that shows `"[auto-format off]"` when `auto-format` is
+    /// suppressed for the current buffer by `auto-format-exclude`.
+    AutoFormatIndicator,
 }
 
 // Cursor shape is read and used on every rendered frame and so needs
@@ -648,6 +808,8 @@ pub enum GutterType {
     Spacer,
     /// Highlight local changes
     Diff,
+    /// Show a sign on lines with a review note (see `helix_view::notes`)
+    Notes,
 }
 
 impl std::str::FromStr for GutterType {
@@ -659,8 +821,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "spacer" => Ok(Self::Spacer),
             "line-numbers" => Ok(Self::LineNumbers),
             "diff" => Ok(Self::Diff),
+            "notes" => Ok(Self::Notes),
             _ => anyhow::bail!(
-                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers` or `diff`."
+                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers`, `diff` or `notes`."
             ),
         }
     }
@@ -841,8 +1004,10 @@ fn default() -> Self {
             gutters: GutterConfig::default(),
             middle_click_paste: true,
             auto_pairs: AutoPairConfig::default(),
+            auto_pairs_surround_selections: true,
             auto_completion: true,
             auto_format: true,
+            auto_format_exclude: Vec::new(),
             auto_save: false,
             completion_timeout: Duration::from_millis(5),
             idle_timeout: Duration::from_millis(250),
@@ -851,6 +1016,7 @@ fn default() -> Self {
             auto_info: true,
             auto_info_delay: Duration::default(),
             file_picker: FilePickerConfig::default(),
+            picker: PickerConfig::default(),
             statusline: StatusLineConfig::default(),
             cursor_shape: CursorShapeConfig::default(),
             true_color: false,
@@ -874,6 +1040,105 @@ fn default() -> Self {
             insert_final_newline: true,
             smart_tab: Some(SmartTabConfig::default()),
             rainbow_brackets: true,
+            render_control_characters: true,
+            paste_selection_mismatch: PasteSelectionMismatch::Repeat,
+            line_length_diagnostic: LineLengthDiagnosticConfig::default(),
+            file_watcher: FileWatcherConfig::default(),
+            low_bandwidth: false,
+            fuzzy_matching: helix_core::fuzzy::FuzzyMatchingConfig::default(),
+            trash_delete: true,
+        }
+    }
+}
+
+impl Config {
+    /// Whether `auto-format` should be skipped for `path` because it matches
+    /// one of `auto-format-exclude`'s globs. Returns `false` (don't exclude)
+    /// for a document with no path, since a glob can't match it.
+    pub fn auto_format_excluded(&self, path: Option<&Path>) -> bool {
+        let Some(path) = path else {
+            return false;
+        };
+        self.auto_format_exclude.iter().any(|glob| {
+            globset::Glob::new(glob)
+                .map(|glob| glob.compile_matcher().is_match(path))
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// See [`Config::file_watcher`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct FileWatcherConfig {
+    /// How often watched files are re-checked for external changes, in
+    /// milliseconds. There's no OS-level filesystem watcher in this
+    /// codebase, so this interval is the only mechanism; see
+    /// `helix_view::fs_watcher`. Defaults to 2000ms.
+    #[serde(
+        serialize_with = "serialize_duration_millis",
+        deserialize_with = "deserialize_duration_millis"
+    )]
+    pub poll_interval: Duration,
+    /// Per-path overrides of `poll_interval`, most useful for slow network
+    /// filesystems (NFS, SSHFS, ...) where polling as eagerly as local
+    /// files would be wasteful. The first glob that matches a path wins;
+    /// falls back to `poll_interval` if none match. Defaults to empty.
+    pub overrides: Vec<FileWatcherOverride>,
+}
+
+impl FileWatcherConfig {
+    /// The poll interval to use for `path`: the interval of the first
+    /// matching entry in `overrides`, or `poll_interval` if none match.
+    pub fn poll_interval_for(&self, path: &Path) -> Duration {
+        self.overrides
+            .iter()
+            .find(|over| {
+                globset::Glob::new(&over.glob)
+                    .map(|glob| glob.compile_matcher().is_match(path))
+                    .unwrap_or(false)
+            })
+            .map_or(self.poll_interval, |over| over.poll_interval)
+    }
+}
+
+impl Default for FileWatcherConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(2000),
+            overrides: Vec::new(),
+        }
+    }
+}
+
+/// A single entry of [`FileWatcherConfig::overrides`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FileWatcherOverride {
+    pub glob: String,
+    #[serde(
+        serialize_with = "serialize_duration_millis",
+        deserialize_with = "deserialize_duration_millis"
+    )]
+    pub poll_interval: Duration,
+}
+
+/// See [`Config::line_length_diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct LineLengthDiagnosticConfig {
+    /// Whether lines over `editor.text-width` (or the language's own
+    /// `text-width` override) are flagged as diagnostics. Defaults to `false`.
+    pub enable: bool,
+    /// Severity to report the diagnostic at. Defaults to `hint`.
+    pub severity: Severity,
+}
+
+impl Default for LineLengthDiagnosticConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            severity: Severity::Hint,
         }
     }
 }
@@ -902,6 +1167,19 @@ pub struct Breakpoint {
 
 use futures_util::stream::{Flatten, Once};
 
+/// One workspace-wide edit, applied via `apply_workspace_edit`, recorded so
+/// it can be undone as a single unit. Stores an inverse transaction per file
+/// rather than reusing each document's own undo history, since by the time
+/// `:undo-workspace` runs the file may have been closed (and needs
+/// reopening) or edited further (which would make redoing on its history
+/// stack apply the wrong change).
+pub struct WorkspaceEditUndo {
+    /// Human-readable description, e.g. "rename symbol" or a label supplied
+    /// by the language server. Shown in the status line by `:undo-workspace`.
+    pub label: String,
+    pub file_undos: Vec<(PathBuf, Transaction)>,
+}
+
 pub struct Editor {
     /// Current editing mode.
     pub mode: Mode,
@@ -909,6 +1187,14 @@ pub struct Editor {
     pub next_document_id: DocumentId,
     pub documents: BTreeMap<DocumentId, Document>,
 
+    /// Order buffers appear in the bufferline, oldest-opened first. Kept in
+    /// sync with `documents` as buffers open and close, and otherwise only
+    /// reordered by [`Self::move_buffer`] (`:buffer-move-left`/
+    /// `:buffer-move-right`). Pinned buffers (see [`Document::pinned`])
+    /// still occupy a slot here, but the bufferline draws them ahead of
+    /// unpinned ones.
+    pub buffer_order: Vec<DocumentId>,
+
     // We Flatten<> to resolve the inner DocumentSavedEventFuture. For that we need a stream of streams, hence the Once<>.
     // https://stackoverflow.com/a/66875668
     pub saves: HashMap<DocumentId, UnboundedSender<Once<DocumentSavedEventFuture>>>,
@@ -923,16 +1209,65 @@ pub struct Editor {
     pub language_servers: helix_lsp::Registry,
     pub diagnostics: BTreeMap<lsp::Url, Vec<(lsp::Diagnostic, usize)>>,
     pub diff_providers: DiffProviderRegistry,
+    pub quickfix: QuickfixList,
+
+    /// Grouped multi-file edits applied via `apply_workspace_edit` (e.g. LSP
+    /// renames or code actions touching several files), most recent last.
+    /// `:undo-workspace` pops one and reverts every file it touched in a
+    /// single step, reopening files that have since been closed.
+    pub workspace_edit_history: Vec<WorkspaceEditUndo>,
+
+    /// The document backing an open command-line window, if any. Set while
+    /// the user edits a `:`/search/global-search prompt in a full buffer,
+    /// and consulted when accepting (`Enter`) or cancelling (`:cmdwin-cancel`)
+    /// to find it again.
+    pub command_line_window: Option<DocumentId>,
 
     pub debugger: Option<dap::Client>,
     pub debugger_events: SelectAll<UnboundedReceiverStream<dap::Payload>>,
     pub breakpoints: HashMap<PathBuf, Vec<Breakpoint>>,
 
+    /// Per-workspace code review notes attached to specific lines,
+    /// independent of VCS. Loaded from `.helix/notes.json` on startup;
+    /// callers are responsible for calling [`crate::notes::NoteStore::save`]
+    /// after mutating it.
+    pub notes: notes::NoteStore,
+
+    /// Vim-style global marks (`m{A-Z}`), persisted across sessions. Callers
+    /// are responsible for calling [`crate::marks::GlobalMarkStore::save`]
+    /// after mutating it, same as `notes` above.
+    pub global_marks: marks::GlobalMarkStore,
+
+    /// Per-file `:set-language` overrides, persisted across sessions. Same
+    /// save-on-mutate contract as `notes`/`global_marks`. See
+    /// [`crate::language_overrides`].
+    pub language_overrides: language_overrides::LanguageOverrideStore,
+
     pub syn_loader: Arc<syntax::Loader>,
     pub theme_loader: Arc<theme::Loader>,
     /// last_theme is used for theme previews. We store the current theme here,
     /// and if previewing is cancelled, we can return to it.
     pub last_theme: Option<Theme>,
+    /// Set by `:theme-edit` while a theme's own source file is open for
+    /// editing. Holds the document being edited and the revision it was at
+    /// when last parsed, so a `PostCommand` hook can detect further edits
+    /// and re-preview the theme without re-parsing on every command.
+    pub theme_edit: Option<(DocumentId, usize)>,
+    /// Set by `:log-follow` while a document is being tailed. On an idle
+    /// timeout where [`Self::fs_watcher`] reports its file changed, the
+    /// followed document is reloaded from disk and scrolled to its last
+    /// line, so appended log lines show up without user input.
+    pub log_follow: Option<DocumentId>,
+    /// Polling watcher backing `:log-follow`: while a document is being
+    /// followed, its path is watched here so growth can be picked up on
+    /// the idle timer (see [`crate::fs_watcher`]). That idle-timeout poll
+    /// also forwards whatever it finds changed to
+    /// `language_servers.file_event_handler`, the same best-effort LSP
+    /// `didChangeWatchedFiles` forwarding that `:reload`/`:reload-all`
+    /// trigger directly -- but since this watcher only ever tracks the
+    /// single followed path, it is not a general substitute for watching
+    /// every path an LSP server has registered interest in.
+    pub fs_watcher: fs_watcher::FsWatcher,
     /// The currently applied editor theme. While previewing a theme, the previewed theme
     /// is set here.
     pub theme: Theme,
@@ -945,6 +1280,11 @@ pub struct Editor {
     pub status_msg: Option<(Cow<'static, str>, Severity)>,
     pub autoinfo: Option<Info>,
 
+    /// History of async notifications (LSP progress, job completion,
+    /// background errors, ...), shown as a stacked toast popup and browsable
+    /// via `:notifications`. See [`notification::NotificationStore`].
+    pub notifications: notification::NotificationStore,
+
     pub config: Arc<dyn DynAccess<Config>>,
     pub auto_pairs: Option<AutoPairs>,
 
@@ -997,6 +1337,10 @@ pub enum EditorEvent {
 pub enum ConfigEvent {
     Refresh,
     Update(Box<Config>),
+    /// Reload tree-sitter grammars and queries from the runtime directory
+    /// and re-parse all open documents, without touching the rest of the
+    /// configuration. Used by `:tree-sitter-reload`.
+    ReloadGrammars,
 }
 
 enum ThemeAction {
@@ -1043,6 +1387,18 @@ pub enum CloseError {
     SaveError(anyhow::Error),
 }
 
+impl std::fmt::Display for CloseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CloseError::DoesNotExist => f.write_str("document does not exist"),
+            CloseError::BufferModified(name) => write!(f, "buffer {name:?} is modified"),
+            CloseError::SaveError(err) => write!(f, "error saving document: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CloseError {}
+
 impl Editor {
     pub fn new(
         mut area: Rect,
@@ -1054,6 +1410,7 @@ pub fn new(
         let language_servers = helix_lsp::Registry::new(syn_loader.clone());
         let conf = config.load();
         let auto_pairs = (&conf.auto_pairs).into();
+        helix_core::fuzzy::FUZZY_MATCHING_CONFIG.store(Arc::new(conf.fuzzy_matching));
 
         // HAXX: offset the render area height by 1 to account for prompt/commandline
         area.height -= 1;
@@ -1063,6 +1420,7 @@ pub fn new(
             tree: Tree::new(area),
             next_document_id: DocumentId::default(),
             documents: BTreeMap::new(),
+            buffer_order: Vec::new(),
             saves: HashMap::new(),
             save_queue: SelectAll::new(),
             write_count: 0,
@@ -1074,16 +1432,26 @@ pub fn new(
             language_servers,
             diagnostics: BTreeMap::new(),
             diff_providers: DiffProviderRegistry::default(),
+            quickfix: QuickfixList::default(),
+            workspace_edit_history: Vec::new(),
+            command_line_window: None,
             debugger: None,
             debugger_events: SelectAll::new(),
             breakpoints: HashMap::new(),
+            notes: notes::NoteStore::load(),
+            global_marks: marks::GlobalMarkStore::load(),
+            language_overrides: language_overrides::LanguageOverrideStore::load(),
             syn_loader,
             theme_loader,
             last_theme: None,
+            theme_edit: None,
+            log_follow: None,
+            fs_watcher: fs_watcher::FsWatcher::default(),
             last_selection: None,
             registers: Registers::default(),
             status_msg: None,
             autoinfo: None,
+            notifications: notification::NotificationStore::default(),
             idle_timer: Box::pin(sleep(conf.idle_timeout)),
             redraw_timer: Box::pin(sleep(Duration::MAX)),
             last_motion: None,
@@ -1121,12 +1489,28 @@ pub fn config(&self) -> DynGuard<Config> {
         self.config.load()
     }
 
+    /// How long to wait after a redraw request before actually redrawing,
+    /// coalescing requests that arrive in quick succession. Longer under
+    /// `Config::low_bandwidth` so a burst of edits over a slow connection
+    /// produces fewer, larger redraws instead of many small ones.
+    fn redraw_debounce(&self) -> Duration {
+        if self.config().low_bandwidth {
+            Duration::from_millis(150)
+        } else {
+            Duration::from_millis(33)
+        }
+    }
+
     /// Call if the config has changed to let the editor update all
     /// relevant members.
     pub fn refresh_config(&mut self) {
         let config = self.config();
         self.auto_pairs = (&config.auto_pairs).into();
+        helix_core::fuzzy::FUZZY_MATCHING_CONFIG.store(Arc::new(config.fuzzy_matching));
         self.reset_idle_timer();
+        for document in self.documents.values_mut() {
+            document.refresh_line_length_diagnostics();
+        }
         self._refresh();
     }
 
@@ -1346,6 +1730,12 @@ pub fn switch(&mut self, id: DocumentId, action: Action) {
         match action {
             Action::Replace => {
                 let (view, doc) = current_ref!(self);
+                if view.pinned && id != doc.id {
+                    // Don't replace a pinned view's document; open the
+                    // target in a split instead so the pinned buffer stays visible.
+                    self.switch(id, Action::VerticalSplit);
+                    return;
+                }
                 // If the current view is an empty scratch buffer and is not displayed in any other views, delete it.
                 // Boolean value is determined before the call to `view_mut` because the operation requires a borrow
                 // of `self.tree`, which is mutably borrowed when `view_mut` is called.
@@ -1436,6 +1826,7 @@ fn new_document(&mut self, mut doc: Document) -> DocumentId {
             DocumentId(unsafe { NonZeroUsize::new_unchecked(self.next_document_id.0.get() + 1) });
         doc.id = id;
         self.documents.insert(id, doc);
+        self.buffer_order.push(id);
 
         let (save_sender, save_receiver) = tokio::sync::mpsc::unbounded_channel();
         self.saves.insert(id, save_sender);
@@ -1458,11 +1849,12 @@ pub fn new_file(&mut self, action: Action) -> DocumentId {
 
     pub fn new_file_from_stdin(&mut self, action: Action) -> Result<DocumentId, Error> {
         let (stdin, encoding, has_bom) = crate::document::read_to_string(&mut stdin(), None)?;
-        let doc = Document::from(
+        let mut doc = Document::from(
             helix_core::Rope::default(),
             Some((encoding, has_bom)),
             self.config.clone(),
         );
+        doc.scratch_buffer_name = Some(crate::document::STDIN_BUFFER_NAME);
         let doc_id = self.new_file_from_document(action, doc);
         let doc = doc_mut!(self, &doc_id);
         let view = view_mut!(self);
@@ -1472,9 +1864,43 @@ pub fn new_file_from_stdin(&mut self, action: Action) -> Result<DocumentId, Erro
                 .with_selection(Selection::point(0));
         doc.apply(&transaction, view.id);
         doc.append_changes_to_history(view);
+        // No file name to detect from, but the content may carry a shebang
+        // (e.g. piping in a script with `cat script.py | hx -`).
+        doc.detect_language(self.syn_loader.clone());
         Ok(doc_id)
     }
 
+    /// Moves `doc_id` one slot left (`Direction::Backward`) or right
+    /// (`Direction::Forward`) in [`Self::buffer_order`], among buffers with
+    /// the same [`Document::pinned`] state so pinned buffers stay anchored
+    /// ahead of unpinned ones. No-op if the buffer is already at that edge
+    /// of its group, or isn't open.
+    pub fn move_buffer(&mut self, doc_id: DocumentId, direction: Direction) {
+        let Some(pinned) = self.documents.get(&doc_id).map(|doc| doc.pinned) else {
+            return;
+        };
+        let group: Vec<usize> = self
+            .buffer_order
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| self.documents.get(id).map_or(false, |doc| doc.pinned == pinned))
+            .map(|(index, _)| index)
+            .collect();
+        let Some(group_pos) = group
+            .iter()
+            .position(|&index| self.buffer_order[index] == doc_id)
+        else {
+            return;
+        };
+        let target = match direction {
+            Direction::Backward => group_pos.checked_sub(1),
+            Direction::Forward => (group_pos + 1 < group.len()).then(|| group_pos + 1),
+        };
+        if let Some(target) = target {
+            self.buffer_order.swap(group[group_pos], group[target]);
+        }
+    }
+
     // ??? possible use for integration tests
     pub fn open(&mut self, path: &Path, action: Action) -> Result<DocumentId, Error> {
         let path = helix_core::path::get_canonicalized_path(path);
@@ -1490,6 +1916,28 @@ pub fn open(&mut self, path: &Path, action: Action) -> Result<DocumentId, Error>
                 self.config.clone(),
             )?;
 
+            // A persisted `:set-language` override takes priority over a
+            // `helix: language=...` modeline comment, which in turn takes
+            // priority over the extension-based detection `Document::open`
+            // already did above.
+            let language_override = self
+                .language_overrides
+                .get(&path)
+                .map(str::to_string)
+                .or_else(|| helix_core::modeline::detect_language(doc.text()));
+            if let Some(language_id) = language_override {
+                if let Err(err) =
+                    doc.set_language_by_language_id(&language_id, self.syn_loader.clone())
+                {
+                    log::warn!(
+                        "failed to apply language override {:?} for {:?}: {}",
+                        language_id,
+                        path,
+                        err
+                    );
+                }
+            }
+
             if let Some(diff_base) = self.diff_providers.get_diff_base(&path) {
                 doc.set_diff_base(diff_base);
             }
@@ -1498,6 +1946,10 @@ pub fn open(&mut self, path: &Path, action: Action) -> Result<DocumentId, Error>
             let id = self.new_document(doc);
             self.launch_language_servers(id);
 
+            if let Err(err) = helix_loader::frecency::record_access(&path) {
+                log::warn!("failed to record frecency for {}: {err}", path.display());
+            }
+
             id
         };
 
@@ -1568,6 +2020,7 @@ enum Action {
         }
 
         self.documents.remove(&doc_id);
+        self.buffer_order.retain(|id| *id != doc_id);
 
         // If the document we removed was visible in all views, we will have no more views. We don't
         // want to close the editor just for a simple buffer close, so we need to create a new view
@@ -1785,7 +2238,7 @@ pub async fn wait_event(&mut self) -> EditorEvent {
                 _ = helix_event::redraw_requested() => {
                     if  !self.needs_redraw{
                         self.needs_redraw = true;
-                        let timeout = Instant::now() + Duration::from_millis(33);
+                        let timeout = Instant::now() + self.redraw_debounce();
                         if timeout < self.idle_timer.deadline() && timeout < self.redraw_timer.deadline(){
                             self.redraw_timer.as_mut().reset(timeout)
                         }
@@ -0,0 +1,81 @@
+//! Polling-based watcher for externally-modified files.
+//!
+//! There's no OS-level filesystem watcher (inotify, FSEvents,
+//! ReadDirectoryChangesW, ...) anywhere in this codebase, so this is the
+//! only watcher backend rather than a fallback reserved for network
+//! filesystems that lack those APIs. It's still most useful there: a path
+//! on a slow NFS/SSHFS mount can be given a longer poll interval than local
+//! files via [`crate::editor::FileWatcherConfig::overrides`], so `:log-follow`
+//! (the only feature built on this) doesn't hammer a network mount with
+//! stats on every tick.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use tokio::time::{Duration, Instant};
+
+use crate::editor::FileWatcherConfig;
+
+struct WatchedPath {
+    poll_interval: Duration,
+    next_poll: Instant,
+    last_modified: Option<SystemTime>,
+}
+
+/// Polls registered paths on a per-path interval and reports the ones that
+/// changed since the last poll. See the module documentation for why
+/// polling is the only backend.
+#[derive(Default)]
+pub struct FsWatcher {
+    watched: HashMap<PathBuf, WatchedPath>,
+}
+
+impl FsWatcher {
+    /// Starts (or resets) watching `path`, using its current metadata as
+    /// the baseline so the next [`Self::poll_changed`] only reports changes
+    /// that happen after this call. `config` picks the poll interval, see
+    /// [`FileWatcherConfig::poll_interval_for`].
+    pub fn watch(&mut self, path: PathBuf, config: &FileWatcherConfig) {
+        let poll_interval = config.poll_interval_for(&path);
+        let last_modified = modified(&path);
+        self.watched.insert(
+            path,
+            WatchedPath {
+                poll_interval,
+                next_poll: Instant::now() + poll_interval,
+                last_modified,
+            },
+        );
+    }
+
+    /// Stops watching `path`. No-op if it wasn't being watched.
+    pub fn unwatch(&mut self, path: &Path) {
+        self.watched.remove(path);
+    }
+
+    /// Re-stats every watched path whose poll interval has elapsed and
+    /// returns the ones whose modification time changed, rearming their
+    /// timer and updating their baseline so the same change isn't reported
+    /// twice.
+    pub fn poll_changed(&mut self) -> Vec<PathBuf> {
+        let now = Instant::now();
+        let mut changed = Vec::new();
+        for (path, state) in self.watched.iter_mut() {
+            if state.next_poll > now {
+                continue;
+            }
+            state.next_poll = now + state.poll_interval;
+            let last_modified = modified(path);
+            if last_modified != state.last_modified {
+                state.last_modified = last_modified;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
@@ -33,6 +33,7 @@ pub fn style<'doc>(
             GutterType::LineNumbers => line_numbers(editor, doc, view, theme, is_focused),
             GutterType::Spacer => padding(editor, doc, view, theme, is_focused),
             GutterType::Diff => diff(editor, doc, view, theme, is_focused),
+            GutterType::Notes => notes(editor, doc, view, theme, is_focused),
         }
     }
 
@@ -42,6 +43,7 @@ pub fn width(self, view: &View, doc: &Document) -> usize {
             GutterType::LineNumbers => line_numbers_width(view, doc),
             GutterType::Spacer => 1,
             GutterType::Diff => 1,
+            GutterType::Notes => 1,
         }
     }
 }
@@ -217,6 +219,31 @@ fn line_numbers_width(view: &View, doc: &Document) -> usize {
     digits.max(n_min)
 }
 
+pub fn notes<'doc>(
+    editor: &'doc Editor,
+    doc: &'doc Document,
+    _view: &View,
+    theme: &Theme,
+    _is_focused: bool,
+) -> GutterFn<'doc> {
+    let style = theme.get("info");
+
+    let notes = match doc.path() {
+        Some(path) => editor.notes.for_file(path),
+        None => return Box::new(move |_, _, _, _| None),
+    };
+
+    Box::new(
+        move |line: usize, _selected: bool, first_visual_line: bool, out: &mut String| {
+            if !first_visual_line || !notes.iter().any(|note| note.line == line) {
+                return None;
+            }
+            write!(out, "▎").ok();
+            Some(style)
+        },
+    )
+}
+
 pub fn padding<'doc>(
     _editor: &'doc Editor,
     _doc: &'doc Document,
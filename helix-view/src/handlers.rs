@@ -1,3 +1,6 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
 use helix_event::send_blocking;
 use tokio::sync::mpsc::Sender;
 
@@ -11,9 +14,48 @@ pub struct Handlers {
     // only public because most of the actual implementation is in helix-term right now :/
     pub completions: Sender<lsp::CompletionEvent>,
     pub signature_hints: Sender<lsp::SignatureHelpEvent>,
+    /// Channels for debounced/event-driven handlers (auto-save, document
+    /// highlights, blame, pull diagnostics, ...) that don't warrant a
+    /// dedicated field here. Register a channel with [`Handlers::register`]
+    /// and deliver events to it with [`Handlers::send`], instead of growing
+    /// this struct by one field per handler.
+    channels: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
 }
 
 impl Handlers {
+    pub fn new(
+        completions: Sender<lsp::CompletionEvent>,
+        signature_hints: Sender<lsp::SignatureHelpEvent>,
+    ) -> Self {
+        Self {
+            completions,
+            signature_hints,
+            channels: HashMap::new(),
+        }
+    }
+
+    /// Registers the sending half of an [`AsyncHook`](helix_event::AsyncHook)'s
+    /// channel under its event type `E`, so that [`Handlers::send`] can later
+    /// deliver events to it. Each event type can only have one channel
+    /// registered; registering a second one replaces the first.
+    pub fn register<E: Send + Sync + 'static>(&mut self, tx: Sender<E>) -> &mut Self {
+        self.channels.insert(TypeId::of::<E>(), Box::new(tx));
+        self
+    }
+
+    /// Sends `event` to the channel registered for `E` via [`Handlers::register`].
+    /// Does nothing if no handler has registered for events of type `E`.
+    pub fn send<E: Send + Sync + 'static>(&self, event: E) {
+        let Some(tx) = self
+            .channels
+            .get(&TypeId::of::<E>())
+            .and_then(|tx| tx.downcast_ref::<Sender<E>>())
+        else {
+            return;
+        };
+        send_blocking(tx, event);
+    }
+
     /// Manually trigger completion (c-x)
     pub fn trigger_completions(&self) {
         send_blocking(&self.completions, lsp::CompletionEvent::Manual);
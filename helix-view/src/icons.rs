@@ -0,0 +1,82 @@
+//! Icons shown alongside file paths in pickers (file, buffer, symbol, ...).
+//!
+//! This is opt-in via `editor.picker.icons` and looks up a glyph by
+//! filename/extension in a small built-in table of Nerd Font codepoints,
+//! falling back to a generic file/directory glyph, or to plain text (no
+//! glyph at all) when the terminal font isn't a patched Nerd Font.
+//!
+//! A user-configurable, theme-driven icon table (mirroring how
+//! [`crate::theme`] loads TOML from the runtime directories) is a natural
+//! extension of this but is not implemented yet -- the table below only
+//! covers a handful of common languages and file kinds.
+
+use std::path::Path;
+
+/// A generic file glyph, used when no more specific icon matches.
+const DEFAULT_FILE_ICON: &str = "\u{f15b}";
+/// A generic directory glyph.
+const DEFAULT_DIR_ICON: &str = "\u{f07b}";
+
+const ICONS_BY_FILENAME: &[(&str, &str)] = &[
+    ("Cargo.toml", "\u{e7a8}"),
+    ("Cargo.lock", "\u{e7a8}"),
+    ("Makefile", "\u{e779}"),
+    ("Dockerfile", "\u{f308}"),
+    (".gitignore", "\u{f1d3}"),
+    (".gitmodules", "\u{f1d3}"),
+    ("README.md", "\u{f48a}"),
+];
+
+const ICONS_BY_EXTENSION: &[(&str, &str)] = &[
+    ("rs", "\u{e7a8}"),
+    ("toml", "\u{e615}"),
+    ("py", "\u{e73c}"),
+    ("js", "\u{e74e}"),
+    ("jsx", "\u{e7ba}"),
+    ("ts", "\u{e628}"),
+    ("tsx", "\u{e7ba}"),
+    ("go", "\u{e627}"),
+    ("c", "\u{e61e}"),
+    ("h", "\u{e61e}"),
+    ("cpp", "\u{e61d}"),
+    ("hpp", "\u{e61d}"),
+    ("java", "\u{e738}"),
+    ("rb", "\u{e21e}"),
+    ("php", "\u{e73d}"),
+    ("md", "\u{e73e}"),
+    ("json", "\u{e60b}"),
+    ("yaml", "\u{e615}"),
+    ("yml", "\u{e615}"),
+    ("html", "\u{e736}"),
+    ("css", "\u{e749}"),
+    ("sh", "\u{f489}"),
+    ("lock", "\u{f023}"),
+];
+
+/// Looks up the icon glyph for `path`. `nerd_font` selects between the Nerd
+/// Font glyph table and a plain-text fallback (a fixed-width space, so
+/// picker columns stay aligned when icons are disabled but the column is
+/// still present).
+pub fn icon_for(path: &Path, is_dir: bool, nerd_font: bool) -> &'static str {
+    if !nerd_font {
+        return " ";
+    }
+
+    if is_dir {
+        return DEFAULT_DIR_ICON;
+    }
+
+    if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+        if let Some((_, icon)) = ICONS_BY_FILENAME.iter().find(|(n, _)| *n == name) {
+            return icon;
+        }
+    }
+
+    if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+        if let Some((_, icon)) = ICONS_BY_EXTENSION.iter().find(|(e, _)| *e == ext) {
+            return icon;
+        }
+    }
+
+    DEFAULT_FILE_ICON
+}
@@ -0,0 +1,87 @@
+//! Per-file language overrides set with `:set-language`, persisted across
+//! sessions so templated files (`.html` that's really Jinja, `.txt` that's
+//! really a log format, ...) don't need `:set-language` re-run every time
+//! they're reopened. See also [`helix_core::modeline`] for a per-file
+//! alternative that lives in the file itself rather than this store.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Default)]
+pub struct LanguageOverrideStore {
+    overrides: HashMap<PathBuf, String>,
+    /// Where the store was (or would be) loaded from, so `save` can write
+    /// back to the same place without re-deriving it.
+    path: PathBuf,
+}
+
+impl LanguageOverrideStore {
+    /// Loads the override store, or returns an empty store if no file
+    /// exists yet.
+    pub fn load() -> Self {
+        let path = Self::overrides_file();
+        let overrides = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        Self { overrides, path }
+    }
+
+    fn overrides_file() -> PathBuf {
+        helix_loader::cache_dir().join("language-overrides.json")
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.overrides)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Persists `language_id` as the override for `path`, replacing any
+    /// existing one.
+    pub fn set(&mut self, path: PathBuf, language_id: String) {
+        self.overrides.insert(path, language_id);
+    }
+
+    /// Removes the override for `path`, if any, reporting whether one
+    /// existed.
+    pub fn remove(&mut self, path: &Path) -> bool {
+        self.overrides.remove(path).is_some()
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&str> {
+        self.overrides.get(path).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_and_get_roundtrip() {
+        let mut store = LanguageOverrideStore::default();
+        store.set(PathBuf::from("templates/index.html"), "jinja".to_string());
+
+        assert_eq!(
+            store.get(Path::new("templates/index.html")),
+            Some("jinja")
+        );
+    }
+
+    #[test]
+    fn remove_reports_whether_an_override_existed() {
+        let mut store = LanguageOverrideStore::default();
+        store.set(PathBuf::from("a.txt"), "log".to_string());
+
+        assert!(store.remove(Path::new("a.txt")));
+        assert!(!store.remove(Path::new("a.txt")));
+    }
+}
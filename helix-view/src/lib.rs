@@ -8,12 +8,19 @@
 pub mod editor;
 pub mod env;
 pub mod events;
+pub mod fs_watcher;
 pub mod graphics;
 pub mod gutter;
 pub mod handlers;
+pub mod icons;
 pub mod info;
 pub mod input;
 pub mod keyboard;
+pub mod language_overrides;
+pub mod marks;
+pub mod notes;
+pub mod notification;
+pub mod quickfix;
 pub mod register;
 pub mod theme;
 pub mod tree;
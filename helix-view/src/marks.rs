@@ -0,0 +1,112 @@
+//! Vim-style named global marks (`m{A-Z}`), persisted across sessions.
+//!
+//! Unlike buffer-local marks (`m{a-z}`, kept on [`crate::document::Document`]
+//! and gone once the buffer closes), global marks record a file path plus a
+//! line/column, not a character offset or [`crate::Selection`]. That keeps
+//! them simple to persist as JSON and to resolve against a file that may not
+//! even be open yet, at the cost of not tracking edits made after the mark
+//! was set — jumping to a stale mark can land a line or two off if the file
+//! changed since.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mark {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct GlobalMarkStore {
+    marks: HashMap<char, Mark>,
+    /// Where the store was (or would be) loaded from, so `save` can write
+    /// back to the same place without re-deriving it.
+    path: PathBuf,
+}
+
+impl GlobalMarkStore {
+    /// Loads the global mark store, or returns an empty store if no file
+    /// exists yet.
+    pub fn load() -> Self {
+        let path = Self::marks_file();
+        let marks = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        Self { marks, path }
+    }
+
+    fn marks_file() -> PathBuf {
+        helix_loader::cache_dir().join("marks.json")
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.marks)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    pub fn set(&mut self, name: char, path: PathBuf, line: usize, column: usize) {
+        self.marks.insert(name, Mark { path, line, column });
+    }
+
+    pub fn get(&self, name: char) -> Option<&Mark> {
+        self.marks.get(&name)
+    }
+
+    pub fn remove(&mut self, name: char) -> bool {
+        self.marks.remove(&name).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (char, &Mark)> {
+        self.marks.iter().map(|(&name, mark)| (name, mark))
+    }
+}
+
+/// Returns whether `name` addresses a global mark (`A`-`Z`) rather than a
+/// buffer-local one (`a`-`z`).
+pub fn is_global_mark(name: char) -> bool {
+    name.is_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_and_get_roundtrip() {
+        let mut store = GlobalMarkStore::default();
+        store.set('A', PathBuf::from("src/lib.rs"), 10, 4);
+
+        let mark = store.get('A').unwrap();
+        assert_eq!(mark.path, PathBuf::from("src/lib.rs"));
+        assert_eq!(mark.line, 10);
+        assert_eq!(mark.column, 4);
+    }
+
+    #[test]
+    fn remove_reports_whether_a_mark_existed() {
+        let mut store = GlobalMarkStore::default();
+        store.set('B', PathBuf::from("a.rs"), 0, 0);
+
+        assert!(store.remove('B'));
+        assert!(!store.remove('B'));
+    }
+
+    #[test]
+    fn is_global_mark_distinguishes_case() {
+        assert!(is_global_mark('A'));
+        assert!(!is_global_mark('a'));
+    }
+}
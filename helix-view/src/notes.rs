@@ -0,0 +1,167 @@
+//! Lightweight, VCS-independent line notes for code review.
+//!
+//! Notes are attached to a line in a file and persisted per-workspace, in
+//! `<workspace>/.helix/notes.json`, so they survive restarts but stay local
+//! to the checkout rather than being tracked by git. They're rendered via
+//! the `notes` gutter (see [`crate::gutter`]) and listed by the
+//! `note-picker` command in `helix-term`.
+//!
+//! This intentionally stops short of the full ask of an interactive
+//! hover/expand popup: `helix-term`'s `:note-show` command surfaces a
+//! note's text on the status line instead. A floating popup anchored to
+//! the gutter sign is a natural follow-up once there's a generic
+//! "hover" component to reuse.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Note {
+    pub line: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Default)]
+pub struct NoteStore {
+    notes: HashMap<PathBuf, Vec<Note>>,
+    /// Where the store was (or would be) loaded from, so `save` can write
+    /// back to the same place without re-deriving the workspace root.
+    path: PathBuf,
+}
+
+impl NoteStore {
+    /// Loads the note store for the current workspace, or returns an empty
+    /// store if no file exists yet.
+    pub fn load() -> Self {
+        let path = Self::workspace_notes_file();
+        let notes = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        Self { notes, path }
+    }
+
+    fn workspace_notes_file() -> PathBuf {
+        helix_loader::find_workspace()
+            .0
+            .join(".helix")
+            .join("notes.json")
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.notes)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Adds a note, replacing any existing note on the same line.
+    pub fn add(&mut self, path: PathBuf, line: usize, text: String) {
+        let notes = self.notes.entry(path).or_default();
+        notes.retain(|note| note.line != line);
+        notes.push(Note { line, text });
+        notes.sort_unstable_by_key(|note| note.line);
+    }
+
+    /// Removes the note on `line` in `path`, if any. Returns whether a note
+    /// was removed.
+    pub fn remove(&mut self, path: &Path, line: usize) -> bool {
+        let Some(notes) = self.notes.get_mut(path) else {
+            return false;
+        };
+        let len_before = notes.len();
+        notes.retain(|note| note.line != line);
+        if notes.is_empty() {
+            self.notes.remove(path);
+        }
+        len_before != notes.len()
+    }
+
+    pub fn get(&self, path: &Path, line: usize) -> Option<&Note> {
+        self.notes.get(path)?.iter().find(|note| note.line == line)
+    }
+
+    pub fn for_file(&self, path: &Path) -> &[Note] {
+        self.notes.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterates over every note in the workspace, alongside the file it
+    /// belongs to.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &Note)> {
+        self.notes
+            .iter()
+            .flat_map(|(path, notes)| notes.iter().map(move |note| (path.as_path(), note)))
+    }
+
+    /// Renders every note as a markdown report, one section per file
+    /// (sorted for stable output) with one bullet per note.
+    pub fn to_markdown(&self) -> String {
+        let mut paths: Vec<&PathBuf> = self.notes.keys().collect();
+        paths.sort();
+
+        let mut out = String::from("# Review notes\n");
+        for path in paths {
+            let notes = &self.notes[path];
+            let _ = writeln!(out, "\n## {}\n", path.display());
+            for note in notes {
+                let _ = writeln!(out, "- line {}: {}", note.line + 1, note.text);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_replaces_existing_line_and_keeps_notes_sorted() {
+        let mut store = NoteStore::default();
+        let path = PathBuf::from("src/lib.rs");
+
+        store.add(path.clone(), 10, "first".to_string());
+        store.add(path.clone(), 3, "second".to_string());
+        store.add(path.clone(), 10, "replaced".to_string());
+
+        let notes = store.for_file(&path);
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].line, 3);
+        assert_eq!(notes[1].line, 10);
+        assert_eq!(notes[1].text, "replaced");
+    }
+
+    #[test]
+    fn to_markdown_groups_notes_by_file() {
+        let mut store = NoteStore::default();
+        store.add(PathBuf::from("b.rs"), 0, "second file".to_string());
+        store.add(PathBuf::from("a.rs"), 4, "first file".to_string());
+
+        let markdown = store.to_markdown();
+        let a_pos = markdown.find("## a.rs").unwrap();
+        let b_pos = markdown.find("## b.rs").unwrap();
+        assert!(a_pos < b_pos);
+        assert!(markdown.contains("- line 5: first file"));
+        assert!(markdown.contains("- line 1: second file"));
+    }
+
+    #[test]
+    fn remove_drops_empty_file_entries() {
+        let mut store = NoteStore::default();
+        let path = PathBuf::from("src/lib.rs");
+        store.add(path.clone(), 1, "note".to_string());
+
+        assert!(store.remove(&path, 1));
+        assert!(!store.remove(&path, 1));
+        assert!(store.for_file(&path).is_empty());
+    }
+}
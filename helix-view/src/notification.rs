@@ -0,0 +1,62 @@
+//! In-memory notification history for async events (LSP progress, job
+//! completion, background errors, ...) delivered through the
+//! [`helix_event::status`] channel.
+//!
+//! Entries land here instead of straight into [`crate::Editor::status_msg`]
+//! so that several async messages arriving in quick succession don't clobber
+//! one another on the statusline. Status messages set directly by commands
+//! (`editor.set_status`/`set_error`) are unaffected and still show on the
+//! statusline as before; only the async channel is rerouted. The editor
+//! keeps only a bounded history here; rendering the stacked, timed toast
+//! popup and the `:notifications` picker is left to `helix-term`.
+
+use std::{borrow::Cow, collections::VecDeque, time::Instant};
+
+use helix_core::diagnostic::Severity;
+
+/// How long a notification stays in the toast popup before it's dropped
+/// from [`NotificationStore::visible`].
+pub const TOAST_DURATION_SECS: u64 = 5;
+
+/// Maximum number of entries kept in the history, oldest first dropped.
+const MAX_HISTORY: usize = 200;
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub severity: Severity,
+    pub message: Cow<'static, str>,
+    pub received_at: Instant,
+}
+
+/// History of notifications, most recent last.
+#[derive(Debug, Default)]
+pub struct NotificationStore {
+    history: VecDeque<Notification>,
+}
+
+impl NotificationStore {
+    pub fn push(&mut self, severity: Severity, message: Cow<'static, str>) {
+        if self.history.len() == MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(Notification {
+            severity,
+            message,
+            received_at: Instant::now(),
+        });
+    }
+
+    /// All notifications, oldest first, for the `:notifications` picker.
+    pub fn history(&self) -> impl DoubleEndedIterator<Item = &Notification> {
+        self.history.iter()
+    }
+
+    /// Notifications still within [`TOAST_DURATION_SECS`] of being received,
+    /// most recent first, for the toast popup.
+    pub fn visible(&self) -> impl Iterator<Item = &Notification> {
+        self.history
+            .iter()
+            .rev()
+            .take_while(|n| n.received_at.elapsed().as_secs() < TOAST_DURATION_SECS)
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+/// A single entry in a [`QuickfixList`], pointing at a line in a file.
+#[derive(Debug, Clone)]
+pub struct QuickfixEntry {
+    pub path: PathBuf,
+    /// 0-indexed line number.
+    pub line: usize,
+}
+
+/// A persistent list of file locations, independent of any picker being
+/// open. Populated wholesale (e.g. by dumping a picker's matched items with
+/// `Ctrl-q`) and stepped through with `]q`/`[q`.
+#[derive(Debug, Default)]
+pub struct QuickfixList {
+    entries: Vec<QuickfixEntry>,
+    current: Option<usize>,
+}
+
+impl QuickfixList {
+    pub fn set(&mut self, entries: Vec<QuickfixEntry>) {
+        self.entries = entries;
+        self.current = None;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn next(&mut self) -> Option<&QuickfixEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let next = match self.current {
+            Some(i) => (i + 1) % self.entries.len(),
+            None => 0,
+        };
+        self.current = Some(next);
+        self.entries.get(next)
+    }
+
+    pub fn prev(&mut self) -> Option<&QuickfixEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let prev = match self.current {
+            Some(0) | None => self.entries.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.current = Some(prev);
+        self.entries.get(prev)
+    }
+}
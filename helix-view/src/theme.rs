@@ -188,6 +188,19 @@ fn path(&self, name: &str, visited_paths: &mut HashSet<PathBuf>) -> Result<PathB
             })
     }
 
+    /// Returns the path to the on-disk file that defines the theme `name`,
+    /// if one exists. Unlike [`Self::path`], this doesn't track visited
+    /// paths for inheritance-cycle detection, since callers just want to
+    /// locate a theme's own file (e.g. to open it for editing), not resolve
+    /// an `inherits` chain.
+    pub fn find_theme_file(&self, name: &str) -> Option<PathBuf> {
+        let filename = format!("{}.toml", name);
+        self.theme_dirs
+            .iter()
+            .map(|dir| dir.join(&filename))
+            .find(|path| path.exists())
+    }
+
     pub fn default_theme(&self, true_color: bool) -> Theme {
         if true_color {
             self.default()
@@ -131,6 +131,10 @@ pub struct View {
     /// mapping keeps track of the last applied history revision so that only new changes
     /// are applied.
     doc_revisions: HashMap<DocumentId, usize>,
+    /// When set, buffer-switch commands (`Action::Replace`) open their target
+    /// in another split instead of replacing this view's document, so a
+    /// notes or log buffer can stay visible while browsing other files.
+    pub pinned: bool,
 }
 
 impl fmt::Debug for View {
@@ -160,6 +164,7 @@ pub fn new(doc: DocumentId, gutters: GutterConfig) -> Self {
             object_selections: Vec::new(),
             gutters,
             doc_revisions: HashMap::new(),
+            pinned: false,
         }
     }
 
@@ -467,6 +472,30 @@ pub fn text_annotations<'a>(
             )));
         }
 
+        // Feature-contributed virtual text (blame, debug values, plugins, ...), lowest
+        // priority first so higher-priority layers win on overlap.
+        let mut virtual_text_layers: Vec<_> = doc.virtual_text_layers().collect();
+        virtual_text_layers.sort_by_key(|(_, layer)| layer.priority);
+        for (_, layer) in virtual_text_layers {
+            let highlight = layer
+                .highlight_scope
+                .as_deref()
+                .and_then(|scope| theme.and_then(|t| t.find_scope_index(scope)))
+                .map(Highlight);
+            text_annotations.add_inline_annotations(&layer.annotations, highlight);
+        }
+
+        // Feature-contributed overlays (redaction, ...). Order doesn't
+        // matter yet since there's only ever been one contributor so far.
+        for (_, layer) in doc.overlay_text_layers() {
+            let highlight = layer
+                .highlight_scope
+                .as_deref()
+                .and_then(|scope| theme.and_then(|t| t.find_scope_index(scope)))
+                .map(Highlight);
+            text_annotations.add_overlay(&layer.overlays, highlight);
+        }
+
         text_annotations
     }
 